@@ -1,9 +1,11 @@
 use darling::{FromMeta, FromVariant};
 use syn::{DataEnum, Path, spanned::Spanned};
 
+use crate::util::{ident_from_name, parse_func_path};
+
 use super::{
-    conversion_field::{ConvertibleField, extract_convertible_fields},
-    conversion_meta::ConversionMethod,
+    conversion_field::{ConvertibleField, FieldIdentifier, extract_convertible_fields},
+    conversion_meta::{ConversionMeta, ConversionMethod},
 };
 
 #[derive(FromMeta)]
@@ -13,6 +15,14 @@ struct VariantConvAttrs {
     // Add other variant-specific attributes here
     #[darling(default)]
     skip: bool,
+    #[darling(default, with = parse_func_path)]
+    with_func: Option<Path>,
+    #[darling(default)]
+    map_to: Option<syn::Expr>,
+    #[darling(default)]
+    target_tuple: bool,
+    #[darling(default)]
+    target_named: bool,
 }
 
 #[derive(FromVariant)]
@@ -23,6 +33,14 @@ struct ConvertVariant {
     rename: Option<String>,
     #[darling(default)]
     skip: bool,
+    #[darling(default, with = parse_func_path)]
+    with_func: Option<Path>,
+    #[darling(default)]
+    map_to: Option<syn::Expr>,
+    #[darling(default)]
+    target_tuple: bool,
+    #[darling(default)]
+    target_named: bool,
 
     // Different conversion types for variants
     #[darling(default)]
@@ -39,16 +57,118 @@ struct ConvertVariant {
 pub(crate) struct ConversionVariant {
     pub(crate) source_name: syn::Ident,
     pub(crate) target_name: syn::Ident,
-    pub(crate) named_variant: bool,
+    // Shapes of the two sides, which differ with `target_tuple` or `target_named`
+    pub(crate) source_named: bool,
+    pub(crate) target_named: bool,
     pub(crate) fields: Vec<ConvertibleField>,
+    // Function converting the payload of a single-field tuple variant as a whole
+    pub(crate) conversion_func: Option<Path>,
+    // Source fields are skipped, so the source pattern ends with `..`
+    pub(crate) skipped_fields: bool,
+    // Target value the whole variant collapses into, ignoring its data
+    pub(crate) map_to: Option<syn::Expr>,
+}
+
+/// Whether the other enum's variant has named fields. It has the deriving variant's
+/// shape, unless a named variant is marked `target_tuple` (or places its fields with
+/// `position`), or a tuple variant is marked `target_named` and names every field with
+/// `rename`. The fields of a `target_tuple` variant are numbered in declaration order.
+fn other_variant_shape(
+    variant: &syn::Variant,
+    is_from: bool,
+    target_tuple: bool,
+    target_named: bool,
+    fields: &mut [ConvertibleField],
+) -> syn::Result<bool> {
+    let named_variant = matches!(variant.fields, syn::Fields::Named(_));
+    if target_tuple && !named_variant {
+        return Err(syn::Error::new(
+            variant.span(),
+            "`target_tuple` is only supported on variants with named fields",
+        ));
+    }
+    if target_named && named_variant {
+        return Err(syn::Error::new(
+            variant.span(),
+            "`target_named` is only supported on tuple variants",
+        ));
+    }
+
+    // The other side of a field is its source when converting from the other enum
+    fn other_side(field: &mut ConvertibleField, is_from: bool) -> &mut FieldIdentifier {
+        if is_from {
+            &mut field.source_name
+        } else {
+            &mut field.target_name
+        }
+    }
+
+    if !named_variant {
+        if target_named
+            && fields
+                .iter_mut()
+                .any(|field| matches!(other_side(field, is_from), FieldIdentifier::Unnamed(_)))
+        {
+            return Err(syn::Error::new(
+                variant.span(),
+                "`target_named` requires a `rename` on every converted field, naming it on the other variant",
+            ));
+        }
+        return Ok(target_named);
+    }
+
+    let positioned = fields
+        .iter_mut()
+        .any(|field| matches!(other_side(field, is_from), FieldIdentifier::Unnamed(_)));
+    if !target_tuple || positioned {
+        return Ok(!positioned);
+    }
+
+    // Fields defaulted when converting from the other enum have no slot on it
+    let declared = variant
+        .fields
+        .iter()
+        .filter_map(|declared| declared.ident.as_ref());
+    let mut index = 0;
+    for ident in declared {
+        let Some(field) = fields.iter_mut().find(|field| {
+            let own = if is_from {
+                &field.target_name
+            } else {
+                &field.source_name
+            };
+            matches!(own, FieldIdentifier::Named(name) if name == ident)
+                && !(is_from && field.default)
+        }) else {
+            continue;
+        };
+        *other_side(field, is_from) = FieldIdentifier::Unnamed(index);
+        index += 1;
+    }
+    Ok(false)
 }
 
 pub(crate) fn extract_enum_variants(
     data_enum: &DataEnum,
-    conversion_type: ConversionMethod,
-    other_type: &Path,
+    meta: &ConversionMeta,
 ) -> syn::Result<Vec<ConversionVariant>> {
+    let conversion_type = meta.method;
+    let other_type = meta.other_type();
+    let rename_all = meta.rename_all;
     let is_from = conversion_type.is_from();
+
+    if let Some(unknown) = meta.map_variants.keys().find(|name| {
+        !data_enum
+            .variants
+            .iter()
+            .any(|variant| variant.ident == **name)
+    }) {
+        return Err(syn::Error::new(
+            unknown.span(),
+            format!("`map_variants` lists `{}`, which is not a variant", unknown),
+        ));
+    }
+
     data_enum
         .variants
         .iter()
@@ -58,7 +178,7 @@ pub(crate) fn extract_enum_variants(
                 Ok(cv) => cv,
                 Err(e) => {
                     return Err(syn::Error::new(
-                        variant.span(),
+                        e.explicit_span().unwrap_or_else(|| variant.span()),
                         format!("Failed to parse variant attributes: {}", e),
                     ));
                 }
@@ -82,25 +202,113 @@ pub(crate) fn extract_enum_variants(
             // Determine the target variant name with priority:
             // 1. Conversion-specific rename
             // 2. Top-level rename
-            // 3. Original variant name
+            // 3. Container-level map_variants
+            // 4. Container-level rename_all
+            // 5. Original variant name
             let other_variant_name = variant_conv_attrs
                 .as_ref()
-                .and_then(|attrs| attrs.rename.as_ref())
-                .or(convert_variant.rename.as_ref())
-                .map(|rename| syn::Ident::new(rename, variant.span()))
+                .and_then(|attrs| attrs.rename.clone())
+                .or(convert_variant.rename)
+                .or_else(|| meta.map_variants.get(&convert_variant.ident).cloned())
+                .or_else(|| rename_all.map(|rule| rule.apply(&convert_variant.ident.to_string())))
+                .map(|rename| ident_from_name(&rename, variant.span()))
                 .unwrap_or_else(|| convert_variant.ident.clone());
 
+            let conversion_func = variant_conv_attrs
+                .as_ref()
+                .and_then(|attrs| attrs.with_func.clone())
+                .or(convert_variant.with_func);
+
+            let map_to = variant_conv_attrs
+                .as_ref()
+                .and_then(|attrs| attrs.map_to.clone())
+                .or(convert_variant.map_to);
+            if let Some(map_to) = map_to {
+                // The target variant might be shared by several source variants, so
+                // only the deriving enum's variants can be collapsed
+                if is_from {
+                    return Err(syn::Error::new(
+                        map_to.span(),
+                        "`map_to` is only supported on `into`/`try_into` conversions",
+                    ));
+                }
+                if conversion_func.is_some() {
+                    return Err(syn::Error::new(
+                        variant.span(),
+                        "`map_to` cannot be combined with `with_func`",
+                    ));
+                }
+                return Ok(Some(ConversionVariant {
+                    source_name: convert_variant.ident.clone(),
+                    target_name: other_variant_name,
+                    source_named: named_variant,
+                    target_named: named_variant,
+                    fields: Vec::new(),
+                    conversion_func: None,
+                    skipped_fields: false,
+                    map_to: Some(map_to),
+                }));
+            }
+
+            let single_field = matches!(
+                &variant.fields,
+                syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1
+            );
+            if conversion_func.is_some() && !single_field {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "`with_func` on a variant requires a tuple variant with exactly one field",
+                ));
+            }
+
             let (source_name, target_name) = if is_from {
                 (other_variant_name, convert_variant.ident.clone())
             } else {
                 (convert_variant.ident.clone(), other_variant_name)
             };
 
+            // The payload is handed to the function untouched
+            let fields = if conversion_func.is_some() {
+                Vec::new()
+            } else {
+                extract_convertible_fields(
+                    &variant.fields,
+                    conversion_type,
+                    &other_type,
+                    &meta.containers,
+                    meta.require_explicit,
+                )?
+            };
+
+            let target_tuple = convert_variant.target_tuple
+                || variant_conv_attrs
+                    .as_ref()
+                    .is_some_and(|attrs| attrs.target_tuple);
+            let target_named = convert_variant.target_named
+                || variant_conv_attrs
+                    .as_ref()
+                    .is_some_and(|attrs| attrs.target_named);
+            let mut fields = fields;
+            let other_named =
+                other_variant_shape(variant, is_from, target_tuple, target_named, &mut fields)?;
+            let (source_named, target_named) = if is_from {
+                (other_named, named_variant)
+            } else {
+                (named_variant, other_named)
+            };
+
+            // Skipped fields of this variant are only in the source pattern when converting from it
+            let skipped_fields = !is_from && fields.len() < variant.fields.len();
+
             Ok(Some(ConversionVariant {
                 source_name,
                 target_name,
-                named_variant,
-                fields: extract_convertible_fields(&variant.fields, conversion_type, other_type)?,
+                source_named,
+                target_named,
+                fields,
+                conversion_func,
+                skipped_fields,
+                map_to: None,
             }))
         })
         .filter_map(|result| result.transpose())
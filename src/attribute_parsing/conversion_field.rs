@@ -1,22 +1,32 @@
 use darling::{FromField, FromMeta};
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{ToTokens, format_ident, quote};
-use syn::{Field, Ident, Path, spanned::Spanned};
+use syn::{
+    Field, Ident, Path, Token, ext::IdentExt, parse_quote, punctuated::Punctuated, spanned::Spanned,
+};
 
-use crate::util::{extract_hashmap_inner_types, extract_inner_type, is_surrounding_type};
+use crate::util::{
+    extract_cow_inner, extract_heapless_vec_inner, extract_inner_type, extract_inner_type_pair,
+    extract_slice_element, ident_from_name, is_surrounding_type, parse_func_path,
+    parse_variant_path,
+};
 
-use super::conversion_meta::ConversionMethod;
+use super::conversion_meta::{ConversionMethod, CustomContainer};
 
 // Field level attributes using darling
 #[derive(FromMeta, Debug)]
 struct ConvertFieldAttr {
     path: Option<Path>,
 
+    // Marks a field converted as is on purpose, which `require_explicit` asks for
+    #[darling(default, rename = "auto")]
+    _auto: bool,
+
     #[darling(default)]
     skip: bool,
 
     #[darling(default)]
-    unwrap: bool,
+    unwrap: Unwrap,
 
     #[darling(default)]
     unwrap_or_default: bool,
@@ -28,8 +38,68 @@ struct ConvertFieldAttr {
     #[darling(default)]
     rename: Option<String>,
 
-    #[darling(default)]
+    #[darling(default, with = parse_func_path)]
     with_func: Option<syn::Path>,
+
+    #[darling(default, with = parse_func_path)]
+    with_func_opt: Option<syn::Path>,
+
+    #[darling(default, with = parse_variant_path)]
+    error_variant: Option<syn::Path>,
+
+    #[darling(default)]
+    cfg_skip: Option<String>,
+
+    #[darling(default)]
+    extra: Option<String>,
+
+    #[darling(default)]
+    map_method: Option<Ident>,
+
+    #[darling(default)]
+    manual: bool,
+
+    #[darling(default)]
+    identity: bool,
+
+    #[darling(default)]
+    unbox: bool,
+
+    #[darling(default)]
+    tuple_elements: bool,
+
+    #[darling(default)]
+    boxed_dyn: Option<String>,
+
+    #[darling(default)]
+    wrap_if: Option<String>,
+
+    #[darling(default)]
+    none_on_err: bool,
+
+    #[darling(default)]
+    parse: bool,
+
+    #[darling(default)]
+    shared: Option<SharedStrategy>,
+
+    #[darling(default)]
+    option_to_result: Option<syn::Expr>,
+
+    #[darling(default)]
+    result_to_option: bool,
+
+    #[darling(default)]
+    to_array: Option<usize>,
+
+    #[darling(default)]
+    position: Option<usize>,
+
+    #[darling(default)]
+    map_keys: Option<MapSide>,
+
+    #[darling(default)]
+    map_values: Option<MapSide>,
 }
 
 #[derive(FromField, Debug)]
@@ -37,6 +107,10 @@ struct ConvertFieldAttr {
 struct ConvertField {
     ident: Option<Ident>,
 
+    // Marks a field converted as is on purpose, which `require_explicit` asks for
+    #[darling(default, rename = "auto")]
+    _auto: bool,
+
     #[darling(default)]
     skip: bool,
 
@@ -47,14 +121,77 @@ struct ConvertField {
     default: bool,
 
     #[darling(default)]
-    unwrap: bool,
+    unwrap: Unwrap,
 
     #[darling(default)]
     unwrap_or_default: bool,
 
-    #[darling(default)]
+    #[darling(default, with = parse_func_path)]
     with_func: Option<syn::Path>,
 
+    #[darling(default, with = parse_func_path)]
+    with_func_opt: Option<syn::Path>,
+
+    #[darling(default, with = parse_variant_path)]
+    error_variant: Option<syn::Path>,
+
+    #[darling(default)]
+    cfg_skip: Option<String>,
+
+    #[darling(default)]
+    extra: Option<String>,
+
+    #[darling(default)]
+    map_method: Option<Ident>,
+
+    #[darling(default)]
+    manual: bool,
+
+    #[darling(default)]
+    identity: bool,
+
+    #[darling(default)]
+    unbox: bool,
+
+    #[darling(default)]
+    tuple_elements: bool,
+
+    #[darling(default)]
+    boxed_dyn: Option<String>,
+
+    #[darling(default)]
+    wrap_if: Option<String>,
+
+    #[darling(default)]
+    none_on_err: bool,
+
+    #[darling(default)]
+    parse: bool,
+
+    #[darling(default)]
+    shared: Option<SharedStrategy>,
+
+    #[darling(default)]
+    option_to_result: Option<syn::Expr>,
+
+    #[darling(default)]
+    result_to_option: bool,
+
+    #[darling(default)]
+    to_array: Option<usize>,
+
+    #[darling(default)]
+    position: Option<usize>,
+
+    #[darling(default)]
+    map_keys: Option<MapSide>,
+
+    #[darling(default)]
+    map_values: Option<MapSide>,
+
+    #[darling(default, multiple)]
+    container: Vec<CustomContainer>,
+
     // Different conversion types
     #[darling(default, multiple)]
     from: Vec<ConvertFieldAttr>,
@@ -69,15 +206,211 @@ struct ConvertField {
     try_into: Vec<ConvertFieldAttr>,
 }
 
+/// `unwrap`, or `unwrap(expect = "...")` to choose the message used when the value is `None`:
+/// the panic message of infallible conversions and the error of fallible ones.
+#[derive(Clone, Debug, Default)]
+struct Unwrap {
+    enabled: bool,
+    expect: Option<String>,
+}
+
+#[derive(FromMeta)]
+struct UnwrapList {
+    expect: String,
+}
+
+impl FromMeta for Unwrap {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Unwrap {
+            enabled: true,
+            expect: None,
+        })
+    }
+
+    fn from_bool(value: bool) -> darling::Result<Self> {
+        Ok(Unwrap {
+            enabled: value,
+            expect: None,
+        })
+    }
+
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        let UnwrapList { expect } = UnwrapList::from_list(items)?;
+        Ok(Unwrap {
+            enabled: true,
+            expect: Some(expect),
+        })
+    }
+}
+
+/// How the value of an `Arc<T>` or `Rc<T>` field is taken out to be converted:
+/// `shared = "clone"` clones it when the pointer is shared, `shared = "try_unwrap"`
+/// fails the conversion instead.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum SharedStrategy {
+    Clone,
+    TryUnwrap,
+}
+
+impl FromMeta for SharedStrategy {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "clone" => Ok(SharedStrategy::Clone),
+            "try_unwrap" => Ok(SharedStrategy::TryUnwrap),
+            _ => Err(darling::Error::unknown_value(value)),
+        }
+    }
+}
+
+/// How one side of a `HashMap` field is converted, overriding the usual `.into()`.
+/// Written `map_keys(skip)`, `map_keys(into)` or `map_values(with_func = "f")`, with
+/// `map_values = "f"` as a shorthand for the latter.
+#[derive(Clone, Debug)]
+pub(crate) enum MapSide {
+    Skip,
+    Into,
+    WithFunc(Path),
+}
+
+#[derive(FromMeta)]
+struct MapSideList {
+    #[darling(default)]
+    skip: bool,
+    #[darling(default)]
+    into: bool,
+    with_func: Option<Path>,
+}
+
+impl FromMeta for MapSide {
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        match MapSideList::from_list(items)? {
+            MapSideList {
+                skip: true,
+                into: false,
+                with_func: None,
+            } => Ok(MapSide::Skip),
+            MapSideList {
+                skip: false,
+                into: true,
+                with_func: None,
+            } => Ok(MapSide::Into),
+            MapSideList {
+                skip: false,
+                into: false,
+                with_func: Some(func),
+            } => Ok(MapSide::WithFunc(func)),
+            _ => Err(darling::Error::custom(
+                "Expected exactly one of `skip`, `into` or `with_func`",
+            )),
+        }
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Path::from_string(value).map(MapSide::WithFunc)
+    }
+
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        match expr {
+            syn::Expr::Path(path) => Ok(MapSide::WithFunc(path.path.clone())),
+            syn::Expr::Lit(lit) => Self::from_value(&lit.lit),
+            _ => Err(darling::Error::unexpected_expr_type(expr)),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) enum FieldConversionMethod {
     Plain,
-    UnwrapOption(Box<FieldConversionMethod>),
+    // A primitive integer target, converted with its own `TryFrom` so that an
+    // out-of-range error keeps its message
+    Integer(Ident),
+    // Carries the `unwrap(expect = "...")` message, if any
+    UnwrapOption(Box<FieldConversionMethod>, Option<String>),
     UnwrapOrDefault(Box<FieldConversionMethod>),
     SomeOption(Box<FieldConversionMethod>),
     Option(Box<FieldConversionMethod>),
+    // A string parsed into the target type with `FromStr`, with `parse`
+    Parse,
+    // `Option<T>` whose value becomes `None` when it fails to convert, with `none_on_err`
+    OptionNoneOnErr(Box<FieldConversionMethod>),
+    // `Option<T>` -> `Result<U, E>`, with `option_to_result` giving the error for `None`
+    OptionToResult(Box<FieldConversionMethod>, syn::Expr),
+    // `Result<T, E>` -> `Option<U>`, dropping the error, with `result_to_option`
+    ResultToOption(Box<FieldConversionMethod>),
+    // `Result<T, E>`, whose `Ok` and `Err` values are both converted
+    Result(Box<FieldConversionMethod>, Box<FieldConversionMethod>),
+    // A tuple like `(T, U)`, converted element by element
+    Tuple(Vec<FieldConversionMethod>),
+    // `Box<T>`, whose value is converted and boxed again
+    Boxed(Box<FieldConversionMethod>),
+    // `Cow<'a, T>` on the source, whose owned value is converted
+    Cow(Box<FieldConversionMethod>),
+    // `Cow<'a, T>` on the target, filled with the converted value as `Cow::Owned`
+    CowOwned(Box<FieldConversionMethod>),
+    // `Arc<T>` or `Rc<T>` with `shared`, whose value is taken out, converted and wrapped again
+    Shared(Ident, SharedStrategy, Box<FieldConversionMethod>),
+    // `Vec<T>`, which unlike other iterables can be pre-allocated
+    Vec(Box<FieldConversionMethod>),
     Iterator(Box<FieldConversionMethod>),
+    // A fixed-capacity collection on the target, like an `ArrayVec`, filled with the
+    // given push function so that overflowing it fails a fallible conversion
+    Bounded(Box<FieldConversionMethod>, Path),
+    // A fixed-capacity map on the target, like a `heapless::FnvIndexMap`, filled with
+    // the given insert function
+    BoundedMap(Box<FieldConversionMethod>, Box<FieldConversionMethod>, Path),
+    // The key/value pairs of a map, collected into whichever map the other side holds
+    Pairs(Box<FieldConversionMethod>, Box<FieldConversionMethod>),
+    // `&[T]`, whose elements are cloned since they can't be moved out
+    Slice(Box<FieldConversionMethod>),
     HashMap(Box<FieldConversionMethod>, Box<FieldConversionMethod>),
+    // `BTreeMap<K, V>`, converted key/value-wise like a `HashMap`
+    BTreeMap(Box<FieldConversionMethod>, Box<FieldConversionMethod>),
+    // `Vec<T>` <-> `[U; N]`, with the length checked at runtime
+    Array(Box<FieldConversionMethod>, usize),
+    // `[T; N]`, mapped element by element. On the source (`false`), the elements are
+    // collected so that the other side may also be a `Vec`
+    FixedArray(Box<FieldConversionMethod>, bool),
+    // The value is used as is, e.g. the keys of a `HashMap` with `map_keys(skip)`
+    Keep,
+    // `.into()` even in fallible conversions, e.g. the keys of a `HashMap` with `map_keys(into)`
+    Into,
+    // The value is passed to a function, e.g. with `map_values = "f"`
+    WithFunc(Path),
+    // The value is boxed as the given trait object, e.g. `Box<dyn Handler + Send>`
+    BoxedDyn(syn::TypeTraitObject),
+}
+
+impl FieldConversionMethod {
+    /// Whether this fills a fixed-capacity collection, like an `ArrayVec`, at any level.
+    fn fills_bounded(&self) -> bool {
+        match self {
+            FieldConversionMethod::Bounded(..) | FieldConversionMethod::BoundedMap(..) => true,
+            FieldConversionMethod::UnwrapOption(inner, _)
+            | FieldConversionMethod::UnwrapOrDefault(inner)
+            | FieldConversionMethod::SomeOption(inner)
+            | FieldConversionMethod::Option(inner)
+            | FieldConversionMethod::OptionNoneOnErr(inner)
+            | FieldConversionMethod::OptionToResult(inner, _)
+            | FieldConversionMethod::ResultToOption(inner)
+            | FieldConversionMethod::Boxed(inner)
+            | FieldConversionMethod::Cow(inner)
+            | FieldConversionMethod::CowOwned(inner)
+            | FieldConversionMethod::Shared(_, _, inner)
+            | FieldConversionMethod::Vec(inner)
+            | FieldConversionMethod::Iterator(inner)
+            | FieldConversionMethod::Slice(inner)
+            | FieldConversionMethod::Array(inner, _)
+            | FieldConversionMethod::FixedArray(inner, _) => inner.fills_bounded(),
+            FieldConversionMethod::Result(first, second)
+            | FieldConversionMethod::HashMap(first, second)
+            | FieldConversionMethod::BTreeMap(first, second)
+            | FieldConversionMethod::Pairs(first, second) => {
+                first.fills_bounded() || second.fills_bounded()
+            }
+            FieldConversionMethod::Tuple(elems) => elems.iter().any(Self::fills_bounded),
+            _ => false,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -95,14 +428,32 @@ pub(crate) struct ConvertibleField {
     pub(crate) method: FieldConversionMethod,
     pub(crate) target_name: FieldIdentifier,
     pub(crate) conversion_func: Option<syn::Path>,
+    // Like `conversion_func`, but returning an `Option` whose `None` fails the conversion
+    pub(crate) conversion_func_opt: Option<syn::Path>,
+    // cfg predicate under which the field is filled with `Default::default()` instead
+    pub(crate) cfg_skip: Option<syn::Meta>,
+    // Catch-all map field on the target this field is inserted into, keyed by its name
+    pub(crate) extra: Option<Ident>,
+    // Method called on the source field before converting its result
+    pub(crate) map_method: Option<Ident>,
+    // Condition under which the converted value is wrapped in `Some`, `None` otherwise
+    pub(crate) wrap_if: Option<syn::Expr>,
+    // Error variant wrapping the field's inner error, converted into the impl's error type
+    pub(crate) error_variant: Option<syn::Path>,
 }
 
 pub(crate) fn extract_convertible_fields(
     fields: &syn::Fields,
     conversion_type: ConversionMethod,
     other_type: &Path,
+    containers: &[CustomContainer],
+    require_explicit: bool,
 ) -> syn::Result<Vec<ConvertibleField>> {
     let mut result = Vec::new();
+    // `position`s of the converted fields, checked once all fields are known
+    let mut positions: Vec<(usize, Span)> = Vec::new();
+    // Index of the next tuple field on the other type, which has no slot for skipped fields
+    let mut other_index = 0;
 
     // Determine which nested field we should check based on conversion type
     let is_from = matches!(
@@ -115,8 +466,9 @@ pub(crate) fn extract_convertible_fields(
         let convert_field = match ConvertField::from_field(field) {
             Ok(cf) => cf,
             Err(e) => {
+                // Point at the offending attribute value where darling knows it
                 return Err(syn::Error::new(
-                    field.span(),
+                    e.explicit_span().unwrap_or_else(|| field.span()),
                     format!("Failed to parse field attributes: {}", e),
                 ));
             }
@@ -152,9 +504,46 @@ pub(crate) fn extract_convertible_fields(
             }
         };
 
+        // Only an option for every conversion or one scoped to this conversion counts,
+        // e.g. not `into(path = "Other", skip)` when converting into `AuditRecord`
+        if require_explicit && field_conv_attrs.is_none() && !has_field_level_option(field) {
+            let name = match &field.ident {
+                Some(ident) => ident.to_string(),
+                None => i.to_string(),
+            };
+            // Pointing at the field's name rather than the attributes scoped elsewhere
+            let span = field
+                .ident
+                .as_ref()
+                .map_or_else(|| field.ty.span(), Ident::span);
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "Field `{}` has no `#[convert(...)]` attribute for this conversion, which `require_explicit` asks for. Add `#[convert(auto)]` to convert it as is",
+                    name
+                ),
+            ));
+        }
+
+        if let Some(attrs) = field_conv_attrs {
+            check_scope_conflicts(
+                field,
+                convert_field.unwrap.enabled,
+                convert_field.unwrap_or_default,
+                attrs,
+            )?;
+        }
+
+        let nested = NestedTypes {
+            containers: custom_container_names(containers, &convert_field.container)?,
+            unbox: convert_field.unbox || field_conv_attrs.is_some_and(|attrs| attrs.unbox),
+            tuple_elements: convert_field.tuple_elements
+                || field_conv_attrs.is_some_and(|attrs| attrs.tuple_elements),
+        };
+
         let unwrap = field_conv_attrs
             .as_ref()
-            .map_or(convert_field.unwrap, |attrs| attrs.unwrap);
+            .map_or(&convert_field.unwrap, |attrs| &attrs.unwrap);
 
         let unwrap_or_default = field_conv_attrs
             .as_ref()
@@ -169,24 +558,68 @@ pub(crate) fn extract_convertible_fields(
         // Skip applies if either top-level or field-specific skip is true
         let skip = convert_field.skip || field_conv_attrs.as_ref().is_some_and(|attrs| attrs.skip);
 
-        // Skip if marked with skip
-        if skip {
+        // Skip if marked with skip. Converting from the other type, the skipped field
+        // is on the target, so with `default` it's still filled in
+        if skip && !(is_from && default) {
             continue;
         }
 
+        // The field's index on the other tuple type. Converting from it, a defaulted
+        // field isn't read from it and takes no index
+        let other_name = match source_name {
+            FieldIdentifier::Unnamed(_) if is_from && default => source_name.clone(),
+            FieldIdentifier::Unnamed(_) => {
+                other_index += 1;
+                FieldIdentifier::Unnamed(other_index - 1)
+            }
+            FieldIdentifier::Named(_) => source_name.clone(),
+        };
+
         // Determine target field identifier with priority:
         // 1. Field-specific rename
         // 2. Top-level rename
-        // 3. Original field name
+        // 3. Original field name, or the index on the other tuple type
         let target_name = field_conv_attrs
             .as_ref()
             .and_then(|attrs| attrs.rename.as_ref())
             .or(convert_field.rename.as_ref())
-            .map(|rename| FieldIdentifier::Named(Ident::new(rename, field.span())))
-            .unwrap_or_else(|| source_name.clone());
+            .map(|rename| FieldIdentifier::Named(ident_from_name(rename, field.span())))
+            .unwrap_or(other_name);
+
+        // A named field placed at an index of the other, tuple, struct
+        let position = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.position)
+            .or(convert_field.position);
+
+        let target_name = match position {
+            Some(_) if field.ident.is_none() => {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`position` is only supported on named fields",
+                ));
+            }
+            Some(_)
+                if convert_field.rename.is_some()
+                    || field_conv_attrs.is_some_and(|attrs| attrs.rename.is_some()) =>
+            {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`position` cannot be combined with `rename`",
+                ));
+            }
+            Some(index) => {
+                positions.push((index, field.span()));
+                FieldIdentifier::Unnamed(index)
+            }
+            None => target_name,
+        };
 
-        // Determine field conversion method
-        let method = decide_field_method(field, is_from, unwrap, unwrap_or_default)?;
+        let map_method = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.map_method.as_ref())
+            .or(convert_field.map_method.as_ref())
+            .cloned();
 
         let conversion_func = field_conv_attrs
             .as_ref()
@@ -194,6 +627,315 @@ pub(crate) fn extract_convertible_fields(
             .or(convert_field.with_func.as_ref())
             .cloned();
 
+        let conversion_func_opt = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.with_func_opt.as_ref())
+            .or(convert_field.with_func_opt.as_ref())
+            .cloned();
+
+        if conversion_func_opt.is_some() && !conversion_type.is_falliable() {
+            return Err(syn::Error::new(
+                field.span(),
+                "`with_func_opt` is only supported on fallible conversions (`try_from`/`try_into`), use `with_func` instead",
+            ));
+        }
+
+        let error_variant = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.error_variant.as_ref())
+            .or(convert_field.error_variant.as_ref())
+            .cloned();
+
+        if error_variant.is_some() && !conversion_type.is_falliable() {
+            return Err(syn::Error::new(
+                field.span(),
+                "`error_variant` is only supported on fallible conversions (`try_from`/`try_into`)",
+            ));
+        }
+
+        if conversion_func_opt.is_some() && conversion_func.is_some() {
+            return Err(syn::Error::new(
+                field.span(),
+                "`with_func` and `with_func_opt` cannot be used on the same field",
+            ));
+        }
+
+        // A `manual` field relies on a hand-written `From`/`TryFrom` for its whole type
+        let manual = convert_field.manual || field_conv_attrs.is_some_and(|attrs| attrs.manual);
+
+        if manual && (unwrap.enabled || unwrap_or_default || conversion_func.is_some()) {
+            return Err(syn::Error::new(
+                field.span(),
+                "`manual` fields are converted with their existing `From`/`TryFrom` impl and cannot be combined with `unwrap`, `unwrap_or_default` or `with_func`",
+            ));
+        }
+
+        let wrap_if = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.wrap_if.as_ref())
+            .or(convert_field.wrap_if.as_ref())
+            .map(|condition| parse_wrap_condition(field, condition))
+            .transpose()?;
+
+        if wrap_if.is_some() && (unwrap.enabled || unwrap_or_default) {
+            return Err(syn::Error::new(
+                field.span(),
+                "`wrap_if` wraps the converted value in an `Option` and cannot be combined with `unwrap` or `unwrap_or_default`",
+            ));
+        }
+
+        // An `identity` field has the same type on both sides and is moved as is
+        let identity =
+            convert_field.identity || field_conv_attrs.is_some_and(|attrs| attrs.identity);
+
+        if identity
+            && (unwrap.enabled
+                || unwrap_or_default
+                || manual
+                || conversion_func.is_some()
+                || conversion_func_opt.is_some()
+                || wrap_if.is_some())
+        {
+            return Err(syn::Error::new(
+                field.span(),
+                "`identity` fields are used as is and cannot be combined with `unwrap`, `unwrap_or_default`, `manual`, `with_func`, `with_func_opt` or `wrap_if`",
+            ));
+        }
+
+        // `From` can't unsize, so a trait object field is boxed and cast instead
+        let boxed_dyn = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.boxed_dyn.as_ref())
+            .or(convert_field.boxed_dyn.as_ref())
+            .map(|bounds| parse_trait_object(field, bounds))
+            .transpose()?;
+
+        if boxed_dyn.is_some()
+            && (unwrap.enabled
+                || unwrap_or_default
+                || manual
+                || identity
+                || conversion_func.is_some()
+                || conversion_func_opt.is_some()
+                || map_method.is_some())
+        {
+            return Err(syn::Error::new(
+                field.span(),
+                "`boxed_dyn` boxes the field as is and cannot be combined with `unwrap`, `unwrap_or_default`, `manual`, `identity`, `with_func`, `with_func_opt` or `map_method`",
+            ));
+        }
+
+        let to_array = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.to_array)
+            .or(convert_field.to_array);
+
+        if to_array.is_some() && !conversion_type.is_falliable() {
+            return Err(syn::Error::new(
+                field.span(),
+                "`to_array` is only supported on fallible conversions (`try_from`/`try_into`), since the length is checked at runtime",
+            ));
+        }
+
+        if to_array.is_some()
+            && (unwrap.enabled
+                || unwrap_or_default
+                || manual
+                || identity
+                || boxed_dyn.is_some()
+                || map_method.is_some()
+                || wrap_if.is_some())
+        {
+            return Err(syn::Error::new(
+                field.span(),
+                "`to_array` cannot be combined with `unwrap`, `unwrap_or_default`, `manual`, `identity`, `boxed_dyn`, `map_method` or `wrap_if`",
+            ));
+        }
+
+        let option_to_result = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.option_to_result.as_ref())
+            .or(convert_field.option_to_result.as_ref())
+            .cloned();
+
+        let result_to_option = convert_field.result_to_option
+            || field_conv_attrs.is_some_and(|attrs| attrs.result_to_option);
+
+        if option_to_result.is_some() && result_to_option {
+            return Err(syn::Error::new(
+                field.span(),
+                "`option_to_result` and `result_to_option` cannot be used on the same field",
+            ));
+        }
+
+        if (option_to_result.is_some() || result_to_option)
+            && (unwrap.enabled
+                || unwrap_or_default
+                || manual
+                || identity
+                || boxed_dyn.is_some()
+                || to_array.is_some()
+                || map_method.is_some()
+                || wrap_if.is_some()
+                || conversion_func.is_some()
+                || conversion_func_opt.is_some())
+        {
+            return Err(syn::Error::new(
+                field.span(),
+                "`option_to_result` and `result_to_option` cannot be combined with `unwrap`, `unwrap_or_default`, `manual`, `identity`, `boxed_dyn`, `to_array`, `map_method`, `wrap_if`, `with_func` or `with_func_opt`",
+            ));
+        }
+
+        // Determine field conversion method. The result of `map_method` has an
+        // unknown type, so it's converted as a whole
+        let shared = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.shared)
+            .or(convert_field.shared);
+
+        if shared.is_some()
+            && (unwrap.enabled
+                || unwrap_or_default
+                || manual
+                || identity
+                || boxed_dyn.is_some()
+                || to_array.is_some()
+                || map_method.is_some()
+                || wrap_if.is_some()
+                || option_to_result.is_some()
+                || result_to_option
+                || conversion_func.is_some()
+                || conversion_func_opt.is_some())
+        {
+            return Err(syn::Error::new(
+                field.span(),
+                "`shared` cannot be combined with `unwrap`, `unwrap_or_default`, `manual`, `identity`, `boxed_dyn`, `to_array`, `map_method`, `wrap_if`, `option_to_result`, `result_to_option`, `with_func` or `with_func_opt`",
+            ));
+        }
+
+        let method = if identity {
+            FieldConversionMethod::Keep
+        } else if let Some(strategy) = shared {
+            decide_shared_method(field, is_from, strategy, conversion_type, &nested)?
+        } else if let Some(trait_object) = boxed_dyn {
+            FieldConversionMethod::BoxedDyn(trait_object)
+        } else if let Some(len) = to_array {
+            decide_array_method(field, is_from, len, &nested)?
+        } else if option_to_result.is_some() || result_to_option {
+            decide_option_result_method(field, is_from, option_to_result, &nested)?
+        } else if manual || map_method.is_some() {
+            FieldConversionMethod::Plain
+        } else if wrap_if.is_some() && is_from {
+            // The `Option` is this field's type, the value inside it is what gets converted
+            let inner_ty = extract_inner_type(&field.ty, "Option").ok_or_else(|| {
+                syn::Error::new_spanned(&field.ty, "`wrap_if` requires an `Option` field")
+            })?;
+            decide_field_method_for_type(inner_ty, is_from, &nested)
+        } else {
+            decide_field_method(field, is_from, unwrap, unwrap_or_default, &nested)?
+        };
+
+        let parse = convert_field.parse || field_conv_attrs.is_some_and(|attrs| attrs.parse);
+
+        let method = if parse {
+            if !conversion_type.is_falliable() {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`parse` is only supported on fallible conversions (`try_from`/`try_into`)",
+                ));
+            }
+            if conversion_func.is_some()
+                || conversion_func_opt.is_some()
+                || identity
+                || manual
+                || matches!(method, FieldConversionMethod::BoxedDyn(_))
+            {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`parse` cannot be combined with `with_func`, `with_func_opt`, `identity`, `manual` or `boxed_dyn`",
+                ));
+            }
+            parse_elements(method)
+        } else {
+            method
+        };
+
+        let none_on_err =
+            convert_field.none_on_err || field_conv_attrs.is_some_and(|attrs| attrs.none_on_err);
+
+        let method = if none_on_err {
+            decide_none_on_err_method(
+                field,
+                method,
+                conversion_type,
+                conversion_func.is_some() || conversion_func_opt.is_some(),
+            )?
+        } else {
+            method
+        };
+
+        // `FromIterator` of a fixed-capacity collection panics when it overflows, so
+        // filling one is left to fallible conversions, which report it as an error
+        if !conversion_type.is_falliable()
+            && conversion_func.is_none()
+            && conversion_func_opt.is_none()
+            && method.fills_bounded()
+        {
+            return Err(syn::Error::new(
+                field.span(),
+                "fixed-capacity collections like `ArrayVec` or `heapless::Vec` can overflow, so filling one is only supported on fallible conversions (`try_from`/`try_into`)",
+            ));
+        }
+
+        let map_keys = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.map_keys.as_ref())
+            .or(convert_field.map_keys.as_ref());
+
+        let map_values = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.map_values.as_ref())
+            .or(convert_field.map_values.as_ref());
+
+        let method = if map_keys.is_some() || map_values.is_some() {
+            if conversion_func.is_some() || conversion_func_opt.is_some() {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "`map_keys` and `map_values` cannot be combined with `with_func` or `with_func_opt`",
+                ));
+            }
+            apply_map_sides(field, method, map_keys, map_values)?
+        } else {
+            method
+        };
+
+        let cfg_skip = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.cfg_skip.as_ref())
+            .or(convert_field.cfg_skip.as_ref())
+            .map(|predicate| parse_cfg_predicate(field, predicate))
+            .transpose()?;
+
+        let extra = field_conv_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.extra.as_ref())
+            .or(convert_field.extra.as_ref())
+            .map(|extra| ident_from_name(extra, field.span()));
+
+        if extra.is_some() && is_from {
+            return Err(syn::Error::new(
+                field.span(),
+                "`extra` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+
+        if extra.is_some() && cfg_skip.is_some() {
+            return Err(syn::Error::new(
+                field.span(),
+                "`cfg_skip` and `extra` cannot be used together, the extra target field would be generated without the `cfg_skip` gate",
+            ));
+        }
+
         let (source_name, target_name) = if is_from {
             (target_name.clone(), source_name.clone())
         } else {
@@ -208,14 +950,25 @@ pub(crate) fn extract_convertible_fields(
             target_name,
             default,
             conversion_func,
+            conversion_func_opt,
+            cfg_skip,
+            extra,
+            map_method,
+            wrap_if,
+            error_variant,
         });
     }
 
+    check_positions(&positions, result.len())?;
+
     // sort so that fields with conversion functions are first
+    let has_func = |field: &ConvertibleField| {
+        field.conversion_func.is_some() || field.conversion_func_opt.is_some()
+    };
     result.sort_by(|a, b| {
-        if a.conversion_func.is_some() && b.conversion_func.is_none() {
+        if has_func(a) && !has_func(b) {
             std::cmp::Ordering::Less
-        } else if a.conversion_func.is_none() && b.conversion_func.is_some() {
+        } else if !has_func(a) && has_func(b) {
             std::cmp::Ordering::Greater
         } else {
             std::cmp::Ordering::Equal
@@ -225,32 +978,558 @@ pub(crate) fn extract_convertible_fields(
     Ok(result)
 }
 
+/// Whether the field's `#[convert(...)]` attributes set an option for every
+/// conversion, like `auto` or `rename`, rather than only scoping some to a
+/// conversion with `from(...)`, `into(...)`, `try_from(...)` or `try_into(...)`.
+fn has_field_level_option(field: &Field) -> bool {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("convert"))
+        .any(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+                .is_ok_and(|metas| {
+                    metas.iter().any(|meta| {
+                        !["from", "into", "try_from", "try_into"]
+                            .iter()
+                            .any(|scope| meta.path().is_ident(scope))
+                    })
+                })
+        })
+}
+
+/// `option_to_result` turns `Some(v)` into `Ok(v)` and `None` into `Err(error)`, and
+/// `result_to_option` turns `Ok(v)` into `Some(v)` and `Err(_)` into `None`. Converting
+/// from the other type, this field is the target, so it holds the other of the two.
+fn decide_option_result_method(
+    field: &Field,
+    is_from: bool,
+    option_to_result: Option<syn::Expr>,
+    nested: &NestedTypes,
+) -> syn::Result<FieldConversionMethod> {
+    let (attribute, field_type) = match (option_to_result.is_some(), is_from) {
+        (true, false) => ("option_to_result", "Option"),
+        (true, true) => ("option_to_result", "Result"),
+        (false, false) => ("result_to_option", "Result"),
+        (false, true) => ("result_to_option", "Option"),
+    };
+    let inner_ty = extract_inner_type(&field.ty, field_type).ok_or_else(|| {
+        syn::Error::new_spanned(
+            &field.ty,
+            format!("`{}` requires a `{}` field here", attribute, field_type),
+        )
+    })?;
+    let inner = Box::new(decide_field_method_for_type(inner_ty, is_from, nested));
+    Ok(match option_to_result {
+        Some(error) => FieldConversionMethod::OptionToResult(inner, error),
+        None => FieldConversionMethod::ResultToOption(inner),
+    })
+}
+
+/// With `parse`, the values a field converts, e.g. the elements of a `Vec` or the
+/// value of an `Option`, are parsed instead of converted with `TryFrom`.
+fn parse_elements(method: FieldConversionMethod) -> FieldConversionMethod {
+    let parse = |inner: Box<FieldConversionMethod>| Box::new(parse_elements(*inner));
+    match method {
+        FieldConversionMethod::Plain | FieldConversionMethod::Integer(_) => {
+            FieldConversionMethod::Parse
+        }
+        FieldConversionMethod::Option(inner) => FieldConversionMethod::Option(parse(inner)),
+        FieldConversionMethod::SomeOption(inner) => FieldConversionMethod::SomeOption(parse(inner)),
+        FieldConversionMethod::UnwrapOption(inner, expect) => {
+            FieldConversionMethod::UnwrapOption(parse(inner), expect)
+        }
+        FieldConversionMethod::UnwrapOrDefault(inner) => {
+            FieldConversionMethod::UnwrapOrDefault(parse(inner))
+        }
+        FieldConversionMethod::Result(ok, err) => FieldConversionMethod::Result(parse(ok), err),
+        FieldConversionMethod::Tuple(elems) => {
+            FieldConversionMethod::Tuple(elems.into_iter().map(parse_elements).collect())
+        }
+        FieldConversionMethod::Boxed(inner) => FieldConversionMethod::Boxed(parse(inner)),
+        FieldConversionMethod::Cow(inner) => FieldConversionMethod::Cow(parse(inner)),
+        FieldConversionMethod::CowOwned(inner) => FieldConversionMethod::CowOwned(parse(inner)),
+        FieldConversionMethod::Shared(pointer, strategy, inner) => {
+            FieldConversionMethod::Shared(pointer, strategy, parse(inner))
+        }
+        FieldConversionMethod::Vec(inner) => FieldConversionMethod::Vec(parse(inner)),
+        FieldConversionMethod::Iterator(inner) => FieldConversionMethod::Iterator(parse(inner)),
+        FieldConversionMethod::Bounded(inner, push) => {
+            FieldConversionMethod::Bounded(parse(inner), push)
+        }
+        FieldConversionMethod::Slice(inner) => FieldConversionMethod::Slice(parse(inner)),
+        FieldConversionMethod::Array(inner, len) => FieldConversionMethod::Array(parse(inner), len),
+        FieldConversionMethod::FixedArray(inner, is_target) => {
+            FieldConversionMethod::FixedArray(parse(inner), is_target)
+        }
+        method => method,
+    }
+}
+
+/// `none_on_err` swallows the error of converting the value of an `Option` field,
+/// leaving `None` in its place, so it only applies to an `Option` converted as is.
+fn decide_none_on_err_method(
+    field: &Field,
+    method: FieldConversionMethod,
+    conversion_type: ConversionMethod,
+    has_func: bool,
+) -> syn::Result<FieldConversionMethod> {
+    if !conversion_type.is_falliable() {
+        return Err(syn::Error::new(
+            field.span(),
+            "`none_on_err` is only supported on fallible conversions (`try_from`/`try_into`)",
+        ));
+    }
+    if has_func {
+        return Err(syn::Error::new(
+            field.span(),
+            "`none_on_err` cannot be combined with `with_func` or `with_func_opt`",
+        ));
+    }
+    match method {
+        FieldConversionMethod::Option(inner) => Ok(FieldConversionMethod::OptionNoneOnErr(inner)),
+        _ => Err(syn::Error::new_spanned(
+            &field.ty,
+            "`none_on_err` requires an `Option` field that is converted as is",
+        )),
+    }
+}
+
+/// Fields placed with `position` make up a whole tuple, so every converted
+/// field needs a distinct one, counting up from 0.
+fn check_positions(positions: &[(usize, Span)], converted: usize) -> syn::Result<()> {
+    let Some((_, span)) = positions.first() else {
+        return Ok(());
+    };
+
+    if positions.len() != converted {
+        return Err(syn::Error::new(
+            *span,
+            "`position` must be set on every converted field, or on none",
+        ));
+    }
+
+    let mut indices: Vec<usize> = positions.iter().map(|(index, _)| *index).collect();
+    indices.sort_unstable();
+    if indices.iter().copied().ne(0..converted) {
+        return Err(syn::Error::new(
+            *span,
+            format!(
+                "Field positions must be unique and contiguous from 0 to {}, got {:?}",
+                converted - 1,
+                indices
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parses the predicate of a `cfg_skip` attribute, e.g. `feature = "legacy"`.
+fn parse_cfg_predicate(field: &Field, predicate: &str) -> syn::Result<syn::Meta> {
+    if field.ident.is_none() {
+        return Err(syn::Error::new(
+            field.span(),
+            "`cfg_skip` is only supported on named fields",
+        ));
+    }
+
+    syn::parse_str(predicate).map_err(|e| {
+        syn::Error::new(
+            field.span(),
+            format!("Invalid cfg predicate `{}`: {}", predicate, e),
+        )
+    })
+}
+
+/// The type names registered with `container(...)` on the type and on the field.
+fn custom_container_names(
+    type_containers: &[CustomContainer],
+    field_containers: &[CustomContainer],
+) -> syn::Result<Vec<Ident>> {
+    type_containers
+        .iter()
+        .chain(field_containers)
+        .map(|container| {
+            if container.iter {
+                Ok(container.path.clone())
+            } else {
+                Err(syn::Error::new(
+                    container.path.span(),
+                    "Custom containers must be declared with `iter`, the only supported kind",
+                ))
+            }
+        })
+        .collect()
+}
+
+/// Overrides how the keys and values of a `HashMap` or `BTreeMap` field are converted.
+fn apply_map_sides(
+    field: &Field,
+    method: FieldConversionMethod,
+    map_keys: Option<&MapSide>,
+    map_values: Option<&MapSide>,
+) -> syn::Result<FieldConversionMethod> {
+    let side_method = |side: Option<&MapSide>, method| match side {
+        Some(MapSide::Skip) => Box::new(FieldConversionMethod::Keep),
+        Some(MapSide::Into) => Box::new(FieldConversionMethod::Into),
+        Some(MapSide::WithFunc(func)) => Box::new(FieldConversionMethod::WithFunc(func.clone())),
+        None => method,
+    };
+
+    match method {
+        FieldConversionMethod::HashMap(key_method, val_method) => {
+            Ok(FieldConversionMethod::HashMap(
+                side_method(map_keys, key_method),
+                side_method(map_values, val_method),
+            ))
+        }
+        FieldConversionMethod::BTreeMap(key_method, val_method) => {
+            Ok(FieldConversionMethod::BTreeMap(
+                side_method(map_keys, key_method),
+                side_method(map_values, val_method),
+            ))
+        }
+        _ => Err(syn::Error::new_spanned(
+            &field.ty,
+            "`map_keys` and `map_values` require a `HashMap` or `BTreeMap` field",
+        )),
+    }
+}
+
+/// Parses the condition of a `wrap_if` attribute, e.g. `!source.note.is_empty()`.
+fn parse_wrap_condition(field: &Field, condition: &str) -> syn::Result<syn::Expr> {
+    syn::parse_str(condition).map_err(|e| {
+        syn::Error::new(
+            field.span(),
+            format!("Invalid `wrap_if` condition `{}`: {}", condition, e),
+        )
+    })
+}
+
+/// `boxed_dyn = "Handler + Send"` names the bounds of the trait object, without the `dyn`.
+fn parse_trait_object(field: &Field, bounds: &str) -> syn::Result<syn::TypeTraitObject> {
+    syn::parse_str(&format!("dyn {}", bounds)).map_err(|e| {
+        syn::Error::new(
+            field.span(),
+            format!("Invalid `boxed_dyn` trait `{}`: {}", bounds, e),
+        )
+    })
+}
+
+/// Scoped attributes take precedence over top-level ones, which would silently
+/// drop a top-level `unwrap` in favor of a scoped `unwrap_or_default` (or vice versa).
+/// Reject those combinations instead of guessing which one was meant.
+fn check_scope_conflicts(
+    field: &Field,
+    unwrap: bool,
+    unwrap_or_default: bool,
+    scoped: &ConvertFieldAttr,
+) -> syn::Result<()> {
+    let conflict = if unwrap && scoped.unwrap_or_default {
+        Some(("unwrap", "unwrap_or_default"))
+    } else if unwrap_or_default && scoped.unwrap.enabled {
+        Some(("unwrap_or_default", "unwrap"))
+    } else {
+        None
+    };
+
+    match conflict {
+        Some((top, inner)) => Err(syn::Error::new(
+            field.span(),
+            format!(
+                "Conflicting field attributes: `{}` is set for all conversions but `{}` is set for this conversion. Use only one of them",
+                top, inner
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Primitive integer types, whose range-checked `TryFrom` errors describe themselves
+/// with `Display`.
+pub(crate) const INTEGER_TYPES: [&str; 12] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Std collections besides `Vec` whose elements are converted one by one and
+/// collected into the other side's collection.
+const ITERABLE_COLLECTIONS: [&str; 5] = [
+    "HashSet",
+    "BTreeSet",
+    "VecDeque",
+    "LinkedList",
+    "BinaryHeap",
+];
+
+/// How the types nested in a field are converted: the custom containers in scope,
+/// and whether its `Box`es and tuples are converted through, with `unbox` and
+/// `tuple_elements`.
+pub(crate) struct NestedTypes {
+    containers: Vec<Ident>,
+    unbox: bool,
+    tuple_elements: bool,
+}
+
 /// Recursively determines the conversion method for a type by inspecting
-/// nested container types (Option, Vec, HashMap).
-fn decide_field_method_for_type(ty: &syn::Type) -> FieldConversionMethod {
+/// nested container types (Option, Vec, sets, maps).
+fn decide_field_method_for_type(
+    ty: &syn::Type,
+    is_from: bool,
+    nested: &NestedTypes,
+) -> FieldConversionMethod {
     if let Some(inner_ty) = extract_inner_type(ty, "Option") {
-        let inner = decide_field_method_for_type(inner_ty);
+        let inner = decide_field_method_for_type(inner_ty, is_from, nested);
         return FieldConversionMethod::Option(Box::new(inner));
     }
+    if let Some((ok_ty, err_ty)) = extract_inner_type_pair(ty, "Result") {
+        let ok_inner = decide_field_method_for_type(ok_ty, is_from, nested);
+        let err_inner = decide_field_method_for_type(err_ty, is_from, nested);
+        return FieldConversionMethod::Result(Box::new(ok_inner), Box::new(err_inner));
+    }
+    // With `unbox`, a boxed value, e.g. a child of a recursive tree, is converted in
+    // place. `Box<str>`, boxed slices and trait objects can't be moved out of the box,
+    // so they're left alone
+    if nested.unbox
+        && let Some(inner_ty) = extract_inner_type(ty, "Box")
+        && !is_unsized(inner_ty)
+    {
+        let inner = decide_field_method_for_type(inner_ty, is_from, nested);
+        return FieldConversionMethod::Boxed(Box::new(inner));
+    }
+    if let Some(inner_ty) = extract_cow_inner(ty) {
+        return decide_cow_method(inner_ty, is_from, nested);
+    }
+    if cfg!(feature = "heapless")
+        && let Some(inner_ty) = extract_heapless_vec_inner(ty)
+    {
+        let inner = Box::new(decide_field_method_for_type(inner_ty, is_from, nested));
+        return if is_from {
+            FieldConversionMethod::Bounded(inner, parse_quote!(::heapless::Vec::push))
+        } else {
+            FieldConversionMethod::Iterator(inner)
+        };
+    }
+    if cfg!(feature = "heapless")
+        && let Some((key_ty, val_ty)) = extract_inner_type_pair(ty, "FnvIndexMap")
+    {
+        let key_inner = Box::new(decide_field_method_for_type(key_ty, is_from, nested));
+        let val_inner = Box::new(decide_field_method_for_type(val_ty, is_from, nested));
+        return if is_from {
+            FieldConversionMethod::BoundedMap(
+                key_inner,
+                val_inner,
+                parse_quote!(::heapless::IndexMap::insert),
+            )
+        } else {
+            FieldConversionMethod::Pairs(key_inner, val_inner)
+        };
+    }
     if let Some(inner_ty) = extract_inner_type(ty, "Vec") {
-        let inner = decide_field_method_for_type(inner_ty);
+        let inner = decide_field_method_for_type(inner_ty, is_from, nested);
+        return FieldConversionMethod::Vec(Box::new(inner));
+    }
+    // Other std collections are collected too, so the two sides may hold different
+    // kinds, e.g. a `Vec` deduplicated into a `HashSet`
+    if let Some(inner_ty) = ITERABLE_COLLECTIONS
+        .iter()
+        .find_map(|collection| extract_inner_type(ty, collection))
+    {
+        let inner = decide_field_method_for_type(inner_ty, is_from, nested);
+        return FieldConversionMethod::Iterator(Box::new(inner));
+    }
+    // `SmallVec<[T; N]>` spills onto the heap, so it's collected like any other collection
+    if cfg!(feature = "smallvec")
+        && let Some(syn::Type::Array(array)) = extract_inner_type(ty, "SmallVec")
+    {
+        let inner = decide_field_method_for_type(&array.elem, is_from, nested);
+        return FieldConversionMethod::Iterator(Box::new(inner));
+    }
+    if cfg!(feature = "arrayvec")
+        && let Some(inner_ty) = extract_inner_type(ty, "ArrayVec")
+    {
+        let inner = Box::new(decide_field_method_for_type(inner_ty, is_from, nested));
+        return if is_from {
+            FieldConversionMethod::Bounded(inner, parse_quote!(::arrayvec::ArrayVec::try_push))
+        } else {
+            FieldConversionMethod::Iterator(inner)
+        };
+    }
+    // User-registered containers are iterated and collected like a `Vec`
+    if let Some(inner_ty) = nested
+        .containers
+        .iter()
+        .find_map(|container| extract_inner_type(ty, &container.to_string()))
+    {
+        let inner = decide_field_method_for_type(inner_ty, is_from, nested);
         return FieldConversionMethod::Iterator(Box::new(inner));
     }
-    if let Some((key_ty, val_ty)) = extract_hashmap_inner_types(ty) {
-        let key_inner = decide_field_method_for_type(key_ty);
-        let val_inner = decide_field_method_for_type(val_ty);
+    // Nothing can be collected into a borrowed slice, so one on the target is passed through
+    if let Some(inner_ty) = extract_slice_element(ty)
+        && !is_from
+    {
+        let inner = decide_field_method_for_type(inner_ty, is_from, nested);
+        return FieldConversionMethod::Slice(Box::new(inner));
+    }
+    if let Some((key_ty, val_ty)) = extract_inner_type_pair(ty, "HashMap") {
+        let key_inner = decide_field_method_for_type(key_ty, is_from, nested);
+        let val_inner = decide_field_method_for_type(val_ty, is_from, nested);
         return FieldConversionMethod::HashMap(Box::new(key_inner), Box::new(val_inner));
     }
+    if let Some((key_ty, val_ty)) = extract_inner_type_pair(ty, "BTreeMap") {
+        let key_inner = decide_field_method_for_type(key_ty, is_from, nested);
+        let val_inner = decide_field_method_for_type(val_ty, is_from, nested);
+        return FieldConversionMethod::BTreeMap(Box::new(key_inner), Box::new(val_inner));
+    }
+    if let syn::Type::Array(array) = ty {
+        let inner = decide_field_method_for_type(&array.elem, is_from, nested);
+        return FieldConversionMethod::FixedArray(Box::new(inner), is_from);
+    }
+    // With `tuple_elements`, a tuple is converted element by element, otherwise as a
+    // whole, e.g. into a type implementing `From<(A, B)>`
+    if nested.tuple_elements
+        && let syn::Type::Tuple(tuple) = ty
+        && !tuple.elems.is_empty()
+    {
+        let elems = tuple
+            .elems
+            .iter()
+            .map(|elem| decide_field_method_for_type(elem, is_from, nested))
+            .collect();
+        return FieldConversionMethod::Tuple(elems);
+    }
+    // Converting from the other type, an integer field is the target of a narrowing
+    if is_from
+        && let syn::Type::Path(type_path) = ty
+        && let Some(ident) = type_path.path.get_ident()
+        && INTEGER_TYPES.iter().any(|integer| ident == integer)
+    {
+        return FieldConversionMethod::Integer(ident.clone());
+    }
     FieldConversionMethod::Plain
 }
 
-pub(crate) fn decide_field_method(
+/// `shared` converts the value behind this field's `Arc<T>` or `Rc<T>`, and wraps
+/// the result in the same pointer.
+fn decide_shared_method(
     field: &Field,
     is_from: bool,
-    unwrap: bool,
+    strategy: SharedStrategy,
+    conversion_type: ConversionMethod,
+    nested: &NestedTypes,
+) -> syn::Result<FieldConversionMethod> {
+    if matches!(strategy, SharedStrategy::TryUnwrap) && !conversion_type.is_falliable() {
+        return Err(syn::Error::new(
+            field.span(),
+            "`shared = \"try_unwrap\"` is only supported on fallible conversions (`try_from`/`try_into`). Use `shared = \"clone\"` instead",
+        ));
+    }
+    let (pointer, inner_ty) = ["Arc", "Rc"]
+        .into_iter()
+        .find_map(|pointer| {
+            extract_inner_type(&field.ty, pointer).map(|inner_ty| (pointer, inner_ty))
+        })
+        .ok_or_else(|| {
+            syn::Error::new_spanned(&field.ty, "`shared` requires an `Arc<T>` or `Rc<T>` field")
+        })?;
+    Ok(FieldConversionMethod::Shared(
+        Ident::new(pointer, field.ty.span()),
+        strategy,
+        Box::new(decide_field_method_for_type(inner_ty, is_from, nested)),
+    ))
+}
+
+/// A `Cow<'a, T>` is converted through its owned value, e.g. the `String` of a
+/// `Cow<str>` or the `Vec` of a `Cow<[T]>`, converted element-wise. On the target,
+/// the converted value is wrapped in `Cow::Owned`, except for a `Cow<str>`, which
+/// is converted into as a whole so that a borrowed `&str` stays borrowed.
+fn decide_cow_method(
+    inner_ty: &syn::Type,
+    is_from: bool,
+    nested: &NestedTypes,
+) -> FieldConversionMethod {
+    let is_str = matches!(inner_ty, syn::Type::Path(type_path) if type_path.path.is_ident("str"));
+    if is_from && is_str {
+        return FieldConversionMethod::Plain;
+    }
+    let owned = match inner_ty {
+        syn::Type::Slice(slice) => FieldConversionMethod::Vec(Box::new(
+            decide_field_method_for_type(&slice.elem, is_from, nested),
+        )),
+        _ if is_str => FieldConversionMethod::Plain,
+        _ => decide_field_method_for_type(inner_ty, is_from, nested),
+    };
+    if is_from {
+        FieldConversionMethod::CowOwned(Box::new(owned))
+    } else {
+        FieldConversionMethod::Cow(Box::new(owned))
+    }
+}
+
+/// Whether a type is one of the unsized types commonly boxed: `str`, a slice or a
+/// trait object.
+fn is_unsized(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Slice(_) | syn::Type::TraitObject(_) => true,
+        syn::Type::Path(type_path) => type_path.path.is_ident("str"),
+        _ => false,
+    }
+}
+
+/// `to_array` converts between this field's `Vec<T>` and the other side's `[U; N]`,
+/// or between the other side's `Vec<T>` and this field's `[U; N]`.
+fn decide_array_method(
+    field: &Field,
+    is_from: bool,
+    len: usize,
+    nested: &NestedTypes,
+) -> syn::Result<FieldConversionMethod> {
+    let element_ty = if is_from {
+        match &field.ty {
+            syn::Type::Array(array) => {
+                if let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(array_len),
+                    ..
+                }) = &array.len
+                    && array_len.base10_parse::<usize>()? != len
+                {
+                    return Err(syn::Error::new_spanned(
+                        &array.len,
+                        format!("`to_array = {}` doesn't match the array length", len),
+                    ));
+                }
+                Some(&*array.elem)
+            }
+            _ => None,
+        }
+    } else {
+        extract_inner_type(&field.ty, "Vec")
+    };
+
+    let element_ty = element_ty.ok_or_else(|| {
+        let expected = if is_from { "an array" } else { "a `Vec`" };
+        syn::Error::new_spanned(
+            &field.ty,
+            format!("`to_array` requires the field to be {}", expected),
+        )
+    })?;
+
+    Ok(FieldConversionMethod::Array(
+        Box::new(decide_field_method_for_type(element_ty, is_from, nested)),
+        len,
+    ))
+}
+
+fn decide_field_method(
+    field: &Field,
+    is_from: bool,
+    unwrap: &Unwrap,
     unwrap_or_default: bool,
+    nested: &NestedTypes,
 ) -> syn::Result<FieldConversionMethod> {
     let is_option = is_surrounding_type(&field.ty, "Option");
+    let expect = unwrap.expect.clone();
+    let unwrap = unwrap.enabled;
 
     if unwrap && unwrap_or_default {
         return Err(syn::Error::new_spanned(
@@ -264,9 +1543,12 @@ pub(crate) fn decide_field_method(
             (true, false) => {
                 // Option<T> -> T: unwrap, then recursively convert inner
                 let inner_ty = extract_inner_type(&field.ty, "Option").unwrap();
-                let inner_method = decide_field_method_for_type(inner_ty);
+                let inner_method = decide_field_method_for_type(inner_ty, is_from, nested);
                 return if unwrap {
-                    Ok(FieldConversionMethod::UnwrapOption(Box::new(inner_method)))
+                    Ok(FieldConversionMethod::UnwrapOption(
+                        Box::new(inner_method),
+                        expect,
+                    ))
                 } else {
                     Ok(FieldConversionMethod::UnwrapOrDefault(Box::new(
                         inner_method,
@@ -276,14 +1558,18 @@ pub(crate) fn decide_field_method(
             (true, true) => {
                 // From direction: T -> Option<T>, wrap in Some
                 let inner_ty = extract_inner_type(&field.ty, "Option").unwrap();
-                let inner_method = decide_field_method_for_type(inner_ty);
+                let inner_method = decide_field_method_for_type(inner_ty, is_from, nested);
                 return Ok(FieldConversionMethod::SomeOption(Box::new(inner_method)));
             }
             (false, true) => {
-                // From direction: other side has Option<T>, self has T
-                let inner_method = decide_field_method_for_type(&field.ty);
+                // From direction: other side has Option<T>, self has T. The other side isn't
+                // visible here, so the generated code pins it to `Option` instead
+                let inner_method = decide_field_method_for_type(&field.ty, is_from, nested);
                 return if unwrap {
-                    Ok(FieldConversionMethod::UnwrapOption(Box::new(inner_method)))
+                    Ok(FieldConversionMethod::UnwrapOption(
+                        Box::new(inner_method),
+                        expect,
+                    ))
                 } else {
                     Ok(FieldConversionMethod::UnwrapOrDefault(Box::new(
                         inner_method,
@@ -300,7 +1586,7 @@ pub(crate) fn decide_field_method(
     }
 
     // No unwrap attributes — determine method recursively from the type
-    Ok(decide_field_method_for_type(&field.ty))
+    Ok(decide_field_method_for_type(&field.ty, is_from, nested))
 }
 
 impl ToTokens for FieldIdentifier {
@@ -318,6 +1604,18 @@ impl ToTokens for FieldIdentifier {
 }
 
 impl FieldIdentifier {
+    /// The field name as a string literal, used as a lookup key for map sources.
+    pub(crate) fn as_key(&self) -> syn::LitStr {
+        match self {
+            FieldIdentifier::Named(ident) => {
+                syn::LitStr::new(&ident.unraw().to_string(), ident.span())
+            }
+            FieldIdentifier::Unnamed(index) => {
+                syn::LitStr::new(&index.to_string(), Span::call_site())
+            }
+        }
+    }
+
     pub(crate) fn as_named(&self) -> TokenStream2 {
         match self {
             FieldIdentifier::Named(ident) => quote! { #ident },
@@ -1,5 +1,11 @@
-use darling::{FromDeriveInput, FromMeta};
-use syn::{DeriveInput, Path};
+use std::collections::HashMap;
+
+use darling::{FromDeriveInput, FromMeta, util::Flag};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{DeriveInput, Path, spanned::Spanned};
+
+use crate::util::RenameRule;
 
 #[derive(Clone, Debug)]
 pub(crate) struct ConversionMeta {
@@ -8,7 +14,150 @@ pub(crate) struct ConversionMeta {
     pub(crate) method: ConversionMethod,
     // Wether we add ..Default::default() to conversions
     pub(crate) default_allowed: bool,
-    pub(crate) validate: Option<Path>,
+    // Functions or inline closures checking the source before it's converted, in order
+    pub(crate) validate: Vec<syn::Expr>,
+    // Function taking `&mut` source, run on it before anything else is done with it
+    pub(crate) normalize: Option<Path>,
+    // Whether the source is a map whose entries are parsed into the fields
+    pub(crate) from_map: bool,
+    // Whether fallible field conversions report all failures instead of the first one
+    pub(crate) accumulate_errors: bool,
+    // Source fields which fail the conversion when equal to their `Default` value
+    pub(crate) reject_defaults: Vec<syn::Member>,
+    // Whether the other type is a tuple (usually behind a type alias), built with tuple syntax
+    pub(crate) target_tuple: bool,
+    // Whether the impl converts from a reference to the source
+    pub(crate) by_ref: bool,
+    // Whether the impl converts from an `Arc` of the source, cloning it out if shared
+    pub(crate) from_arc: bool,
+    // Whether the source's fields are read through getter methods of the same name
+    pub(crate) via_getters: bool,
+    // Case convention applied to enum variant names without an explicit `rename`
+    pub(crate) rename_all: Option<RenameRule>,
+    // Other type's variant name for each of the deriving enum's variants listed in `map_variants`
+    pub(crate) map_variants: HashMap<syn::Ident, String>,
+    // Hand-written function performing the whole conversion, replacing the generated body
+    pub(crate) body: Option<Path>,
+    // Variant of the target enum that the struct's fields are wrapped into
+    pub(crate) wrap_variant: Option<syn::Ident>,
+    // Whether the target is a newtype built from the struct's single field
+    pub(crate) wrap_newtype: bool,
+    // Number of target fields the conversion is expected to produce
+    pub(crate) expect_fields: Option<usize>,
+    // Fields that only exist on the target, each filled with its own `Default`. With
+    // `from`/`try_from` they are fields of the deriving type
+    pub(crate) default_fields: Vec<syn::Ident>,
+    // Target fields built from a group of the source's flat fields
+    pub(crate) nests: Vec<Nest>,
+    // Target fields set to an expression over `source`, evaluated before any field is moved
+    pub(crate) computes: Vec<Compute>,
+    // Whether a failed conversion hands a clone of the source back along with the error
+    pub(crate) recoverable: bool,
+    // Whether an enum converts into the (renamed) name of its variant, ignoring the data
+    pub(crate) variant_names: bool,
+    // Generic containers declared on the type, converted element-wise
+    pub(crate) containers: Vec<CustomContainer>,
+    // `Vec` and `HashMap` fields are built with their capacity reserved up front
+    pub(crate) reserve_capacity: bool,
+    // Doc comment attached to the generated impl
+    pub(crate) doc: Option<String>,
+    // Inline hint attached to the generated `from`/`try_from`
+    pub(crate) inline: Option<Inline>,
+    // Inherent method of the deriving type generated instead of the trait impl,
+    // converting from `&self`
+    pub(crate) ref_method: Option<syn::Ident>,
+    // Builder of the target whose setters are called with the converted fields, returned
+    // by an inherent `into_partial` instead of the trait impl
+    pub(crate) builder: Option<Path>,
+    // Inherent `const fn` generated instead of the trait impl, taking the source by value
+    pub(crate) const_fn: Option<syn::Ident>,
+    // Visibility of the deriving type, given to the `ref_method` and `const_fn`
+    pub(crate) vis: syn::Visibility,
+    // Whether every field needs a `#[convert(...)]` attribute, so none is converted unnoticed
+    pub(crate) require_explicit: bool,
+}
+
+/// `inline` adds `#[inline]` to the generated function, `inline = "always"` and
+/// `inline = "never"` the matching `#[inline(..)]`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Inline {
+    Hint,
+    Always,
+    Never,
+}
+
+impl FromMeta for Inline {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Inline::Hint)
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "always" => Ok(Inline::Always),
+            "never" => Ok(Inline::Never),
+            _ => Err(darling::Error::unknown_value(value)),
+        }
+    }
+}
+
+/// `validate = "check"` (or an inline closure), or `validate("check_a", "check_b")`
+/// to run several checks in order.
+#[derive(Debug)]
+pub(crate) struct Validators(Vec<syn::Expr>);
+
+impl FromMeta for Validators {
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        syn::Expr::from_expr(expr).map(|expr| Validators(vec![expr]))
+    }
+
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                darling::ast::NestedMeta::Lit(syn::Lit::Str(func)) => {
+                    func.parse().map_err(darling::Error::from)
+                }
+                darling::ast::NestedMeta::Meta(syn::Meta::Path(func)) => {
+                    Ok(syn::Expr::Path(syn::ExprPath {
+                        attrs: Vec::new(),
+                        qself: None,
+                        path: func.clone(),
+                    }))
+                }
+                _ => {
+                    Err(darling::Error::custom("Expected a validator function path")
+                        .with_span(item))
+                }
+            })
+            .collect::<darling::Result<_>>()
+            .map(Validators)
+    }
+}
+
+/// `container(path = "Grid", iter)`: a user-defined generic container whose
+/// elements are converted one by one, like a `Vec`.
+#[derive(FromMeta, Clone, Debug)]
+pub(crate) struct CustomContainer {
+    pub(crate) path: syn::Ident,
+    #[darling(default)]
+    pub(crate) iter: bool,
+}
+
+/// `nest(field = "address", path = "Address", from = ["street", "city"])`: the listed
+/// source fields become the fields of a nested `address: Address { .. }` literal.
+#[derive(FromMeta, Clone, Debug)]
+pub(crate) struct Nest {
+    pub(crate) field: syn::Ident,
+    pub(crate) path: Path,
+    pub(crate) from: Vec<syn::LitStr>,
+}
+
+/// `compute(field = "full_name", expr = "format!(...)")`: a target field set to an
+/// expression, which may read any number of the source's fields through `source`.
+#[derive(FromMeta, Clone, Debug)]
+pub(crate) struct Compute {
+    pub(crate) field: syn::Ident,
+    pub(crate) expr: syn::Expr,
 }
 
 impl ConversionMeta {
@@ -19,6 +168,105 @@ impl ConversionMeta {
             self.target_name.clone()
         }
     }
+
+    /// Lifetimes named in the paths, e.g. `'a` in `View<'a>`, declared on the impl.
+    fn lifetimes(&self) -> Vec<&syn::Lifetime> {
+        [&self.source_name, &self.target_name]
+            .into_iter()
+            .flat_map(|path| &path.segments)
+            .filter_map(|segment| match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => Some(&args.args),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Lifetime(lifetime) => Some(lifetime),
+                _ => None,
+            })
+            .fold(Vec::new(), |mut lifetimes, lifetime| {
+                // Both sides often borrow for the same lifetime
+                if !lifetimes.contains(&lifetime) {
+                    lifetimes.push(lifetime);
+                }
+                lifetimes
+            })
+    }
+
+    /// Attributes placed on the generated impl block. Like std's derives, it's
+    /// marked as derived and kept out of the user's lints, which it can't act on.
+    pub(crate) fn impl_attrs(&self) -> TokenStream2 {
+        let doc = self.doc.as_ref().map(|doc| quote! { #[doc = #doc] });
+        quote! {
+            #doc
+            #[automatically_derived]
+            #[allow(clippy::all, unused)]
+        }
+    }
+
+    /// How the generated code names the target when building it. Inside the trait
+    /// impl that's `Self`, which avoids spelling out generic arguments. A `ref_method`,
+    /// or an `into` `const_fn`, is implemented on the source, so it uses the target's
+    /// path in expression form.
+    pub(crate) fn constructor(&self) -> TokenStream2 {
+        if self.ref_method.is_none() && (self.const_fn.is_none() || self.method.is_from()) {
+            return quote! { Self };
+        }
+
+        let mut path = self.target_name.clone();
+        for segment in &mut path.segments {
+            if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                args.colon2_token = Some(Default::default());
+            }
+        }
+        quote! { #path }
+    }
+
+    /// Attributes placed on the generated `from`/`try_from` function.
+    pub(crate) fn fn_attrs(&self) -> TokenStream2 {
+        match self.inline {
+            None => quote! {},
+            Some(Inline::Hint) => quote! { #[inline] },
+            Some(Inline::Always) => quote! { #[inline(always)] },
+            Some(Inline::Never) => quote! { #[inline(never)] },
+        }
+    }
+
+    pub(crate) fn impl_generics(&self) -> TokenStream2 {
+        let lifetimes = self.lifetimes();
+        if lifetimes.is_empty() {
+            quote! {}
+        } else {
+            quote! { <#(#lifetimes),*> }
+        }
+    }
+
+    /// Statement starting each generated body. With `from_arc`, it shadows the
+    /// `Arc` argument with the owned source, only cloning it if the `Arc` is shared.
+    pub(crate) fn owned_source(&self) -> TokenStream2 {
+        if self.from_arc {
+            quote! { let source = ::std::sync::Arc::unwrap_or_clone(source); }
+        } else {
+            quote! {}
+        }
+    }
+
+    /// The type the conversion consumes. With `by_ref` the source is borrowed for
+    /// the first lifetime of the paths, so borrowed data can be moved into the target.
+    /// With `from_arc` it's an `Arc` holding the source.
+    pub(crate) fn source_type(&self) -> TokenStream2 {
+        let source_name = &self.source_name;
+        if self.from_arc {
+            return quote! { ::std::sync::Arc<#source_name> };
+        }
+        if !self.by_ref {
+            return quote! { #source_name };
+        }
+
+        match self.lifetimes().first() {
+            Some(lifetime) => quote! { &#lifetime #source_name },
+            None => quote! { &#source_name },
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -37,101 +285,722 @@ impl ConversionMethod {
     pub(crate) fn is_falliable(&self) -> bool {
         matches!(self, ConversionMethod::TryInto | ConversionMethod::TryFrom)
     }
+
+    /// The name of the `#[convert(...)]` list declaring the conversion.
+    pub(crate) fn attribute_name(&self) -> &'static str {
+        match self {
+            ConversionMethod::Into => "into",
+            ConversionMethod::TryInto => "try_into",
+            ConversionMethod::From => "from",
+            ConversionMethod::TryFrom => "try_from",
+        }
+    }
 }
 
-fn ident_to_path(ident: &syn::Ident) -> syn::Path {
+/// The deriving type is always referred to by its local ident, since the macro
+/// has no way to know its module. The other side can be any path, e.g.
+/// `crate::api::Model`, so same-named types in different modules convert fine.
+/// Lifetime parameters of the deriving type are kept, e.g. for a borrowing view
+/// `View<'a>`, and become impl generics like the ones in the other path.
+fn self_path(ident: &syn::Ident, generics: &syn::Generics) -> syn::Path {
+    let lifetimes: Vec<_> = generics.lifetimes().map(|param| &param.lifetime).collect();
+    let arguments = if lifetimes.is_empty() {
+        syn::PathArguments::None
+    } else {
+        syn::PathArguments::AngleBracketed(syn::parse_quote! { <#(#lifetimes),*> })
+    };
     syn::Path {
         leading_colon: None,
         segments: std::iter::once(syn::PathSegment {
             ident: ident.clone(),
-            arguments: syn::PathArguments::None,
+            arguments,
         })
         .collect(),
     }
 }
 
+/// Parses a list of field names, e.g. `reject_defaults("name", "0")`, pointing at the
+/// literal that isn't one.
+fn parse_members(meta: &syn::Meta) -> darling::Result<Vec<syn::Member>> {
+    Vec::<syn::LitStr>::from_meta(meta)?
+        .iter()
+        .map(|field| {
+            field.parse().map_err(|e| {
+                darling::Error::custom(format!("Invalid field name `{}`: {}", field.value(), e))
+                    .with_span(field)
+            })
+        })
+        .collect()
+}
+
 #[derive(FromMeta, Debug)]
 struct ConvAttrs {
     path: Path,
     #[darling(default)]
     default: bool,
     #[darling(default)]
-    validate: Option<Path>,
+    validate: Option<Validators>,
+    #[darling(default)]
+    normalize: Option<Path>,
+    #[darling(default)]
+    from_map: bool,
+    #[darling(default)]
+    accumulate_errors: bool,
+    #[darling(default, with = parse_members)]
+    reject_defaults: Vec<syn::Member>,
+    #[darling(default)]
+    target_tuple: bool,
+    #[darling(default)]
+    by_ref: bool,
+    #[darling(default)]
+    from_arc: Flag,
+    #[darling(default)]
+    via_getters: bool,
+    #[darling(default)]
+    rename_all: Option<RenameRule>,
+    #[darling(default)]
+    map_variants: HashMap<syn::Ident, String>,
+    #[darling(default)]
+    body: Option<Path>,
+    #[darling(default)]
+    wrap_variant: Option<syn::Ident>,
+    #[darling(default)]
+    wrap_newtype: bool,
+    #[darling(default)]
+    expect_fields: Option<usize>,
+    #[darling(default, multiple)]
+    default_field: Vec<syn::Ident>,
+    #[darling(default, multiple)]
+    nest: Vec<Nest>,
+    #[darling(default, multiple)]
+    compute: Vec<Compute>,
+    #[darling(default)]
+    recoverable: bool,
+    #[darling(default)]
+    option_impl: bool,
+    #[darling(default)]
+    variant_names: bool,
+    #[darling(default)]
+    doc: Option<String>,
+    #[darling(default)]
+    inline: Option<Inline>,
+    #[darling(default)]
+    ref_method: Option<syn::Ident>,
+    #[darling(default)]
+    const_fn: Option<syn::Ident>,
+    #[darling(default)]
+    builder: Option<Path>,
+    #[darling(default)]
+    require_explicit: bool,
+    #[darling(default)]
+    deprecated: Option<String>,
+    // The options as written, to point errors at them
+    #[darling(skip)]
+    keys: Vec<Path>,
+}
+
+impl ConvAttrs {
+    /// Where the `name` option was written, or the conversion's path if it wasn't.
+    fn key_span(&self, name: &str) -> proc_macro2::Span {
+        self.keys
+            .iter()
+            .find(|key| key.is_ident(name))
+            .unwrap_or(&self.path)
+            .span()
+    }
+}
+
+/// Parses a conversion's options, keeping their keys.
+fn parse_conv_attrs(meta: &syn::Meta) -> darling::Result<ConvAttrs> {
+    let mut attr = ConvAttrs::from_meta(meta)?;
+    if let syn::Meta::List(list) = meta {
+        attr.keys = darling::ast::NestedMeta::parse_meta_list(list.tokens.clone())?
+            .into_iter()
+            .filter_map(|item| match item {
+                darling::ast::NestedMeta::Meta(meta) => Some(meta.path().clone()),
+                darling::ast::NestedMeta::Lit(_) => None,
+            })
+            .collect();
+    }
+    Ok(attr)
+}
+
+/// A custom `body` replaces all field analysis, so options affecting it would be ignored.
+fn check_body(attr: &ConvAttrs) -> syn::Result<()> {
+    if attr.body.is_none() {
+        return Ok(());
+    }
+    let ignored = [
+        ("default", attr.default),
+        ("validate", attr.validate.is_some()),
+        ("from_map", attr.from_map),
+        ("accumulate_errors", attr.accumulate_errors),
+        ("reject_defaults", !attr.reject_defaults.is_empty()),
+        ("target_tuple", attr.target_tuple),
+        ("via_getters", attr.via_getters),
+        ("rename_all", attr.rename_all.is_some()),
+        ("map_variants", !attr.map_variants.is_empty()),
+        ("wrap_variant", attr.wrap_variant.is_some()),
+        ("wrap_newtype", attr.wrap_newtype),
+        ("expect_fields", attr.expect_fields.is_some()),
+        ("default_field", !attr.default_field.is_empty()),
+        ("nest", !attr.nest.is_empty()),
+        ("compute", !attr.compute.is_empty()),
+        ("variant_names", attr.variant_names),
+        ("ref_method", attr.ref_method.is_some()),
+        ("const_fn", attr.const_fn.is_some()),
+        ("builder", attr.builder.is_some()),
+        ("require_explicit", attr.require_explicit),
+    ];
+    match ignored.iter().find(|(_, set)| *set) {
+        Some((name, _)) => Err(syn::Error::new(
+            attr.key_span(name),
+            format!("`{}` cannot be combined with `body`", name),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// `impl From<Option<Source>> for Option<Target>` is rejected by the orphan rules,
+/// since `Option` isn't local even when both its arguments are.
+fn check_option_impl(attr: &ConvAttrs) -> syn::Result<()> {
+    if attr.option_impl {
+        return Err(syn::Error::new(
+            attr.key_span("option_impl"),
+            "`option_impl` can't be generated: `Option` is a foreign type, so the orphan rules forbid implementing `From` between two `Option`s. Convert with `option.map(Into::into)` instead",
+        ));
+    }
+    Ok(())
+}
+
+/// Deprecation can't be attached to trait impls: rustc denies `#[deprecated]` on them
+/// (`useless_deprecated`), since a trait impl can't be named to trigger the warning.
+fn check_deprecated(attr: &ConvAttrs) -> syn::Result<()> {
+    if attr.deprecated.is_some() {
+        return Err(syn::Error::new(
+            attr.key_span("deprecated"),
+            "`deprecated` can't be attached to the generated impl: rustc rejects `#[deprecated]` on trait impls. Use `doc` to point users at the replacement instead",
+        ));
+    }
+    Ok(())
+}
+
+/// `from_arc` takes ownership of the source out of the `Arc`, which neither a
+/// borrowed source nor a recoverable one (handing the `Arc` back) fits with.
+fn check_from_arc(attr: &ConvAttrs) -> syn::Result<()> {
+    if !attr.from_arc.is_present() {
+        return Ok(());
+    }
+    let conflicting = if attr.by_ref {
+        "by_ref"
+    } else if attr.recoverable {
+        "recoverable"
+    } else {
+        return Ok(());
+    };
+    Err(syn::Error::new(
+        attr.from_arc.span(),
+        format!("`from_arc` cannot be combined with `{}`", conflicting),
+    ))
+}
+
+/// `normalize` mutates the source in place, so it has to own it.
+fn check_normalize(attr: &ConvAttrs) -> syn::Result<()> {
+    let Some(normalize) = &attr.normalize else {
+        return Ok(());
+    };
+    let borrowing = if attr.by_ref {
+        "by_ref"
+    } else if attr.ref_method.is_some() {
+        "ref_method"
+    } else {
+        return Ok(());
+    };
+    Err(syn::Error::new_spanned(
+        normalize,
+        format!(
+            "`normalize` cannot be combined with `{}`, since the borrowed source can't be mutated",
+            borrowing
+        ),
+    ))
+}
+
+/// A `const fn` can only move the owned source's fields, so options that borrow,
+/// unwrap or mutate the source first don't fit with it.
+fn check_const_fn(attr: &ConvAttrs) -> syn::Result<()> {
+    let Some(const_fn) = &attr.const_fn else {
+        return Ok(());
+    };
+    let ignored = [
+        ("by_ref", attr.by_ref),
+        ("from_arc", attr.from_arc.is_present()),
+        ("normalize", attr.normalize.is_some()),
+    ];
+    match ignored.iter().find(|(_, set)| *set) {
+        Some((name, _)) => Err(syn::Error::new(
+            const_fn.span(),
+            format!("`const_fn` cannot be combined with `{}`", name),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// `builder` replaces the trait impl with a method consuming the source, so options
+/// generating another kind of impl, or another source, don't fit with it.
+fn check_builder(attr: &ConvAttrs) -> syn::Result<()> {
+    let Some(builder) = &attr.builder else {
+        return Ok(());
+    };
+    let ignored = [
+        ("by_ref", attr.by_ref),
+        ("from_arc", attr.from_arc.is_present()),
+        ("normalize", attr.normalize.is_some()),
+        ("recoverable", attr.recoverable),
+        ("ref_method", attr.ref_method.is_some()),
+        ("const_fn", attr.const_fn.is_some()),
+    ];
+    match ignored.iter().find(|(_, set)| *set) {
+        Some((name, _)) => Err(syn::Error::new_spanned(
+            builder,
+            format!("`builder` cannot be combined with `{}`", name),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// With `ref_method`, the conversion is also generated as a method borrowing the
+/// source, whose fields are cloned like with `by_ref`.
+fn ref_method_conversion(meta: &ConversionMeta, name: syn::Ident) -> ConversionMeta {
+    ConversionMeta {
+        ref_method: Some(name),
+        by_ref: true,
+        from_arc: false,
+        recoverable: false,
+        doc: None,
+        ..meta.clone()
+    }
+}
+
+/// With `const_fn`, the conversion is also generated as an inherent `const fn`,
+/// since trait methods like `From::from` can't be `const`.
+fn const_fn_conversion(meta: &ConversionMeta, name: syn::Ident) -> ConversionMeta {
+    ConversionMeta {
+        const_fn: Some(name),
+        doc: None,
+        ..meta.clone()
+    }
 }
 
 #[derive(FromDeriveInput)]
 #[darling(attributes(convert))]
 struct Conversions {
     ident: syn::Ident,
+    vis: syn::Visibility,
+    generics: syn::Generics,
+    #[darling(default)]
+    debug: bool,
     #[darling(default, multiple)]
+    container: Vec<CustomContainer>,
+    #[darling(default)]
+    reserve_capacity: bool,
+    #[darling(default)]
+    roundtrip_test: bool,
+    #[darling(default, multiple, with = parse_conv_attrs)]
     into: Vec<ConvAttrs>,
 
-    #[darling(default, multiple)]
+    #[darling(default, multiple, with = parse_conv_attrs)]
     try_into: Vec<ConvAttrs>,
 
-    #[darling(default, multiple)]
+    #[darling(default, multiple, with = parse_conv_attrs)]
     from: Vec<ConvAttrs>,
 
-    #[darling(default, multiple)]
+    #[darling(default, multiple, with = parse_conv_attrs)]
     try_from: Vec<ConvAttrs>,
 }
 
-pub(crate) fn extract_conversions(ast: &DeriveInput) -> Vec<ConversionMeta> {
-    let conversions_data = match Conversions::from_derive_input(ast) {
-        Ok(v) => v,
-        Err(e) => {
-            // You'd typically emit this as a compile error
-            panic!("Error parsing conversion attributes: {}", e);
-        }
-    };
+/// The conversions requested on the deriving type, plus options that apply to all of them.
+pub(crate) struct ExtractedConversions {
+    pub(crate) conversions: Vec<ConversionMeta>,
+    // Whether the generated code is printed to stderr at compile time
+    pub(crate) debug: bool,
+    // Whether round trip assertion helpers are generated on the deriving type
+    pub(crate) roundtrip_test: bool,
+}
+
+pub(crate) fn extract_conversions(ast: &DeriveInput) -> syn::Result<ExtractedConversions> {
+    let conversions_data = Conversions::from_derive_input(ast).map_err(|e| {
+        // Point at the offending attribute value where darling knows it
+        syn::Error::new(
+            e.explicit_span().unwrap_or_else(|| ast.ident.span()),
+            format!("Error parsing conversion attributes: {}", e),
+        )
+    })?;
 
     let mut result = Vec::new();
 
     for attr in conversions_data.into {
+        check_body(&attr)?;
+        check_option_impl(&attr)?;
+        check_deprecated(&attr)?;
+        check_from_arc(&attr)?;
+        check_normalize(&attr)?;
+        check_const_fn(&attr)?;
+        check_builder(&attr)?;
+        if attr.via_getters {
+            return Err(syn::Error::new(
+                attr.key_span("via_getters"),
+                "`via_getters` is only supported on `from`/`try_from` conversions",
+            ));
+        }
+        if attr.from_map {
+            return Err(syn::Error::new(
+                attr.key_span("from_map"),
+                "`from_map` is only supported on `try_from` conversions",
+            ));
+        }
         if attr.validate.is_some() {
             panic!("`validate` is only supported on fallible conversions (`try_from`/`try_into`)");
         }
+        if attr.accumulate_errors {
+            return Err(syn::Error::new(
+                attr.key_span("accumulate_errors"),
+                "`accumulate_errors` is only supported on fallible conversions (`try_from`/`try_into`)",
+            ));
+        }
+        if !attr.reject_defaults.is_empty() {
+            return Err(syn::Error::new(
+                attr.key_span("reject_defaults"),
+                "`reject_defaults` is only supported on fallible conversions (`try_from`/`try_into`)",
+            ));
+        }
+        if attr.recoverable {
+            return Err(syn::Error::new(
+                attr.key_span("recoverable"),
+                "`recoverable` is only supported on fallible conversions (`try_from`/`try_into`)",
+            ));
+        }
+        let ref_method = attr.ref_method.clone();
+        let const_fn = attr.const_fn.clone();
         result.push(ConversionMeta {
-            source_name: ident_to_path(&conversions_data.ident),
+            source_name: self_path(&conversions_data.ident, &conversions_data.generics),
             target_name: attr.path,
             method: ConversionMethod::Into,
             default_allowed: attr.default,
-            validate: None,
+            validate: Vec::new(),
+            normalize: attr.normalize,
+            from_map: false,
+            accumulate_errors: false,
+            reject_defaults: Vec::new(),
+            target_tuple: attr.target_tuple,
+            by_ref: attr.by_ref,
+            from_arc: attr.from_arc.is_present(),
+            via_getters: false,
+            rename_all: attr.rename_all,
+            map_variants: attr.map_variants,
+            body: attr.body,
+            wrap_variant: attr.wrap_variant,
+            wrap_newtype: attr.wrap_newtype,
+            expect_fields: attr.expect_fields,
+            default_fields: attr.default_field,
+            nests: attr.nest,
+            computes: attr.compute,
+            recoverable: false,
+            variant_names: attr.variant_names,
+            containers: conversions_data.container.clone(),
+            reserve_capacity: conversions_data.reserve_capacity,
+            doc: attr.doc,
+            inline: attr.inline,
+            ref_method: None,
+            const_fn: None,
+            builder: attr.builder,
+            vis: conversions_data.vis.clone(),
+            require_explicit: attr.require_explicit,
         });
+        if let Some(name) = ref_method {
+            let meta = ref_method_conversion(result.last().unwrap(), name);
+            result.push(meta);
+        }
+        if let Some(name) = const_fn {
+            let meta = const_fn_conversion(result.last().unwrap(), name);
+            result.push(meta);
+        }
     }
 
     for attr in conversions_data.try_into {
+        check_body(&attr)?;
+        check_option_impl(&attr)?;
+        check_deprecated(&attr)?;
+        check_from_arc(&attr)?;
+        check_normalize(&attr)?;
+        check_const_fn(&attr)?;
+        check_builder(&attr)?;
+        if attr.via_getters {
+            return Err(syn::Error::new(
+                attr.key_span("via_getters"),
+                "`via_getters` is only supported on `from`/`try_from` conversions",
+            ));
+        }
+        if attr.from_map {
+            return Err(syn::Error::new(
+                attr.key_span("from_map"),
+                "`from_map` is only supported on `try_from` conversions",
+            ));
+        }
+        if let Some(const_fn) = &attr.const_fn {
+            return Err(syn::Error::new(
+                const_fn.span(),
+                "`const_fn` is only supported on infallible conversions (`from`/`into`)",
+            ));
+        }
+        let ref_method = attr.ref_method.clone();
         result.push(ConversionMeta {
-            source_name: ident_to_path(&conversions_data.ident),
+            source_name: self_path(&conversions_data.ident, &conversions_data.generics),
             target_name: attr.path,
             method: ConversionMethod::TryInto,
             default_allowed: attr.default,
-            validate: attr.validate,
+            validate: attr
+                .validate
+                .map_or_else(Vec::new, |validators| validators.0),
+            normalize: attr.normalize,
+            from_map: false,
+            accumulate_errors: attr.accumulate_errors,
+            reject_defaults: attr.reject_defaults,
+            target_tuple: attr.target_tuple,
+            by_ref: attr.by_ref,
+            from_arc: attr.from_arc.is_present(),
+            via_getters: false,
+            rename_all: attr.rename_all,
+            map_variants: attr.map_variants,
+            body: attr.body,
+            wrap_variant: attr.wrap_variant,
+            wrap_newtype: attr.wrap_newtype,
+            expect_fields: attr.expect_fields,
+            default_fields: attr.default_field,
+            nests: attr.nest,
+            computes: attr.compute,
+            recoverable: attr.recoverable,
+            variant_names: attr.variant_names,
+            containers: conversions_data.container.clone(),
+            reserve_capacity: conversions_data.reserve_capacity,
+            doc: attr.doc,
+            inline: attr.inline,
+            ref_method: None,
+            const_fn: None,
+            builder: attr.builder,
+            vis: conversions_data.vis.clone(),
+            require_explicit: attr.require_explicit,
         });
+        if let Some(name) = ref_method {
+            let meta = ref_method_conversion(result.last().unwrap(), name);
+            result.push(meta);
+        }
     }
 
     for attr in conversions_data.from {
+        check_body(&attr)?;
+        check_option_impl(&attr)?;
+        check_deprecated(&attr)?;
+        check_from_arc(&attr)?;
+        check_normalize(&attr)?;
+        check_const_fn(&attr)?;
+        check_builder(&attr)?;
+        if attr.ref_method.is_some() {
+            return Err(syn::Error::new(
+                attr.key_span("ref_method"),
+                "`ref_method` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        if let Some(builder) = &attr.builder {
+            return Err(syn::Error::new_spanned(
+                builder,
+                "`builder` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        if attr.wrap_variant.is_some() {
+            return Err(syn::Error::new(
+                attr.key_span("wrap_variant"),
+                "`wrap_variant` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        if attr.wrap_newtype {
+            return Err(syn::Error::new(
+                attr.key_span("wrap_newtype"),
+                "`wrap_newtype` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        if !attr.nest.is_empty() {
+            return Err(syn::Error::new(
+                attr.key_span("nest"),
+                "`nest` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        if attr.from_map {
+            return Err(syn::Error::new(
+                attr.key_span("from_map"),
+                "`from_map` is only supported on `try_from` conversions",
+            ));
+        }
         if attr.validate.is_some() {
             panic!("`validate` is only supported on fallible conversions (`try_from`/`try_into`)");
         }
+        if attr.accumulate_errors {
+            return Err(syn::Error::new(
+                attr.key_span("accumulate_errors"),
+                "`accumulate_errors` is only supported on fallible conversions (`try_from`/`try_into`)",
+            ));
+        }
+        if !attr.reject_defaults.is_empty() {
+            return Err(syn::Error::new(
+                attr.key_span("reject_defaults"),
+                "`reject_defaults` is only supported on fallible conversions (`try_from`/`try_into`)",
+            ));
+        }
+        if attr.recoverable {
+            return Err(syn::Error::new(
+                attr.key_span("recoverable"),
+                "`recoverable` is only supported on fallible conversions (`try_from`/`try_into`)",
+            ));
+        }
+        if attr.variant_names {
+            return Err(syn::Error::new(
+                attr.key_span("variant_names"),
+                "`variant_names` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        let const_fn = attr.const_fn.clone();
         result.push(ConversionMeta {
             source_name: attr.path,
-            target_name: ident_to_path(&conversions_data.ident),
+            target_name: self_path(&conversions_data.ident, &conversions_data.generics),
             method: ConversionMethod::From,
             default_allowed: attr.default,
-            validate: None,
+            validate: Vec::new(),
+            normalize: attr.normalize,
+            from_map: false,
+            accumulate_errors: false,
+            reject_defaults: Vec::new(),
+            target_tuple: attr.target_tuple,
+            by_ref: attr.by_ref,
+            from_arc: attr.from_arc.is_present(),
+            via_getters: attr.via_getters,
+            rename_all: attr.rename_all,
+            map_variants: attr.map_variants,
+            body: attr.body,
+            wrap_variant: None,
+            wrap_newtype: false,
+            expect_fields: attr.expect_fields,
+            default_fields: attr.default_field,
+            nests: attr.nest,
+            computes: attr.compute,
+            recoverable: false,
+            variant_names: false,
+            containers: conversions_data.container.clone(),
+            reserve_capacity: conversions_data.reserve_capacity,
+            doc: attr.doc,
+            inline: attr.inline,
+            ref_method: None,
+            const_fn: None,
+            builder: None,
+            vis: conversions_data.vis.clone(),
+            require_explicit: attr.require_explicit,
         });
+        if let Some(name) = const_fn {
+            let meta = const_fn_conversion(result.last().unwrap(), name);
+            result.push(meta);
+        }
     }
 
     for attr in conversions_data.try_from {
+        check_body(&attr)?;
+        check_option_impl(&attr)?;
+        check_deprecated(&attr)?;
+        check_from_arc(&attr)?;
+        check_normalize(&attr)?;
+        check_const_fn(&attr)?;
+        check_builder(&attr)?;
+        if attr.ref_method.is_some() {
+            return Err(syn::Error::new(
+                attr.key_span("ref_method"),
+                "`ref_method` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        if let Some(builder) = &attr.builder {
+            return Err(syn::Error::new_spanned(
+                builder,
+                "`builder` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        if attr.wrap_variant.is_some() {
+            return Err(syn::Error::new(
+                attr.key_span("wrap_variant"),
+                "`wrap_variant` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        if attr.wrap_newtype {
+            return Err(syn::Error::new(
+                attr.key_span("wrap_newtype"),
+                "`wrap_newtype` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        if !attr.nest.is_empty() {
+            return Err(syn::Error::new(
+                attr.key_span("nest"),
+                "`nest` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        if attr.variant_names {
+            return Err(syn::Error::new(
+                attr.key_span("variant_names"),
+                "`variant_names` is only supported on `into`/`try_into` conversions",
+            ));
+        }
+        if let Some(const_fn) = &attr.const_fn {
+            return Err(syn::Error::new(
+                const_fn.span(),
+                "`const_fn` is only supported on infallible conversions (`from`/`into`)",
+            ));
+        }
         result.push(ConversionMeta {
             source_name: attr.path,
-            target_name: ident_to_path(&conversions_data.ident),
+            target_name: self_path(&conversions_data.ident, &conversions_data.generics),
             method: ConversionMethod::TryFrom,
             default_allowed: attr.default,
-            validate: attr.validate,
+            validate: attr
+                .validate
+                .map_or_else(Vec::new, |validators| validators.0),
+            normalize: attr.normalize,
+            from_map: attr.from_map,
+            accumulate_errors: attr.accumulate_errors,
+            reject_defaults: attr.reject_defaults,
+            target_tuple: attr.target_tuple,
+            by_ref: attr.by_ref,
+            from_arc: attr.from_arc.is_present(),
+            via_getters: attr.via_getters,
+            rename_all: attr.rename_all,
+            map_variants: attr.map_variants,
+            body: attr.body,
+            wrap_variant: None,
+            wrap_newtype: false,
+            expect_fields: attr.expect_fields,
+            default_fields: attr.default_field,
+            nests: attr.nest,
+            computes: attr.compute,
+            recoverable: attr.recoverable,
+            variant_names: false,
+            containers: conversions_data.container.clone(),
+            reserve_capacity: conversions_data.reserve_capacity,
+            doc: attr.doc,
+            inline: attr.inline,
+            ref_method: None,
+            const_fn: None,
+            builder: None,
+            vis: conversions_data.vis.clone(),
+            require_explicit: attr.require_explicit,
         });
     }
 
-    result
+    Ok(ExtractedConversions {
+        conversions: result,
+        debug: conversions_data.debug,
+        roundtrip_test: conversions_data.roundtrip_test,
+    })
 }
@@ -1,117 +1,557 @@
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, quote_spanned};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
 use syn::{DeriveInput, Path};
 
 use crate::{
     attribute_parsing::{
-        conversion_field::{ConvertibleField, FieldConversionMethod},
-        conversion_meta::{ConversionMeta, extract_conversions},
+        conversion_field::{
+            ConvertibleField, FieldConversionMethod, FieldIdentifier, SharedStrategy,
+        },
+        conversion_meta::{ConversionMeta, ExtractedConversions, extract_conversions},
     },
     enum_convert::implement_all_enum_conversions,
     struct_convert::implement_all_struct_conversions,
 };
 
+/// Unwraps an `Option` for an infallible conversion. The message is only
+/// formatted once the value turns out to be `None`.
+fn unwrap_expr(value: TokenStream2, expect: Option<&String>, span: Span) -> TokenStream2 {
+    let expect = expect.map_or("Expected value to exist when converting", String::as_str);
+    quote_spanned!(span => ::core::option::Option::unwrap_or_else(
+        #value,
+        || ::core::panic!("{}", #expect),
+    ))
+}
+
+/// The error of a fallible conversion finding `None` in an unwrapped field.
+fn missing_value_message(expect: Option<&String>) -> &str {
+    expect.map_or("Expected value to exist", String::as_str)
+}
+
+/// Boxes a value as a trait object, which `From` can't express since it doesn't unsize.
+fn boxed_dyn_expr(
+    value: TokenStream2,
+    trait_object: &syn::TypeTraitObject,
+    span: Span,
+) -> TokenStream2 {
+    quote_spanned!(span => ::std::boxed::Box::new(#value) as ::std::boxed::Box<#trait_object>)
+}
+
 /// Generate an infallible conversion expression for a value according to the
 /// recursive `FieldConversionMethod`. Returns a `TokenStream` that evaluates
-/// to the converted value.
-fn infallible_expr(value: TokenStream2, method: &FieldConversionMethod) -> TokenStream2 {
+/// to the converted value, spanned to the field so that type errors point at
+/// it. With `reserve`, `Vec` and `HashMap` values are built with their capacity
+/// reserved instead of collected.
+fn infallible_expr(
+    value: TokenStream2,
+    method: &FieldConversionMethod,
+    reserve: bool,
+    span: Span,
+) -> TokenStream2 {
     match method {
-        FieldConversionMethod::Plain => quote!(#value.into()),
+        FieldConversionMethod::Plain | FieldConversionMethod::Integer(_) => {
+            quote_spanned!(span => #value.into())
+        }
         FieldConversionMethod::Option(inner) => {
-            let inner_expr = infallible_expr(quote!(v), inner);
-            quote!(#value.map(|v| #inner_expr))
+            let inner_expr = infallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => #value.map(|v| #inner_expr))
+        }
+        FieldConversionMethod::OptionNoneOnErr(_) => {
+            unreachable!("`none_on_err` is rejected on infallible conversions")
+        }
+        FieldConversionMethod::OptionToResult(inner, error) => {
+            let inner_expr = infallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => ::core::option::Option::ok_or_else(#value, || #error).map(|v| #inner_expr))
         }
-        FieldConversionMethod::Iterator(inner) => {
-            let inner_expr = infallible_expr(quote!(v), inner);
-            quote!(#value.into_iter().map(|v| #inner_expr).collect())
+        FieldConversionMethod::ResultToOption(inner) => {
+            let inner_expr = infallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => ::core::result::Result::ok(#value).map(|v| #inner_expr))
+        }
+        FieldConversionMethod::Result(ok, err) => {
+            let ok_expr = infallible_expr(quote_spanned!(span => v), ok, reserve, span);
+            let err_expr = infallible_expr(quote_spanned!(span => e), err, reserve, span);
+            quote_spanned!(span => #value.map(|v| #ok_expr).map_err(|e| #err_expr))
+        }
+        FieldConversionMethod::Tuple(elems) => {
+            let (bindings, elem_exprs) = tuple_elems(elems, span, |value, elem| {
+                infallible_expr(value, elem, reserve, span)
+            });
+            quote_spanned!(span => {
+                let (#(#bindings,)*) = #value;
+                (#(#elem_exprs,)*)
+            })
+        }
+        FieldConversionMethod::Boxed(inner) => {
+            let inner_expr =
+                infallible_expr(quote_spanned!(span => (*#value)), inner, reserve, span);
+            quote_spanned!(span => ::std::boxed::Box::new(#inner_expr))
+        }
+        FieldConversionMethod::Shared(pointer, SharedStrategy::Clone, inner) => {
+            let pointer = shared_pointer(pointer);
+            let value = quote_spanned!(span => #pointer::unwrap_or_clone(#value));
+            let inner_expr = infallible_expr(value, inner, reserve, span);
+            quote_spanned!(span => #pointer::new(#inner_expr))
+        }
+        FieldConversionMethod::Shared(_, SharedStrategy::TryUnwrap, _) => {
+            unreachable!("`shared = \"try_unwrap\"` is rejected on infallible conversions")
+        }
+        FieldConversionMethod::Cow(inner) => {
+            let value = quote_spanned!(span => ::std::borrow::Cow::into_owned(#value));
+            infallible_expr(value, inner, reserve, span)
+        }
+        FieldConversionMethod::CowOwned(inner) => {
+            let inner_expr = infallible_expr(value, inner, reserve, span);
+            quote_spanned!(span => ::std::borrow::Cow::Owned(#inner_expr))
+        }
+        FieldConversionMethod::Vec(inner) if reserve => {
+            let inner_expr = infallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => {
+                let __iter = #value.into_iter();
+                let mut __out = ::std::vec::Vec::with_capacity(__iter.size_hint().0);
+                for v in __iter {
+                    __out.push(#inner_expr);
+                }
+                __out
+            })
+        }
+        FieldConversionMethod::Vec(inner) | FieldConversionMethod::Iterator(inner) => {
+            let inner_expr = infallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => #value.into_iter().map(|v| #inner_expr).collect())
+        }
+        FieldConversionMethod::Slice(inner) => {
+            let inner_expr = infallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => #value.iter().cloned().map(|v| #inner_expr).collect())
         }
         FieldConversionMethod::HashMap(key_method, val_method) => {
-            let key_expr = infallible_expr(quote!(k), key_method);
-            let val_expr = infallible_expr(quote!(v), val_method);
-            quote!(#value.into_iter().map(|(k, v)| (#key_expr, #val_expr)).collect())
-        }
-        FieldConversionMethod::UnwrapOption(inner) => {
-            let inner_expr = infallible_expr(quote!(__unwrapped), inner);
-            quote!({
-                let __unwrapped = #value.expect(
-                    format!("Expected value to exist when converting").as_str()
-                );
+            let key_expr = infallible_expr(quote_spanned!(span => k), key_method, reserve, span);
+            let val_expr = infallible_expr(quote_spanned!(span => v), val_method, reserve, span);
+            if reserve {
+                quote_spanned!(span => {
+                    let __iter = #value.into_iter();
+                    let mut __out = ::std::collections::HashMap::with_capacity_and_hasher(
+                        __iter.size_hint().0,
+                        ::core::default::Default::default(),
+                    );
+                    for (k, v) in __iter {
+                        __out.insert(#key_expr, #val_expr);
+                    }
+                    __out
+                })
+            } else {
+                quote_spanned!(span => #value.into_iter().map(|(k, v)| (#key_expr, #val_expr)).collect())
+            }
+        }
+        FieldConversionMethod::BTreeMap(key_method, val_method)
+        | FieldConversionMethod::Pairs(key_method, val_method) => {
+            let key_expr = infallible_expr(quote_spanned!(span => k), key_method, reserve, span);
+            let val_expr = infallible_expr(quote_spanned!(span => v), val_method, reserve, span);
+            quote_spanned!(span => #value.into_iter().map(|(k, v)| (#key_expr, #val_expr)).collect())
+        }
+        FieldConversionMethod::UnwrapOption(inner, expect) => {
+            let inner_expr =
+                infallible_expr(quote_spanned!(span => __unwrapped), inner, reserve, span);
+            let unwrapped = unwrap_expr(value, expect.as_ref(), span);
+            quote_spanned!(span => {
+                let __unwrapped = #unwrapped;
                 #inner_expr
             })
         }
         FieldConversionMethod::UnwrapOrDefault(inner) => {
-            let inner_expr = infallible_expr(quote!(__unwrapped), inner);
-            quote!({
-                let __unwrapped = #value.unwrap_or_default();
+            let inner_expr =
+                infallible_expr(quote_spanned!(span => __unwrapped), inner, reserve, span);
+            quote_spanned!(span => {
+                let __unwrapped = ::core::option::Option::unwrap_or_default(#value);
                 #inner_expr
             })
         }
         FieldConversionMethod::SomeOption(inner) => {
-            let inner_expr = infallible_expr(value, inner);
-            quote!(Some(#inner_expr))
+            let inner_expr = infallible_expr(value, inner, reserve, span);
+            quote_spanned!(span => ::core::option::Option::Some(#inner_expr))
+        }
+        FieldConversionMethod::Array(..) => {
+            unreachable!("`to_array` is rejected on infallible conversions")
+        }
+        FieldConversionMethod::Bounded(..) | FieldConversionMethod::BoundedMap(..) => {
+            unreachable!("fixed-capacity collections are rejected on infallible conversions")
         }
+        FieldConversionMethod::FixedArray(inner, true) => {
+            let inner_expr = infallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => #value.map(|v| #inner_expr))
+        }
+        // Converting from an array, the elements are mapped in place and the result goes
+        // through `Into`, so the other side may be an array or a `Vec` of the converted
+        // elements
+        FieldConversionMethod::FixedArray(inner, false) => {
+            let inner_expr = infallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => #value.map(|v| #inner_expr).into())
+        }
+        FieldConversionMethod::Parse => {
+            unreachable!("`parse` is rejected on infallible conversions")
+        }
+        FieldConversionMethod::Keep => value,
+        FieldConversionMethod::Into => quote_spanned!(span => #value.into()),
+        FieldConversionMethod::WithFunc(func) => quote_spanned!(span => #func(#value)),
+        FieldConversionMethod::BoxedDyn(trait_object) => boxed_dyn_expr(value, trait_object, span),
+    }
+}
+
+/// The bindings a tuple field is destructured into, `__elem_0, __elem_1, ..`, along
+/// with the conversion of each of them.
+fn tuple_elems(
+    elems: &[FieldConversionMethod],
+    span: Span,
+    convert: impl Fn(TokenStream2, &FieldConversionMethod) -> TokenStream2,
+) -> (Vec<syn::Ident>, Vec<TokenStream2>) {
+    elems
+        .iter()
+        .enumerate()
+        .map(|(i, elem)| {
+            let binding = format_ident!("__elem_{}", i, span = span);
+            let expr = convert(quote_spanned!(span => #binding), elem);
+            (binding, expr)
+        })
+        .unzip()
+}
+
+/// The absolute path of a `shared` field's pointer type.
+fn shared_pointer(pointer: &syn::Ident) -> TokenStream2 {
+    if pointer == "Rc" {
+        quote!(::std::rc::Rc)
+    } else {
+        quote!(::std::sync::Arc)
     }
 }
 
-fn fallible_expr(value: TokenStream2, method: &FieldConversionMethod) -> TokenStream2 {
+fn fallible_expr(
+    value: TokenStream2,
+    method: &FieldConversionMethod,
+    reserve: bool,
+    span: Span,
+) -> TokenStream2 {
     match method {
         FieldConversionMethod::Plain => {
-            quote!(#value.try_into().map_err(|e| format!("{:?}", e)))
+            quote_spanned!(span => #value.try_into().map_err(|e| ::std::format!("{:?}", e)))
+        }
+        // For integer sources the error is `TryFromIntError`, whose message is used
+        // instead of the `Debug` output `TryFromIntError(())`. Naming the target makes
+        // the error type known in the closure, so other sources' errors, which may only
+        // implement `Debug`, fall back to it through autoref specialization
+        FieldConversionMethod::Integer(integer) => {
+            quote_spanned!(span => <#integer as ::core::convert::TryFrom<_>>::try_from(#value)
+            .map_err(|e| {
+                struct Describe<'a, E>(&'a E);
+                trait ViaDisplay {
+                    fn describe(&self) -> ::std::string::String;
+                }
+                impl<E: ::core::fmt::Display> ViaDisplay for Describe<'_, E> {
+                    fn describe(&self) -> ::std::string::String {
+                        ::std::string::ToString::to_string(self.0)
+                    }
+                }
+                trait ViaDebug {
+                    fn describe(&self) -> ::std::string::String;
+                }
+                impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                    fn describe(&self) -> ::std::string::String {
+                        ::std::format!("{:?}", self.0)
+                    }
+                }
+                (&Describe(&e)).describe()
+            }))
+        }
+        // `FromStr` errors, like `ParseIntError`, describe themselves with `Display`
+        FieldConversionMethod::Parse => {
+            quote_spanned!(span => #value.parse().map_err(|e| ::std::string::ToString::to_string(&e)))
         }
         FieldConversionMethod::Option(inner) => {
-            let inner_expr = fallible_expr(quote!(v), inner);
-            quote!(#value.map(|v| #inner_expr).transpose())
+            let inner_expr = fallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => #value.map(|v| #inner_expr).transpose())
+        }
+        FieldConversionMethod::OptionNoneOnErr(inner) => {
+            let inner_expr = fallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => ::core::result::Result::Ok::<_, ::std::string::String>(
+                #value.and_then(|v| #inner_expr.ok())
+            ))
+        }
+        FieldConversionMethod::OptionToResult(inner, error) => {
+            let inner_expr = fallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => match #value {
+                ::core::option::Option::Some(v) => #inner_expr.map(::core::result::Result::Ok),
+                ::core::option::Option::None => {
+                    ::core::result::Result::Ok(::core::result::Result::Err(#error))
+                }
+            })
+        }
+        FieldConversionMethod::ResultToOption(inner) => {
+            let inner_expr = fallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => ::core::result::Result::ok(#value).map(|v| #inner_expr).transpose())
+        }
+        // Either half failing to convert fails the field, which otherwise holds a `Result`
+        FieldConversionMethod::Result(ok, err) => {
+            let ok_expr = fallible_expr(quote_spanned!(span => v), ok, reserve, span);
+            let err_expr = fallible_expr(quote_spanned!(span => e), err, reserve, span);
+            quote_spanned!(span => match #value {
+                ::core::result::Result::Ok(v) => #ok_expr.map(::core::result::Result::Ok),
+                ::core::result::Result::Err(e) => #err_expr.map(::core::result::Result::Err),
+            })
+        }
+        FieldConversionMethod::Tuple(elems) => {
+            let (bindings, elem_exprs) = tuple_elems(elems, span, |value, elem| {
+                fallible_expr(value, elem, reserve, span)
+            });
+            quote_spanned!(span => (|| -> ::core::result::Result<_, ::std::string::String> {
+                let (#(#bindings,)*) = #value;
+                ::core::result::Result::Ok((#(#elem_exprs?,)*))
+            })())
+        }
+        FieldConversionMethod::Boxed(inner) => {
+            let inner_expr = fallible_expr(quote_spanned!(span => (*#value)), inner, reserve, span);
+            quote_spanned!(span => #inner_expr.map(::std::boxed::Box::new))
+        }
+        FieldConversionMethod::Shared(pointer, SharedStrategy::Clone, inner) => {
+            let pointer = shared_pointer(pointer);
+            let value = quote_spanned!(span => #pointer::unwrap_or_clone(#value));
+            let inner_expr = fallible_expr(value, inner, reserve, span);
+            quote_spanned!(span => #inner_expr.map(#pointer::new))
+        }
+        // The pointer is handed back by `try_unwrap` when it's shared, which fails the field
+        FieldConversionMethod::Shared(pointer, SharedStrategy::TryUnwrap, inner) => {
+            let pointer = shared_pointer(pointer);
+            let inner_expr = fallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => #pointer::try_unwrap(#value)
+                .map_err(|_| ::std::string::String::from("the value is still shared"))
+                .and_then(|v| #inner_expr)
+                .map(#pointer::new))
+        }
+        FieldConversionMethod::Cow(inner) => {
+            let value = quote_spanned!(span => ::std::borrow::Cow::into_owned(#value));
+            fallible_expr(value, inner, reserve, span)
         }
-        FieldConversionMethod::Iterator(inner) => {
-            let inner_expr = fallible_expr(quote!(v), inner);
-            quote!(#value.into_iter().map(|v| #inner_expr).collect::<Result<_, _>>())
+        FieldConversionMethod::CowOwned(inner) => {
+            let inner_expr = fallible_expr(value, inner, reserve, span);
+            quote_spanned!(span => #inner_expr.map(::std::borrow::Cow::Owned))
+        }
+        // Collecting into a `Result` can't tell the final length, so the `Vec` would regrow
+        FieldConversionMethod::Vec(inner) if reserve => {
+            let inner_expr = fallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => (|| -> ::core::result::Result<_, ::std::string::String> {
+                let __iter = #value.into_iter();
+                let mut __out = ::std::vec::Vec::with_capacity(__iter.size_hint().0);
+                for v in __iter {
+                    __out.push(#inner_expr?);
+                }
+                ::core::result::Result::Ok(__out)
+            })())
+        }
+        FieldConversionMethod::Bounded(inner, push) => {
+            let inner_expr = fallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => (|| -> ::core::result::Result<_, ::std::string::String> {
+                let mut __out = ::core::default::Default::default();
+                for v in #value {
+                    if #push(&mut __out, #inner_expr?).is_err() {
+                        return ::core::result::Result::Err(::std::format!(
+                            "more elements than the capacity of {}",
+                            __out.len()
+                        ));
+                    }
+                }
+                ::core::result::Result::Ok(__out)
+            })())
+        }
+        FieldConversionMethod::Vec(inner) | FieldConversionMethod::Iterator(inner) => {
+            let inner_expr = fallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => #value.into_iter().map(|v| #inner_expr).collect::<::core::result::Result<_, _>>())
+        }
+        FieldConversionMethod::Slice(inner) => {
+            let inner_expr = fallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => #value.iter().cloned().map(|v| #inner_expr).collect::<::core::result::Result<_, _>>())
         }
         FieldConversionMethod::HashMap(key_method, val_method) => {
-            let key_expr = fallible_expr(quote!(k), key_method);
-            let val_expr = fallible_expr(quote!(v), val_method);
-            quote!((|| -> Result<_, String> {
-                let mut result = ::std::collections::HashMap::new();
+            let key_expr = fallible_expr(quote_spanned!(span => k), key_method, reserve, span);
+            let val_expr = fallible_expr(quote_spanned!(span => v), val_method, reserve, span);
+            let result = if reserve {
+                quote_spanned!(span => ::std::collections::HashMap::with_capacity_and_hasher(
+                    __iter.size_hint().0,
+                    ::core::default::Default::default(),
+                ))
+            } else {
+                quote_spanned!(span => ::std::collections::HashMap::default())
+            };
+            quote_spanned!(span => (|| -> ::core::result::Result<_, ::std::string::String> {
+                let __iter = #value.into_iter();
+                let mut result = #result;
+                for (k, v) in __iter {
+                    result.insert(#key_expr?, #val_expr?);
+                }
+                ::core::result::Result::Ok(result)
+            })())
+        }
+        FieldConversionMethod::BTreeMap(key_method, val_method) => {
+            let key_expr = fallible_expr(quote_spanned!(span => k), key_method, reserve, span);
+            let val_expr = fallible_expr(quote_spanned!(span => v), val_method, reserve, span);
+            quote_spanned!(span => (|| -> ::core::result::Result<_, ::std::string::String> {
+                let mut result = ::std::collections::BTreeMap::new();
                 for (k, v) in #value {
                     result.insert(#key_expr?, #val_expr?);
                 }
-                Ok(result)
+                ::core::result::Result::Ok(result)
+            })())
+        }
+        FieldConversionMethod::BoundedMap(key_method, val_method, insert) => {
+            let key_expr = fallible_expr(quote_spanned!(span => k), key_method, reserve, span);
+            let val_expr = fallible_expr(quote_spanned!(span => v), val_method, reserve, span);
+            quote_spanned!(span => (|| -> ::core::result::Result<_, ::std::string::String> {
+                let mut __out = ::core::default::Default::default();
+                for (k, v) in #value {
+                    if #insert(&mut __out, #key_expr?, #val_expr?).is_err() {
+                        return ::core::result::Result::Err(::std::format!(
+                            "more elements than the capacity of {}",
+                            __out.len()
+                        ));
+                    }
+                }
+                ::core::result::Result::Ok(__out)
             })())
         }
-        FieldConversionMethod::UnwrapOption(inner) => {
-            let inner_expr = fallible_expr(quote!(__unwrapped), inner);
-            quote!(#value
-                .ok_or_else(|| String::from("Expected value to exist"))
-                .and_then(|__unwrapped| #inner_expr))
+        FieldConversionMethod::Pairs(key_method, val_method) => {
+            let key_expr = fallible_expr(quote_spanned!(span => k), key_method, reserve, span);
+            let val_expr = fallible_expr(quote_spanned!(span => v), val_method, reserve, span);
+            quote_spanned!(span => #value
+            .into_iter()
+            .map(|(k, v)| -> ::core::result::Result<_, ::std::string::String> {
+                ::core::result::Result::Ok((#key_expr?, #val_expr?))
+            })
+            .collect::<::core::result::Result<_, _>>())
+        }
+        FieldConversionMethod::UnwrapOption(inner, expect) => {
+            let inner_expr =
+                fallible_expr(quote_spanned!(span => __unwrapped), inner, reserve, span);
+            let message = missing_value_message(expect.as_ref());
+            quote_spanned!(span => ::core::option::Option::ok_or_else(
+                #value,
+                || ::std::string::String::from(#message),
+            )
+            .and_then(|__unwrapped| #inner_expr))
         }
         FieldConversionMethod::UnwrapOrDefault(inner) => {
-            let inner_expr = fallible_expr(quote!(__unwrapped), inner);
-            quote!({
-                let __unwrapped = #value.unwrap_or_default();
+            let inner_expr =
+                fallible_expr(quote_spanned!(span => __unwrapped), inner, reserve, span);
+            quote_spanned!(span => {
+                let __unwrapped = ::core::option::Option::unwrap_or_default(#value);
                 #inner_expr
             })
         }
         FieldConversionMethod::SomeOption(inner) => {
-            let inner_expr = fallible_expr(value, inner);
-            quote!(#inner_expr.map(Some))
+            let inner_expr = fallible_expr(value, inner, reserve, span);
+            quote_spanned!(span => #inner_expr.map(::core::option::Option::Some))
+        }
+        FieldConversionMethod::Array(inner, len) => {
+            let inner_expr = fallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => #value
+            .into_iter()
+            .map(|v| #inner_expr)
+            .collect::<::core::result::Result<::std::vec::Vec<_>, _>>()
+            .and_then(|v| {
+                let len = v.len();
+                v.try_into()
+                    .map_err(|_| ::std::format!("expected {} elements, got {}", #len, len))
+            }))
+        }
+        // Collected through a `Vec`, so either side may also be a `Vec`, e.g. a length
+        // checked when filling an array from one
+        FieldConversionMethod::FixedArray(inner, _) => {
+            let inner_expr = fallible_expr(quote_spanned!(span => v), inner, reserve, span);
+            quote_spanned!(span => #value
+            .into_iter()
+            .map(|v| #inner_expr)
+            .collect::<::core::result::Result<::std::vec::Vec<_>, _>>()
+            .and_then(|v| {
+                let len = v.len();
+                ::core::convert::TryInto::try_into(v).map_err(|_| {
+                    ::std::format!("{} elements don't match the array length", len)
+                })
+            }))
+        }
+        FieldConversionMethod::Keep => {
+            quote_spanned!(span => ::core::result::Result::Ok::<_, ::std::string::String>(#value))
+        }
+        FieldConversionMethod::Into => {
+            quote_spanned!(span => ::core::result::Result::Ok::<_, ::std::string::String>(#value.into()))
+        }
+        FieldConversionMethod::WithFunc(func) => {
+            quote_spanned!(span => #func(#value).map_err(|e| ::std::format!("{:?}", e)))
+        }
+        FieldConversionMethod::BoxedDyn(trait_object) => {
+            let boxed = boxed_dyn_expr(value, trait_object, span);
+            quote_spanned!(span => ::core::result::Result::Ok::<_, ::std::string::String>(#boxed))
         }
     }
 }
 
+/// Reads a field from the source, either as `source.field` or through the binding
+/// of a destructured enum variant, calling `map_method` on it if set. With
+/// `via_getters` it's read with `source.field()` instead, owning what the getter
+/// returns. A borrowed source can't be moved out of, so its fields are cloned,
+/// with `Vec`s and maps iterated by reference and cloned one element at a time.
+fn source_field_expr(
+    source_name: &FieldIdentifier,
+    method: &FieldConversionMethod,
+    map_method: Option<&syn::Ident>,
+    source_prefix: bool,
+    meta: &ConversionMeta,
+    span: Span,
+) -> TokenStream2 {
+    let value = if source_prefix && meta.via_getters {
+        quote_spanned!(span => source.#source_name().to_owned())
+    } else if source_prefix {
+        quote_spanned!(span => source.#source_name)
+    } else {
+        source_name.as_named()
+    };
+
+    match map_method {
+        Some(method) => quote_spanned!(span => #value.#method()),
+        None if meta.via_getters => value,
+        None if meta.by_ref => match method {
+            FieldConversionMethod::Vec(_) => quote_spanned!(span => #value.iter().cloned()),
+            FieldConversionMethod::HashMap(..) | FieldConversionMethod::BTreeMap(..) => {
+                quote_spanned!(span => #value.iter().map(|(k, v)| (k.clone(), v.clone())))
+            }
+            _ => quote_spanned!(span => #value.clone()),
+        },
+        None => value,
+    }
+}
+
+/// The argument passed to `with_func`. A borrowed source is passed as is, so
+/// the function can return data borrowed from it.
+fn with_func_arg(by_ref: bool) -> TokenStream2 {
+    if by_ref {
+        quote!(source)
+    } else {
+        quote!(&source)
+    }
+}
+
 pub(super) fn field_falliable_conversion(
-    ConvertibleField {
+    field: ConvertibleField,
+    meta: &ConversionMeta,
+    named: bool,
+    source_prefix: bool,
+) -> TokenStream2 {
+    let ConvertibleField {
         source_name,
         target_name,
         skip,
         method,
         span,
         default,
-        conversion_func,
-    }: ConvertibleField,
-    target_type: &Path,
-    named: bool,
-    source_prefix: bool,
-) -> TokenStream2 {
-    if skip {
+        map_method,
+        ..
+    } = &field;
+    let span = *span;
+
+    if *skip {
         return quote! {};
     }
 
@@ -121,52 +561,350 @@ pub(super) fn field_falliable_conversion(
         quote! {}
     };
 
-    let source_name = if source_prefix {
-        quote!(source.#source_name)
+    let source_name = source_field_expr(
+        source_name,
+        method,
+        map_method.as_ref(),
+        source_prefix,
+        meta,
+        span,
+    );
+
+    if *default {
+        return quote_spanned! { span =>
+            #named_start ::core::default::Default::default(),
+        };
+    }
+
+    let result = field_falliable_result(&source_name, &field, meta);
+
+    quote_spanned! { span =>
+        #named_start #result?,
+    }
+}
+
+/// The `Error` type of the generated fallible impls, selected by crate feature.
+pub(super) fn error_type() -> TokenStream2 {
+    if cfg!(feature = "anyhow") {
+        quote! { ::anyhow::Error }
+    } else if cfg!(feature = "box-error") {
+        quote! { ::std::boxed::Box<dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync> }
     } else {
-        let source_name = source_name.as_named();
-        quote!(#source_name)
-    };
+        quote! { ::std::string::String }
+    }
+}
 
-    if default {
+/// Checks the source with each `validate` function or inline closure in order,
+/// returning early on the first failure. A closure is bound with the source and
+/// error types spelled out, since nothing else would tell what it takes and returns.
+pub(super) fn validate_call(meta: &ConversionMeta) -> TokenStream2 {
+    let source_name = &meta.source_name;
+    let target_name = &meta.target_name;
+    let error_creator = error_creator();
+
+    let calls = meta.validate.iter().enumerate().map(|(index, validate)| {
+        let (binding, func) = match validate {
+            syn::Expr::Path(func) => (quote! {}, quote! { #func }),
+            closure => {
+                let error_type = error_type();
+                let func = format_ident!("__validate_{}", index);
+                (
+                    quote! {
+                        let #func: fn(&#source_name) -> ::core::result::Result<(), #error_type> = #closure;
+                    },
+                    quote! { #func },
+                )
+            }
+        };
+
+        quote! {
+            #binding
+            #func(&source).map_err(|e| #error_creator("Failed trying to convert {} to {}: {}",
+                ::core::stringify!(#source_name), ::core::stringify!(#target_name), e))?;
+        }
+    });
+
+    quote! { #(#calls)* }
+}
+
+/// The `Error` type of a fallible impl. With `recoverable`, the source is handed
+/// back along with the error.
+pub(super) fn conversion_error_type(meta: &ConversionMeta) -> TokenStream2 {
+    let error_type = error_type();
+    if meta.recoverable {
+        let source_type = meta.source_type();
+        quote! { (#source_type, #error_type) }
+    } else {
+        error_type
+    }
+}
+
+/// The body of a fallible impl's `try_from`. With `recoverable`, the fields are still
+/// moved out of the source, so a clone of it is kept to hand back on failure.
+pub(super) fn fallible_body(meta: &ConversionMeta, body: TokenStream2) -> TokenStream2 {
+    if !meta.recoverable {
+        return body;
+    }
+
+    let error_type = error_type();
+    quote! {
+        let __recovered = ::core::clone::Clone::clone(&source);
+        (|| -> ::core::result::Result<Self, #error_type> { #body })().map_err(|e| (__recovered, e))
+    }
+}
+
+/// The error constructor matching the error type of the generated fallible impls.
+/// A boxed error is built from the formatted message, so where the error isn't
+/// propagated with `?` it needs an explicit `.into()`.
+pub(super) fn error_creator() -> TokenStream2 {
+    if cfg!(feature = "anyhow") {
+        quote!(::anyhow::anyhow!)
+    } else {
+        quote!(::std::format!)
+    }
+}
+
+/// Wraps the body of a conversion, evaluating to the converted value (in `Ok` when
+/// fallible), into its `From`/`TryFrom` impl. With `ref_method`, it becomes an
+/// inherent method of the deriving type instead, converting from `&self`, and with
+/// `const_fn` an inherent `const fn` on the side it's declared on. With `builder`, the
+/// body evaluates to the builder, returned by `into_partial` on the source. With `normalize`,
+/// the source is rebound as `mut` and normalized before the body runs.
+pub(super) fn conversion_impl(meta: &ConversionMeta, body: TokenStream2) -> TokenStream2 {
+    let impl_attrs = meta.impl_attrs();
+    let fn_attrs = meta.fn_attrs();
+    let impl_generics = meta.impl_generics();
+    let target_name = &meta.target_name;
+
+    if let Some(ref_method) = &meta.ref_method {
+        let source_name = &meta.source_name;
+        let vis = &meta.vis;
+        let return_type = if meta.method.is_falliable() {
+            let error_type = error_type();
+            quote! { ::core::result::Result<#target_name, #error_type> }
+        } else {
+            quote! { #target_name }
+        };
+        return quote! {
+            #impl_attrs
+            impl #impl_generics #source_name {
+                #fn_attrs
+                #vis fn #ref_method(&self) -> #return_type {
+                    let source = self;
+                    #body
+                }
+            }
+        };
+    }
+
+    if let Some(builder) = &meta.builder {
+        let source_name = &meta.source_name;
+        let vis = &meta.vis;
+        let return_type = if meta.method.is_falliable() {
+            let error_type = error_type();
+            quote! { ::core::result::Result<#builder, #error_type> }
+        } else {
+            quote! { #builder }
+        };
+        return quote! {
+            #impl_attrs
+            impl #impl_generics #source_name {
+                #fn_attrs
+                #vis fn into_partial(self) -> #return_type {
+                    let source = self;
+                    #body
+                }
+            }
+        };
+    }
+
+    if let Some(const_fn) = &meta.const_fn {
+        let source_name = &meta.source_name;
+        let vis = &meta.vis;
+        return if meta.method.is_from() {
+            quote! {
+                #impl_attrs
+                impl #impl_generics #target_name {
+                    #fn_attrs
+                    #vis const fn #const_fn(source: #source_name) -> Self {
+                        #body
+                    }
+                }
+            }
+        } else {
+            quote! {
+                #impl_attrs
+                impl #impl_generics #source_name {
+                    #fn_attrs
+                    #vis const fn #const_fn(self) -> #target_name {
+                        let source = self;
+                        #body
+                    }
+                }
+            }
+        };
+    }
+
+    let mut owned_source = meta.owned_source();
+    if let Some(normalize) = &meta.normalize {
+        owned_source.extend(quote! {
+            let mut source = source;
+            #normalize(&mut source);
+        });
+    }
+    let source_type = meta.source_type();
+    if meta.method.is_falliable() {
+        let error_type = conversion_error_type(meta);
+        let body = fallible_body(meta, body);
+        quote! {
+            #impl_attrs
+            impl #impl_generics ::core::convert::TryFrom<#source_type> for #target_name {
+                type Error = #error_type;
+                #fn_attrs
+                fn try_from(source: #source_type) -> ::core::result::Result<#target_name, Self::Error> {
+                    #owned_source
+                    #body
+                }
+            }
+        }
+    } else {
+        quote! {
+            #impl_attrs
+            impl #impl_generics ::core::convert::From<#source_type> for #target_name {
+                #fn_attrs
+                fn from(source: #source_type) -> #target_name {
+                    #owned_source
+                    #body
+                }
+            }
+        }
+    }
+}
+
+/// Implements the conversion by delegating to a hand-written `body` function,
+/// without looking at the fields at all.
+pub(super) fn implement_body_conversion(meta: &ConversionMeta, body: &Path) -> TokenStream2 {
+    if meta.method.is_falliable() {
+        conversion_impl(meta, quote! { ::core::result::Result::Ok(#body(source)?) })
+    } else {
+        conversion_impl(meta, quote! { #body(source) })
+    }
+}
+
+/// Builds the `Result` expression holding the converted value of a fallible field,
+/// with the error already mapped into the conversion's error type. With an
+/// `error_variant`, the inner error (the function's own error with `with_func`,
+/// a message otherwise) is wrapped in it and then converted with `.into()`.
+fn field_falliable_result(
+    source_name: &TokenStream2,
+    field: &ConvertibleField,
+    meta: &ConversionMeta,
+) -> TokenStream2 {
+    let ConvertibleField {
+        method,
+        span,
+        conversion_func,
+        conversion_func_opt,
+        wrap_if,
+        error_variant,
+        ..
+    } = field;
+    let span = *span;
+
+    if let Some(condition) = wrap_if {
+        let unconditional = ConvertibleField {
+            wrap_if: None,
+            ..field.clone()
+        };
+        let result = field_falliable_result(source_name, &unconditional, meta);
         return quote_spanned! { span =>
-            #named_start Default::default(),
+            (if #condition { #result.map(::core::option::Option::Some) } else { ::core::result::Result::Ok(::core::option::Option::None) })
         };
     }
 
-    let error_creator = if cfg!(feature = "anyhow") {
-        quote!(anyhow::anyhow!)
+    let error_creator = error_creator();
+    let target_type = &meta.target_name;
+    let error_type = error_type();
+
+    // With `unwrap`, the function is given the unwrapped value instead of the parent
+    let unwrap_expect = match method {
+        FieldConversionMethod::UnwrapOption(_, expect) => Some(expect.as_ref()),
+        _ => None,
+    };
+    let arg = if unwrap_expect.is_some() {
+        quote!(&__unwrapped)
     } else {
-        quote!(format!)
+        with_func_arg(meta.by_ref)
     };
 
-    if let Some(func) = conversion_func {
-        return quote_spanned! { span =>
-            #named_start #func(&source).map_err(|e|
+    let func_result = match (conversion_func_opt, error_variant) {
+        (Some(func), Some(variant)) => Some(quote_spanned! { span =>
+            #func(#arg).ok_or_else(|| -> #error_type {
+                #variant(::std::format!("{} returned None", ::core::stringify!(#func))).into()
+            })
+        }),
+        (Some(func), None) => Some(quote_spanned! { span =>
+            #func(#arg).ok_or_else(||
+                #error_creator("Failed trying to convert {} to {}: {} returned None",
+                    ::core::stringify!(#source_name),
+                    ::core::stringify!(#target_type),
+                    ::core::stringify!(#func),
+                )
+            )
+        }),
+        (None, Some(variant)) => conversion_func.as_ref().map(|func| {
+            quote_spanned! { span =>
+                #func(#arg).map_err(|e| -> #error_type { #variant(e).into() })
+            }
+        }),
+        (None, None) => conversion_func.as_ref().map(|func| {
+            quote_spanned! { span =>
+                #func(#arg).map_err(|e|
                     #error_creator("Failed trying to convert {} to {}: {:?}",
-                        stringify!(#source_name),
-                        stringify!(#target_type),
+                        ::core::stringify!(#source_name),
+                        ::core::stringify!(#target_type),
                         e,
                     )
-                )?,
+                )
+            }
+        }),
+    };
+
+    if let Some(result) = func_result {
+        let Some(expect) = unwrap_expect else {
+            return result;
+        };
+        let message = missing_value_message(expect);
+        return quote_spanned! { span =>
+            ::core::option::Option::ok_or_else(#source_name, ||
+                #error_creator("Failed trying to convert {} to {}: {}",
+                    ::core::stringify!(#source_name),
+                    ::core::stringify!(#target_type),
+                    #message,
+                )
+            )
+            .and_then(|__unwrapped| #result)
         };
     }
 
-    let map_err = quote! {
-        map_err(|e|
-            #error_creator("Failed trying to convert {} to {}: {}",
-                stringify!(#source_name),
-                stringify!(#target_type),
-                e,
+    let map_err = match error_variant {
+        Some(variant) => quote_spanned! { span =>
+            map_err(|e| -> #error_type { #variant(e).into() })
+        },
+        None => quote_spanned! { span =>
+            map_err(|e|
+                #error_creator("Failed trying to convert {} to {}: {}",
+                    ::core::stringify!(#source_name),
+                    ::core::stringify!(#target_type),
+                    e,
+                )
             )
-        )
+        },
     };
 
-    let expr = fallible_expr(source_name, &method);
+    let expr = fallible_expr(source_name.clone(), method, meta.reserve_capacity, span);
 
-    quote_spanned! { span =>
-        #named_start #expr.#map_err?,
-    }
+    quote_spanned! { span => #expr.#map_err }
 }
 
 pub(super) fn field_infalliable_conversion(
@@ -178,7 +916,11 @@ pub(super) fn field_infalliable_conversion(
         span,
         default,
         conversion_func,
+        map_method,
+        wrap_if,
+        ..
     }: ConvertibleField,
+    meta: &ConversionMeta,
     named: bool,
     source_prefix: bool,
 ) -> TokenStream2 {
@@ -191,61 +933,449 @@ pub(super) fn field_infalliable_conversion(
         quote! {}
     };
 
-    let source_name = if source_prefix {
-        quote!(source.#source_name)
-    } else {
-        let source_name = source_name.as_named();
-        quote!(#source_name)
-    };
+    let source_name = source_field_expr(
+        &source_name,
+        &method,
+        map_method.as_ref(),
+        source_prefix,
+        meta,
+        span,
+    );
 
     if default {
         return quote_spanned! { span =>
-            #named_start Default::default(),
+            #named_start ::core::default::Default::default(),
         };
     }
 
-    if let Some(func) = conversion_func {
+    let expr = match conversion_func {
+        // With `unwrap`, the function is given the unwrapped value instead of the parent
+        Some(func) => match &method {
+            FieldConversionMethod::UnwrapOption(_, expect) => {
+                let unwrapped = unwrap_expr(source_name, expect.as_ref(), span);
+                quote_spanned! { span => #func(&#unwrapped) }
+            }
+            _ => {
+                let arg = with_func_arg(meta.by_ref);
+                quote_spanned! { span => #func(#arg) }
+            }
+        },
+        None => infallible_expr(source_name, &method, meta.reserve_capacity, span),
+    };
+
+    let expr = match wrap_if {
+        Some(condition) => quote_spanned! { span =>
+            if #condition { ::core::option::Option::Some(#expr) } else { ::core::option::Option::None }
+        },
+        None => expr,
+    };
+
+    quote_spanned! { span =>
+        #named_start #expr,
+    }
+}
+
+/// Pulls a field out of a map source by its (renamed) key and parses it into
+/// the field type. `Option` fields tolerate a missing key, and with `none_on_err`
+/// a value that fails to parse.
+pub(super) fn field_map_conversion(field: ConvertibleField, meta: &ConversionMeta) -> TokenStream2 {
+    if field.default || field.conversion_func.is_some() || field.conversion_func_opt.is_some() {
+        return field_falliable_conversion(field, meta, true, true);
+    }
+
+    let target_type = &meta.target_name;
+
+    let ConvertibleField {
+        source_name,
+        target_name,
+        method,
+        span,
+        error_variant,
+        ..
+    } = field;
+
+    let key = source_name.as_key();
+
+    let error_creator = error_creator();
+    let error_type = error_type();
+
+    let parse = match error_variant {
+        Some(variant) => quote_spanned! { span =>
+            parse().map_err(|e| -> #error_type { #variant(::std::format!("{:?}", e)).into() })
+        },
+        None => quote_spanned! { span =>
+            parse().map_err(|e|
+                #error_creator("Failed trying to convert {} to {}: {:?}",
+                    #key,
+                    ::core::stringify!(#target_type),
+                    e,
+                )
+            )
+        },
+    };
+
+    if let FieldConversionMethod::Option(_) = method {
         return quote_spanned! { span =>
-            #named_start #func(&source),
+            #target_name: source.remove(#key).map(|v| v.#parse).transpose()?,
         };
     }
 
-    let expr = infallible_expr(source_name, &method);
+    if let FieldConversionMethod::OptionNoneOnErr(_) = method {
+        return quote_spanned! { span =>
+            #target_name: source.remove(#key).and_then(|v| v.parse().ok()),
+        };
+    }
 
     quote_spanned! { span =>
-        #named_start #expr,
+        #target_name: source
+            .remove(#key)
+            .ok_or_else(|| #error_creator("Missing key {} when converting to {}",
+                #key,
+                ::core::stringify!(#target_type),
+            ))?
+            .#parse?,
     }
 }
 
+fn accumulated_binding(index: usize) -> syn::Ident {
+    format_ident!("__converted_{}", index)
+}
+
+/// For `accumulate_errors`: converts every field into a local binding up front,
+/// collecting all failures and returning them together instead of failing on the first.
+pub(super) fn build_accumulated_bindings(
+    meta: &ConversionMeta,
+    source_prefix: bool,
+    fields: &[ConvertibleField],
+) -> TokenStream2 {
+    let error_creator = error_creator();
+
+    let bindings = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| !field.default)
+        .map(|(index, field)| {
+            let binding = accumulated_binding(index);
+            let source_name = source_field_expr(
+                &field.source_name,
+                &field.method,
+                field.map_method.as_ref(),
+                source_prefix,
+                meta,
+                field.span,
+            );
+            let result = field_falliable_result(&source_name, field, meta);
+            let cfg = field
+                .cfg_skip
+                .as_ref()
+                .map(|predicate| quote!(#[cfg(not(#predicate))]));
+
+            quote_spanned! { field.span =>
+                #cfg
+                let #binding = #result.map_err(|e| __errors.push(e.to_string())).ok();
+            }
+        });
+
+    quote! {
+        let mut __errors: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+        #(#bindings)*
+        if !__errors.is_empty() {
+            return ::core::result::Result::Err(#error_creator("{}", __errors.join("; ")).into());
+        }
+    }
+}
+
+/// Field initializer reading the binding produced by `build_accumulated_bindings`.
+fn field_accumulated_conversion(
+    field: &ConvertibleField,
+    index: usize,
+    named: bool,
+) -> TokenStream2 {
+    let target_name = &field.target_name;
+    let named_start = if named {
+        quote! { #target_name: }
+    } else {
+        quote! {}
+    };
+
+    if field.default {
+        return quote_spanned! { field.span =>
+            #named_start ::core::default::Default::default(),
+        };
+    }
+
+    // Every binding is `Some` once the error check in the bindings has passed
+    let binding = accumulated_binding(index);
+    quote_spanned! { field.span =>
+        #named_start #binding.unwrap(),
+    }
+}
+
+/// Gates a field conversion behind `cfg(not(predicate))`, falling back to
+/// `Default::default()` when the predicate is active.
+fn cfg_skipped_conversion(
+    field: &ConvertibleField,
+    predicate: &syn::Meta,
+    conversion: TokenStream2,
+) -> TokenStream2 {
+    let target_name = &field.target_name;
+    quote_spanned! { field.span =>
+        #[cfg(not(#predicate))]
+        #conversion
+        #[cfg(#predicate)]
+        #target_name: ::core::default::Default::default(),
+    }
+}
+
+/// Fields with a `with_func` are converted first, since the function borrows the
+/// whole source before the other fields are moved out of it. Positional fields
+/// can't be reordered, so their conversions are evaluated into bindings in that
+/// order and then passed in declaration order.
+pub(super) fn positional_conversions(
+    fields: &[ConvertibleField],
+    conversions: Vec<TokenStream2>,
+) -> (TokenStream2, Vec<TokenStream2>) {
+    let positions: Vec<usize> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match field.target_name {
+            FieldIdentifier::Unnamed(position) => position,
+            FieldIdentifier::Named(_) => index,
+        })
+        .collect();
+
+    if positions.is_sorted() {
+        return (quote! {}, conversions);
+    }
+
+    let bindings: Vec<_> = positions
+        .iter()
+        .map(|position| format_ident!("__field_{}", position))
+        .collect();
+    let mut ordered: Vec<_> = positions.iter().zip(&bindings).collect();
+    ordered.sort_by_key(|(position, _)| **position);
+
+    (
+        quote! { let (#(#bindings,)*) = (#(#conversions)*); },
+        ordered
+            .into_iter()
+            .map(|(_, binding)| quote! { #binding, })
+            .collect(),
+    )
+}
+
 pub(super) fn build_field_conversions(
     meta: &ConversionMeta,
     named: bool,
     source_prefix: bool,
     fields: &[ConvertibleField],
 ) -> syn::Result<Vec<TokenStream2>> {
-    Ok(fields
-        .iter()
-        .map(|field| {
-            if meta.method.is_falliable() {
-                field_falliable_conversion(field.clone(), &meta.target_name, named, source_prefix)
-            } else {
-                field_infalliable_conversion(field.clone(), named, source_prefix)
+    let mut conversions = Vec::new();
+    // Catch-all maps in order of first appearance, with their `(key, value)` entries
+    let mut extras: Vec<(syn::Ident, Vec<TokenStream2>)> = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        // Extra entries are converted like unnamed fields, i.e. just the value
+        let field_named = named && field.extra.is_none();
+
+        let conversion = if meta.from_map {
+            field_map_conversion(field.clone(), meta)
+        } else if meta.accumulate_errors {
+            field_accumulated_conversion(field, index, field_named)
+        } else if meta.method.is_falliable() {
+            field_falliable_conversion(field.clone(), meta, field_named, source_prefix)
+        } else {
+            field_infalliable_conversion(field.clone(), meta, field_named, source_prefix)
+        };
+
+        let Some(extra) = &field.extra else {
+            conversions.push(match &field.cfg_skip {
+                Some(predicate) => cfg_skipped_conversion(field, predicate, conversion),
+                None => conversion,
+            });
+            continue;
+        };
+
+        if !named {
+            return Err(syn::Error::new(
+                field.span,
+                "`extra` is only supported when converting into named fields",
+            ));
+        }
+
+        let key = field.target_name.as_key();
+        let entry = quote_spanned! { field.span => (#key.into(), #conversion) };
+        match extras.iter_mut().find(|(map, _)| map == extra) {
+            Some((_, entries)) => entries.push(entry),
+            None => extras.push((extra.clone(), vec![entry])),
+        }
+    }
+
+    conversions.extend(extras.into_iter().map(|(map, entries)| {
+        quote! {
+            #map: [#(#entries),*].into_iter().collect(),
+        }
+    }));
+
+    Ok(conversions)
+}
+
+/// Whether the type converts both into and from the same other type with plain,
+/// owned `From`/`TryFrom` impls, which a round trip goes through.
+fn has_roundtrip(conversions: &[ConversionMeta], falliable: bool) -> bool {
+    let owned = |meta: &&ConversionMeta| {
+        meta.method.is_falliable() == falliable
+            && !meta.by_ref
+            && !meta.from_arc
+            && !meta.recoverable
+            && meta.ref_method.is_none()
+            && meta.const_fn.is_none()
+            && meta.builder.is_none()
+    };
+    let other_types = |is_from: bool| {
+        conversions
+            .iter()
+            .filter(owned)
+            .filter(move |meta| meta.method.is_from() == is_from)
+            .map(|meta| meta.other_type().to_token_stream().to_string())
+    };
+    other_types(false).any(|into| other_types(true).any(|from| from == into))
+}
+
+/// With `roundtrip_test`, generates `assert_roundtrip::<T>(&sample)` (and
+/// `assert_try_roundtrip` for fallible conversions) on the deriving type, asserting
+/// that converting a sample into `T` and back gives the sample back. They aren't
+/// `#[cfg(test)]`, so integration tests and doc tests can call them too.
+fn roundtrip_helpers(
+    ast: &DeriveInput,
+    conversions: &[ConversionMeta],
+) -> syn::Result<TokenStream2> {
+    let infallible = has_roundtrip(conversions, false);
+    let fallible = has_roundtrip(conversions, true);
+    if !infallible && !fallible {
+        return Err(syn::Error::new(
+            ast.ident.span(),
+            "`roundtrip_test` needs conversions both into and from the same type, e.g. `into(path = \"Dto\")` and `from(path = \"Dto\")`",
+        ));
+    }
+
+    let ident = &ast.ident;
+    let vis = &ast.vis;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let assert_roundtrip = infallible.then(|| {
+        quote! {
+            /// Asserts that converting `sample` into `T` and back gives `sample` back.
+            #[allow(dead_code)]
+            #[track_caller]
+            #vis fn assert_roundtrip<__T>(sample: &Self)
+            where
+                __T: ::core::convert::From<Self>,
+                Self: ::core::convert::From<__T>
+                    + ::core::clone::Clone
+                    + ::core::cmp::PartialEq
+                    + ::core::fmt::Debug,
+            {
+                let converted = <__T as ::core::convert::From<Self>>::from(::core::clone::Clone::clone(sample));
+                let back = <Self as ::core::convert::From<__T>>::from(converted);
+                ::core::assert_eq!(
+                    &back,
+                    sample,
+                    "round trip through {} changed the value",
+                    ::core::any::type_name::<__T>(),
+                );
             }
-        })
-        .collect())
+        }
+    });
+
+    let assert_try_roundtrip = fallible.then(|| {
+        quote! {
+            /// Asserts that converting `sample` into `T` and back succeeds both ways
+            /// and gives `sample` back.
+            #[allow(dead_code)]
+            #[track_caller]
+            #vis fn assert_try_roundtrip<__T>(sample: &Self)
+            where
+                __T: ::core::convert::TryFrom<Self>,
+                <__T as ::core::convert::TryFrom<Self>>::Error: ::core::fmt::Debug,
+                Self: ::core::convert::TryFrom<__T>
+                    + ::core::clone::Clone
+                    + ::core::cmp::PartialEq
+                    + ::core::fmt::Debug,
+                <Self as ::core::convert::TryFrom<__T>>::Error: ::core::fmt::Debug,
+            {
+                let converted = match <__T as ::core::convert::TryFrom<Self>>::try_from(::core::clone::Clone::clone(sample)) {
+                    ::core::result::Result::Ok(converted) => converted,
+                    ::core::result::Result::Err(e) => ::core::panic!(
+                        "converting into {} failed: {:?}",
+                        ::core::any::type_name::<__T>(),
+                        e,
+                    ),
+                };
+                let back = match <Self as ::core::convert::TryFrom<__T>>::try_from(converted) {
+                    ::core::result::Result::Ok(back) => back,
+                    ::core::result::Result::Err(e) => ::core::panic!(
+                        "converting back from {} failed: {:?}",
+                        ::core::any::type_name::<__T>(),
+                        e,
+                    ),
+                };
+                ::core::assert_eq!(
+                    &back,
+                    sample,
+                    "round trip through {} changed the value",
+                    ::core::any::type_name::<__T>(),
+                );
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #assert_roundtrip
+            #assert_try_roundtrip
+        }
+    })
 }
 
 pub(super) fn try_convert_derive(ast: &DeriveInput) -> syn::Result<TokenStream2> {
-    let conversions = extract_conversions(ast);
+    // Reject unions before looking at the attributes, so this is the only error reported
+    if let syn::Data::Union(data_union) = &ast.data {
+        return Err(syn::Error::new(
+            data_union.union_token.span,
+            "Convert cannot be derived for unions: only one field of a union is valid at a time, \
+             so there is no field-by-field mapping to generate. \
+             Use a struct or an enum instead, or implement `From`/`TryFrom` manually",
+        ));
+    }
+
+    let ExtractedConversions {
+        conversions,
+        debug,
+        roundtrip_test,
+    } = extract_conversions(ast)?;
+
+    let roundtrip_helpers = if roundtrip_test {
+        roundtrip_helpers(ast, &conversions)?
+    } else {
+        quote! {}
+    };
 
-    match &ast.data {
+    let generated = match &ast.data {
         syn::Data::Struct(data_struct) => {
             implement_all_struct_conversions(data_struct, conversions)
         }
         syn::Data::Enum(data_enum) => implement_all_enum_conversions(data_enum, conversions),
-        syn::Data::Union(_) => Err(syn::Error::new_spanned(
-            ast.ident.clone(),
-            "Unions are not supported".to_string(),
-        ))?,
+        syn::Data::Union(_) => unreachable!("unions are rejected above"),
+    }?;
+    let generated = quote! {
+        #generated
+        #roundtrip_helpers
+    };
+
+    if debug {
+        eprintln!("derive(Convert) for {}:\n{}", ast.ident, generated);
     }
+
+    Ok(generated)
 }
@@ -1,13 +1,17 @@
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::DataEnum;
+use quote::{format_ident, quote};
+use syn::{DataEnum, ext::IdentExt, spanned::Spanned};
 
 use crate::{
     attribute_parsing::{
         conversion_enum::{ConversionVariant, extract_enum_variants},
+        conversion_field::{FieldIdentifier, INTEGER_TYPES},
         conversion_meta::ConversionMeta,
     },
-    derive_into::build_field_conversions,
+    derive_into::{
+        build_field_conversions, conversion_impl, error_creator, implement_body_conversion,
+        positional_conversions, validate_call,
+    },
 };
 
 pub(super) fn implement_all_enum_conversions(
@@ -17,8 +21,13 @@ pub(super) fn implement_all_enum_conversions(
     let conversion_impls: Vec<_> = conversions
         .into_iter()
         .map(|conversion| {
-            let variants =
-                extract_enum_variants(data_enum, conversion.method, &conversion.other_type())?;
+            if let Some(body) = &conversion.body {
+                return Ok(implement_body_conversion(&conversion, body));
+            }
+            if let Some(integer) = integer_source(&conversion) {
+                return implement_discriminant_conversion(data_enum, &conversion, &integer);
+            }
+            let variants = extract_enum_variants(data_enum, &conversion)?;
             implement_enum_conversion(conversion.clone(), &variants)
         })
         .collect::<Result<_, _>>()?;
@@ -28,6 +37,89 @@ pub(super) fn implement_all_enum_conversions(
     })
 }
 
+/// The primitive integer converted from, e.g. `u8` for `try_from(path = "u8")`.
+fn integer_source(meta: &ConversionMeta) -> Option<syn::Ident> {
+    if !meta.method.is_from() {
+        return None;
+    }
+    meta.source_name
+        .get_ident()
+        .filter(|ident| INTEGER_TYPES.iter().any(|integer| *ident == integer))
+        .cloned()
+}
+
+/// Converts an integer into the fieldless variant with that discriminant. Each
+/// discriminant becomes a constant of the integer type, so out of range ones fail
+/// to compile, and variants without one follow the previous one like rustc numbers
+/// them.
+fn implement_discriminant_conversion(
+    data_enum: &DataEnum,
+    meta: &ConversionMeta,
+    integer: &syn::Ident,
+) -> syn::Result<TokenStream2> {
+    if !meta.method.is_falliable() {
+        return Err(syn::Error::new(
+            integer.span(),
+            format!(
+                "Not every `{}` is a variant, use `try_from(path = \"{}\")` instead",
+                integer, integer
+            ),
+        ));
+    }
+
+    if let Some(variant) = data_enum
+        .variants
+        .iter()
+        .find(|variant| !variant.fields.is_empty())
+    {
+        return Err(syn::Error::new(
+            variant.span(),
+            format!(
+                "Converting from `{}` is only supported on enums without fields, `{}` has fields",
+                integer, variant.ident
+            ),
+        ));
+    }
+
+    let target_name = &meta.target_name;
+    let constructor = meta.constructor();
+    let error_creator = error_creator();
+    let validate_call = validate_call(meta);
+
+    let mut previous: Option<syn::Ident> = None;
+    let mut constants = Vec::new();
+    let mut arms = Vec::new();
+    for (index, variant) in data_enum.variants.iter().enumerate() {
+        let constant = format_ident!("__DISCRIMINANT_{}", index);
+        let value = match (&variant.discriminant, &previous) {
+            (Some((_, discriminant)), _) => quote! { #discriminant },
+            (None, Some(previous)) => quote! { #previous + 1 },
+            (None, None) => quote! { 0 },
+        };
+        constants.push(quote! { const #constant: #integer = #value; });
+        let variant_name = &variant.ident;
+        arms.push(quote! { #constant => #constructor::#variant_name, });
+        previous = Some(constant);
+    }
+
+    Ok(conversion_impl(
+        meta,
+        quote! {
+            #validate_call
+            #(#constants)*
+            ::core::result::Result::Ok(match source {
+                #(#arms)*
+                other => {
+                    return ::core::result::Result::Err(#error_creator(
+                        "Failed trying to convert {} to {}: unknown discriminant {}",
+                        ::core::stringify!(#integer), ::core::stringify!(#target_name), other,
+                    ).into());
+                }
+            })
+        },
+    ))
+}
+
 fn implement_enum_conversion(
     meta: ConversionMeta,
     variants: &[ConversionVariant],
@@ -37,78 +129,272 @@ fn implement_enum_conversion(
         target_name,
         method,
         default_allowed,
-        validate,
+        from_map,
+        accumulate_errors,
+        reject_defaults,
+        target_tuple,
+        wrap_variant,
+        wrap_newtype,
+        expect_fields,
+        default_fields: defaulted_fields,
+        nests,
+        ..
     } = meta.clone();
 
+    if from_map {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`from_map` is not supported for enums",
+        ));
+    }
+
+    if accumulate_errors {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`accumulate_errors` is not supported for enums",
+        ));
+    }
+
+    if target_tuple {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`target_tuple` is not supported for enums",
+        ));
+    }
+
+    if wrap_variant.is_some() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`wrap_variant` is not supported for enums",
+        ));
+    }
+
+    if wrap_newtype {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`wrap_newtype` is not supported for enums",
+        ));
+    }
+
+    if expect_fields.is_some() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`expect_fields` is not supported for enums",
+        ));
+    }
+
+    if !nests.is_empty() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`nest` is not supported for enums",
+        ));
+    }
+
+    if !meta.computes.is_empty() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`compute` is not supported for enums",
+        ));
+    }
+
+    if !defaulted_fields.is_empty() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`default_field` is not supported for enums",
+        ));
+    }
+
+    if !reject_defaults.is_empty() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`reject_defaults` is not supported for enums",
+        ));
+    }
+
+    if meta.via_getters {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`via_getters` is not supported for enums",
+        ));
+    }
+
+    if meta.const_fn.is_some() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`const_fn` is not supported for enums",
+        ));
+    }
+
+    if meta.builder.is_some() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`builder` is not supported for enums",
+        ));
+    }
+
     let default_fields = if default_allowed {
-        quote! { ..Default::default() }
+        quote! { ..::core::default::Default::default() }
     } else {
         quote! {}
     };
 
+    let error_creator = error_creator();
+    let constructor = meta.constructor();
+
+    // `View<'a>::Variant` isn't a valid pattern, and the arguments are inferred anyway
+    let mut source_enum = source_name.clone();
+    for segment in &mut source_enum.segments {
+        segment.arguments = syn::PathArguments::None;
+    }
+
+    let variant_names = variants.iter().map(|variant| {
+        let source_variant_name = &variant.source_name;
+        let name = syn::LitStr::new(
+            &variant.target_name.unraw().to_string(),
+            variant.target_name.span(),
+        );
+        // `{ .. }` matches unit, tuple and named variants alike
+        quote! {
+            #source_enum::#source_variant_name { .. } => ::std::string::String::from(#name),
+        }
+    });
+
     let variant_conversions = variants.iter().map(|variant| {
         let ConversionVariant {
             source_name: source_variant_name,
             target_name: target_variant_name,
-            named_variant,
+            source_named,
+            target_named,
             fields,
+            conversion_func,
+            skipped_fields,
+            map_to,
         } = variant;
 
-        let source_fields = fields.iter().map(|f| f.source_name.as_named());
+        if let Some(map_to) = map_to {
+            return Ok(quote! {
+                #source_enum::#source_variant_name { .. } => #map_to,
+            });
+        }
+
+        if let Some(func) = conversion_func {
+            let converted = if method.is_falliable() {
+                quote! {
+                    #func(__value).map_err(|e| #error_creator("Failed trying to convert {} to {}: {:?}",
+                        ::core::concat!(::core::stringify!(#source_name), "::", ::core::stringify!(#source_variant_name)),
+                        ::core::stringify!(#target_name), e))?
+                }
+            } else {
+                quote! { #func(__value) }
+            };
+            return Ok(quote! {
+                #source_enum::#source_variant_name(__value) => #constructor::#target_variant_name(#converted),
+            });
+        }
+
+        // Converting from the other enum, defaulted fields don't exist on its variant.
+        // A tuple source is matched with its fields in order
+        let mut source_fields: Vec<_> = fields
+            .iter()
+            .filter(|f| !(method.is_from() && f.default))
+            .map(|f| &f.source_name)
+            .collect();
+        if !*source_named {
+            source_fields.sort_by_key(|name| match name {
+                FieldIdentifier::Unnamed(index) => *index,
+                FieldIdentifier::Named(_) => 0,
+            });
+        }
+        // Skipped fields of a tuple source leave holes in its pattern, filled with `_`
+        let source_fields: Vec<_> = if *source_named {
+            source_fields.iter().map(|name| name.as_named()).collect()
+        } else {
+            let mut next = 0;
+            let mut bindings = Vec::new();
+            for name in &source_fields {
+                if let FieldIdentifier::Unnamed(index) = name {
+                    bindings.extend((next..*index).map(|_| quote! { _ }));
+                    next = index + 1;
+                }
+                bindings.push(name.as_named());
+            }
+            bindings
+        };
 
         let field_conversions =
-            build_field_conversions(&meta, *named_variant, false, fields).unwrap();
+            build_field_conversions(&meta, *target_named, false, fields)?;
+
+        // Skipped fields aren't bound, and are dropped from the target
+        let rest = if *skipped_fields {
+            quote! { .. }
+        } else {
+            quote! {}
+        };
 
         if variant.fields.is_empty() {
-            return quote! {
-                #source_name::#source_variant_name => #target_name::#target_variant_name,
-            };
+            if *skipped_fields {
+                return Ok(quote! {
+                    #source_enum::#source_variant_name { .. } => #constructor::#target_variant_name,
+                });
+            }
+            return Ok(quote! {
+                #source_enum::#source_variant_name => #constructor::#target_variant_name,
+            });
         }
 
-        if variant.named_variant {
+        let source_pattern = if *source_named {
+            quote! { { #(#source_fields,)* #rest } }
+        } else {
+            quote! { (#(#source_fields,)* #rest) }
+        };
+
+        Ok(if *target_named {
             quote! {
-                #source_name::#source_variant_name{ #(#source_fields),* } => #target_name::#target_variant_name {
+                #source_enum::#source_variant_name #source_pattern => #constructor::#target_variant_name {
                     #(#field_conversions)*
                     #default_fields
                 },
             }
         } else {
+            let (positional_bindings, field_conversions) =
+                positional_conversions(fields, field_conversions);
             quote! {
-                #source_name::#source_variant_name(#(#source_fields),*) => {
-                    #target_name::#target_variant_name(#(#field_conversions)*)
+                #source_enum::#source_variant_name #source_pattern => {
+                    #positional_bindings
+                    #constructor::#target_variant_name(#(#field_conversions)*)
                 },
             }
-        }
+        })
     });
 
-    let validate_call = validate.map(|func| quote! {
-        #func(&source).map_err(|e| format!("Failed trying to convert {} to {}: {}",
-            stringify!(#source_name), stringify!(#target_name), e))?;
-    });
+    let variant_conversions: Vec<_> = if meta.variant_names {
+        variant_names.collect()
+    } else {
+        variant_conversions.collect::<syn::Result<_>>()?
+    };
+
+    let validate_call = validate_call(&meta);
 
     Ok(if method.is_falliable() {
-        quote! {
-            impl TryFrom<#source_name> for #target_name {
-                type Error = String;
-                fn try_from(source: #source_name) -> Result<#target_name, Self::Error> {
-                    #validate_call
-                    Ok(
-                        match source {
-                            #(#variant_conversions)*
-                        }
-                    )
-                }
-            }
-        }
-    } else {
-        quote! {
-            impl From<#source_name> for #target_name {
-                fn from(source: #source_name) -> #target_name {
+        conversion_impl(
+            &meta,
+            quote! {
+                #validate_call
+                ::core::result::Result::Ok(
                     match source {
                         #(#variant_conversions)*
                     }
+                )
+            },
+        )
+    } else {
+        conversion_impl(
+            &meta,
+            quote! {
+                match source {
+                    #(#variant_conversions)*
                 }
-            }
-        }
+            },
+        )
     })
 }
@@ -7,6 +7,11 @@ mod enum_convert;
 mod struct_convert;
 mod util;
 
+#[cfg(all(feature = "anyhow", feature = "box-error"))]
+compile_error!(
+    "The `anyhow` and `box-error` features select the error type and are mutually exclusive"
+);
+
 /** # derive-into
 
  For more information, visit the [github repository](https://github.com/sharonex/derive-into/tree/darling-migration).
@@ -47,6 +52,35 @@ mod util;
  | `#[convert(from(path = "Type"))]` | Implements `From<Type> for Self` |
  | `#[convert(try_into(path = "Type"))]` | Implements `TryFrom<Self> for Type` |
  | `#[convert(try_from(path = "Type"))]` | Implements `TryFrom<Type> for Self` |
+ | `#[convert(into(path = "Type", default_field = "f"))]` | Fills the target-only field `f` with its `Default`; repeatable |
+ | `#[convert(into(path = "Alias", target_tuple))]` | The other type is a tuple (e.g. behind a type alias) |
+ | `#[convert(into(path = "View<'a>", by_ref))]` | Converts from `&'a Self`; fields are cloned and `with_func` gets the borrow |
+ | `#[convert(into(path = "UserDto", ref_method = "to_dto"))]` | Also generates `fn to_dto(&self) -> UserDto`, cloning fields out of the borrow |
+ | `#[convert(into(path = "Point", const_fn = "to_point"))]` | Also generates `const fn to_point(self) -> Point` when every field is `identity` or skipped |
+ | `#[convert(into(path = "Account", builder = "AccountBuilder"))]` | Generates `fn into_partial(self) -> AccountBuilder`, calling a setter per converted field |
+ | `#[convert(try_from(path = "Type", normalize = "func"))]` | Call `func(&mut source)` before converting |
+ | `#[convert(into(path = "ApiUser", from_arc))]` | Converts from `Arc<Self>`, cloning the source out only if shared |
+ | `#[convert(into(path = "Tick", inline))]` | Marks the generated function `#[inline]` (or `inline = "always"`/`"never"`) |
+ | `#[convert(from(path = "Foreign", via_getters))]` | Reads source fields through getter methods |
+ | `#[convert(try_from(path = "Type", accumulate_errors))]` | Reports every failing field instead of only the first |
+ | `#[convert(try_from(path = "Type", reject_defaults("id")))]` | Fails if a listed source field equals its `Default` value |
+ | `#[convert(into(path = "Type", rename_all = "snake_case"))]` | Renames enum variants to a case convention unless they have a `rename` |
+ | `#[convert(into(path = "Type", map_variants(Foo = "Bar")))]` | Maps enum variants to the other type's variant names |
+ | `#[convert(try_from(path = "HashMap<String, String>", from_map))]` | Parses each field from the map entry with the same key |
+ | `#[convert(into(path = "Enum", wrap_variant = "Variant"))]` | Wraps a struct's fields into a variant of the target enum |
+ | `#[convert(into(path = "Wrapper", wrap_newtype))]` | Builds a newtype target from a struct's single field |
+ | `#[convert(into(path = "Type", expect_fields = 3))]` | Errors unless exactly 3 target fields are produced |
+ | `#[convert(into(path = "Type", require_explicit))]` | Errors on fields without a `#[convert(...)]` attribute for this conversion |
+ | `#[convert(into(path = "Type", compute(field = "full_name", expr = "...")))]` | Sets a target field to an expression over `source` |
+ | `#[convert(into(path = "Type", nest(field = "f", path = "Inner", from = ["a", "b"])))]` | Builds the nested field `f: Inner { a, b }` from flat source fields |
+ | `#[convert(into(path = "Type", body = "func"))]` | Delegates the whole conversion to a hand-written function |
+ | `#[convert(try_from(path = "Type", recoverable))]` | Returns the source along with the error on failure |
+ | `#[convert(into(path = "String", variant_names))]` | Converts an enum into its variant's name |
+ | `#[convert(into(path = "Type", doc = "..."))]` | Attaches a doc comment to the generated impl |
+ | `#[convert(debug)]` | Prints the generated code to stderr at compile time |
+ | `#[convert(roundtrip_test)]` | Generates `assert_roundtrip::<T>(&sample)` to check conversions both ways |
+ | `#[convert(container(path = "Grid", iter))]` | Converts a user-defined container element-wise, like `Vec` |
+ | `#[convert(reserve_capacity)]` | Pre-allocates `Vec` and `HashMap` fields instead of collecting them |
 
  Multiple conversion attributes can be specified for a single type:
 
@@ -97,8 +131,30 @@ mod util;
  | `#[convert(skip)]` | Excludes field from conversion |
  | `#[convert(default)]` | Uses `Default::default()` for this field |
  | `#[convert(unwrap)]` | Unwraps `Option` (`try_from` fails if `None`) |
+ | `#[convert(unwrap(expect = "message"))]` | Unwraps with a custom panic or error message |
  | `#[convert(unwrap_or_default)]` | Automatically calls unwrap_or_default on `Option` value before converting it |
- | `#[convert(with_func = "func_name")]` | Uses custom conversion function |
+ | `#[convert(try_from(none_on_err))]` | A fallible `Option` field becomes `None` when its value fails to convert |
+ | `#[convert(parse)]` | Parses a string field with `FromStr` in fallible conversions |
+ | `#[convert(shared = "clone")]` | Converts the value behind an `Arc`/`Rc`, cloning it out or, with `"try_unwrap"`, failing when shared |
+ | `#[convert(option_to_result = "MissingErr")]` | Converts `Option<T>` into `Result<U, E>`, with `Err(MissingErr)` for `None` |
+ | `#[convert(result_to_option)]` | Converts `Result<T, E>` into `Option<U>`, dropping the error |
+ | `#[convert(with_func = "func_name")]` | Uses custom conversion function, given the unwrapped value with `unwrap` |
+ | `#[convert(with_func_opt = "func_name")]` | Like `with_func` for functions returning `Option`; `None` fails a fallible conversion |
+ | `#[convert(container(path = "Grid", iter))]` | Converts a user-defined container element-wise for this field only |
+ | `#[convert(map_keys(skip), map_values = "func")]` | Keeps, `.into()`s or converts the keys and values of a `HashMap` or `BTreeMap` separately |
+ | `#[convert(position = 0)]` | Maps a named field to index 0 of a tuple struct or variant |
+ | `#[convert(error_variant = MyError::BadName)]` | Wraps the field's error in a variant of your own error type |
+ | `#[convert(identity)]` | Moves the field as is, without converting it |
+ | `#[convert(auto)]` | Converts the field as usual, for `require_explicit` |
+ | `#[convert(boxed_dyn = "Handler")]` | Boxes the field as a `Box<dyn Handler>` trait object |
+ | `#[convert(unbox)]` | Converts the value inside the field's `Box`es, e.g. `Option<Box<Node>>` trees, and boxes it again. Opt-in, since a `Box` field otherwise converts as a whole, e.g. `T` into `Box<T>` or `Box<T>` into `Arc<T>` |
+ | `#[convert(tuple_elements)]` | Converts the field's tuples element by element, e.g. `(u32, String)` into `(Score, Tag)`. Opt-in, since a tuple field otherwise converts as a whole, e.g. into a type implementing `From<(A, B)>` |
+ | `#[convert(to_array = 16)]` | Converts a `Vec<T>` to or from `[U; 16]`, checking the length |
+ | `#[convert(manual)]` | Converts the whole field with an existing `From`/`TryFrom` impl |
+ | `#[convert(map_method = "method_name")]` | Converts the result of calling a method on the field |
+ | `#[convert(extra = "map_field")]` | Inserts this field into the target's `map_field` map, keyed by name |
+ | `#[convert(wrap_if = "!source.note.is_empty()")]` | Converts into `Some(..)` when the condition holds, `None` otherwise |
+ | `#[convert(cfg_skip = "feature = \"legacy\"")]` | Uses `Default::default()` when the cfg predicate is active |
 
  ### Custom Conversion Functions
 
@@ -129,7 +185,7 @@ struct ApiModel {
 
  * **Direct mapping**: Identical types are copied directly
  * **Automatic conversion**: Uses `From`/`Into` for different types
- * **Container types**: Handles `Option<T>`, `Vec<T>`, and `HashMap<K,V>`
+ * **Container types**: Handles `Option<T>`, `Result<T, E>`, `Box<T>` (with `unbox`, except `Box<str>` and boxed slices), `Cow<'a, T>`, `Vec<T>`, `HashMap<K,V>` and `BTreeMap<K,V>`, collecting between `Vec` and the std sets, and `[T; N]` arrays and tuples (with `tuple_elements`) element by element
  * **Nested conversions**: Converts nested structs/enums automatically
 
  ## Container Type Examples
@@ -260,6 +316,8 @@ pub fn derive_into(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
 #[cfg(test)]
 mod tests {
+    // The other cases check error messages as `String`s
+    #[cfg(not(feature = "box-error"))]
     #[test]
     fn test_derive_macro() {
         let t = trybuild::TestCases::new();
@@ -270,5 +328,192 @@ mod tests {
         t.pass("tests/cases/test_struct_conversions.rs");
         t.pass("tests/cases/test_field_attributes.rs");
         t.pass("tests/cases/test_nested_containers.rs");
+        t.pass("tests/cases/test_cfg_skip.rs");
+        t.pass("tests/cases/test_from_map.rs");
+        t.pass("tests/cases/test_module_paths.rs");
+        t.pass("tests/cases/test_accumulate_errors.rs");
+        t.pass("tests/cases/test_extra_fields.rs");
+        t.pass("tests/cases/test_string_like.rs");
+        t.pass("tests/cases/test_reject_defaults.rs");
+        t.pass("tests/cases/test_map_method.rs");
+        t.pass("tests/cases/test_enum_names.rs");
+        t.pass("tests/cases/test_manual.rs");
+        t.pass("tests/cases/test_target_tuple.rs");
+        t.pass("tests/cases/test_by_ref.rs");
+        t.pass("tests/cases/test_rename_all.rs");
+        t.pass("tests/cases/test_debug.rs");
+        t.pass("tests/cases/test_custom_body.rs");
+        t.pass("tests/cases/test_wrap_variant.rs");
+        t.pass("tests/cases/test_wrap_if.rs");
+        t.pass("tests/cases/test_wrap_newtype.rs");
+        t.pass("tests/cases/test_option_fallible.rs");
+        t.pass("tests/cases/test_with_func_opt.rs");
+        t.pass("tests/cases/test_to_array.rs");
+        t.pass("tests/cases/test_expect_fields.rs");
+        t.pass("tests/cases/test_variant_with_func.rs");
+        t.pass("tests/cases/test_default_field.rs");
+        t.pass("tests/cases/test_nest.rs");
+        t.pass("tests/cases/test_tuple_with_func.rs");
+        t.pass("tests/cases/test_custom_container.rs");
+        t.pass("tests/cases/test_reserve_capacity.rs");
+        t.pass("tests/cases/test_unwrap_with_func.rs");
+        t.pass("tests/cases/test_map_sides.rs");
+        t.pass("tests/cases/test_enum_skip_fields.rs");
+        t.pass("tests/cases/test_generic_target.rs");
+        t.pass("tests/cases/test_slice_fields.rs");
+        t.pass("tests/cases/test_recoverable.rs");
+        t.pass("tests/cases/test_position.rs");
+        t.pass("tests/cases/test_from_direction.rs");
+        t.pass("tests/cases/test_variant_names.rs");
+        t.pass("tests/cases/test_impl_doc.rs");
+        t.pass("tests/cases/test_nested_derive.rs");
+        t.pass("tests/cases/test_unwrap_expect.rs");
+        t.pass("tests/cases/test_tuple_skip.rs");
+        t.pass("tests/cases/test_map_variants.rs");
+        t.pass("tests/cases/test_error_variant.rs");
+        t.pass("tests/cases/test_via_getters.rs");
+        t.pass("tests/cases/test_validate_closure.rs");
+        t.pass("tests/cases/test_nested_enum.rs");
+        t.pass("tests/cases/test_lint_clean.rs");
+        t.pass("tests/cases/test_identity.rs");
+        t.pass("tests/cases/test_from_arc.rs");
+        t.pass("tests/cases/test_inline.rs");
+        t.pass("tests/cases/test_cross_container.rs");
+        t.pass("tests/cases/test_map_to.rs");
+        t.pass("tests/cases/test_shadowed_prelude.rs");
+        t.pass("tests/cases/test_boxed_dyn.rs");
+        t.pass("tests/cases/test_all_skipped.rs");
+        t.pass("tests/cases/test_ref_method.rs");
+        t.pass("tests/cases/test_normalize.rs");
+        t.pass("tests/cases/test_none_on_err.rs");
+        t.pass("tests/cases/test_roundtrip.rs");
+        t.pass("tests/cases/test_vec_of_enums.rs");
+        t.pass("tests/cases/test_require_explicit.rs");
+        t.pass("tests/cases/test_variant_shapes.rs");
+        t.pass("tests/cases/test_wrap_variant_fields.rs");
+        t.pass("tests/cases/test_option_result.rs");
+        t.pass("tests/cases/test_integer_narrowing.rs");
+        t.pass("tests/cases/test_validate_many.rs");
+        t.pass("tests/cases/test_compute.rs");
+        t.pass("tests/cases/test_extra_source_fields.rs");
+        t.pass("tests/cases/test_single_field_variants.rs");
+        t.pass("tests/cases/test_raw_identifiers.rs");
+        t.pass("tests/cases/test_discriminants.rs");
+        t.pass("tests/cases/test_const_fn.rs");
+        t.pass("tests/cases/test_builder.rs");
+        t.pass("tests/cases/test_parse.rs");
+        t.pass("tests/cases/test_btreemap.rs");
+        t.pass("tests/cases/test_queues.rs");
+        t.pass("tests/cases/test_boxed_fields.rs");
+        t.pass("tests/cases/test_shared_fields.rs");
+        t.pass("tests/cases/test_cow_fields.rs");
+        t.pass("tests/cases/test_result_fields.rs");
+        t.pass("tests/cases/test_tuple_fields.rs");
+        t.pass("tests/cases/test_fixed_arrays.rs");
+        t.pass("tests/cases/test_custom_hasher.rs");
+        t.pass("tests/cases/test_qualified_paths.rs");
+        t.compile_fail("tests/cases/compile_fail/*.rs");
+    }
+
+    #[cfg(all(feature = "smallvec", not(feature = "box-error")))]
+    #[test]
+    fn test_smallvec() {
+        let t = trybuild::TestCases::new();
+        t.pass("tests/cases/test_smallvec.rs");
+    }
+
+    #[cfg(all(feature = "arrayvec", not(feature = "box-error")))]
+    #[test]
+    fn test_arrayvec() {
+        let t = trybuild::TestCases::new();
+        t.pass("tests/cases/test_arrayvec.rs");
+        t.compile_fail("tests/cases/compile_fail/features/arrayvec_*.rs");
+    }
+
+    #[cfg(all(feature = "heapless", not(feature = "box-error")))]
+    #[test]
+    fn test_heapless() {
+        let t = trybuild::TestCases::new();
+        t.pass("tests/cases/test_heapless.rs");
+        t.compile_fail("tests/cases/compile_fail/features/heapless_*.rs");
+    }
+
+    #[cfg(feature = "box-error")]
+    #[test]
+    fn test_box_error() {
+        let t = trybuild::TestCases::new();
+        t.pass("tests/cases/test_box_error.rs");
+        t.pass("tests/cases/test_discriminants.rs");
+    }
+
+    // The snapshots are generated with the default `String` errors
+    #[cfg(not(any(feature = "anyhow", feature = "box-error")))]
+    mod snapshots {
+        use syn::{DeriveInput, Token, punctuated::Punctuated};
+
+        use crate::derive_into::try_convert_derive;
+
+        /// Test cases whose generated code is kept in `tests/expand`, covering the
+        /// infallible, fallible, enum, tuple and map paths of the codegen.
+        const SNAPSHOT_CASES: &[&str] = &[
+            "basic",
+            "test_struct_conversions",
+            "test_enum_conversions",
+            "test_option_fallible",
+            "test_from_map",
+            "test_tuple_with_func",
+            "test_accumulate_errors",
+        ];
+
+        /// Compares the code generated for each snapshot case with its `.expanded.rs`
+        /// file. After an intended codegen change, rerun with `SNAPSHOTS=overwrite`
+        /// and review the diff of the snapshots.
+        #[test]
+        fn test_expansion_snapshots() {
+            let overwrite = std::env::var("SNAPSHOTS").is_ok_and(|value| value == "overwrite");
+            let mut changed = Vec::new();
+
+            for case in SNAPSHOT_CASES {
+                let source = std::fs::read_to_string(format!("tests/cases/{}.rs", case)).unwrap();
+                let expanded = expand_derives(&source);
+                let path = format!("tests/expand/{}.expanded.rs", case);
+
+                if overwrite {
+                    std::fs::write(&path, expanded).unwrap();
+                } else if std::fs::read_to_string(&path).ok().as_ref() != Some(&expanded) {
+                    changed.push(path);
+                }
+            }
+
+            assert!(
+                changed.is_empty(),
+                "Generated code differs from {:?}. If the change is intended, rerun with SNAPSHOTS=overwrite",
+                changed
+            );
+        }
+
+        /// Runs the derive on every top-level `#[derive(Convert)]` item of a test case
+        /// and pretty-prints the generated impls.
+        fn expand_derives(source: &str) -> String {
+            let file = syn::parse_file(source).unwrap();
+            let generated = file.items.into_iter().filter_map(|item| {
+                let input: DeriveInput = match item {
+                    syn::Item::Struct(item) => syn::parse_quote!(#item),
+                    syn::Item::Enum(item) => syn::parse_quote!(#item),
+                    _ => return None,
+                };
+                derives_convert(&input).then(|| try_convert_derive(&input).unwrap())
+            });
+            prettyplease::unparse(&syn::parse_quote!(#(#generated)*))
+        }
+
+        fn derives_convert(input: &DeriveInput) -> bool {
+            input.attrs.iter().any(|attr| {
+                attr.path().is_ident("derive")
+                    && attr
+                        .parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                        .is_ok_and(|derives| derives.iter().any(|path| path.is_ident("Convert")))
+            })
+        }
     }
 }
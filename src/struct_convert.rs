@@ -1,12 +1,18 @@
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::{DataStruct, spanned::Spanned};
+use quote::{ToTokens, format_ident, quote};
+use syn::{DataStruct, ext::IdentExt, spanned::Spanned};
 
 use crate::{
     attribute_parsing::{
-        conversion_field::extract_convertible_fields, conversion_meta::ConversionMeta,
+        conversion_field::{
+            ConvertibleField, FieldConversionMethod, FieldIdentifier, extract_convertible_fields,
+        },
+        conversion_meta::{Compute, ConversionMeta, Nest},
+    },
+    derive_into::{
+        build_accumulated_bindings, build_field_conversions, conversion_impl, error_creator,
+        implement_body_conversion, positional_conversions, validate_call,
     },
-    derive_into::build_field_conversions,
 };
 
 pub(super) fn implement_all_struct_conversions(
@@ -22,19 +28,95 @@ pub(super) fn implement_all_struct_conversions(
     let conversion_impls: Vec<_> = conversions
         .into_iter()
         .map(|conversion| {
+            if let Some(body) = &conversion.body {
+                return Ok(implement_body_conversion(&conversion, body));
+            }
+            let mut conversion = conversion;
+            let mut fields = extract_convertible_fields(
+                &data_struct.fields,
+                conversion.method,
+                &conversion.other_type(),
+                &conversion.containers,
+                conversion.require_explicit,
+            )?;
+            if conversion.method.is_from() {
+                default_own_fields(&mut conversion, &mut fields)?;
+            }
+            check_const_fields(&conversion, &fields)?;
+            if conversion.wrap_newtype && fields.len() != 1 {
+                return Err(syn::Error::new(
+                    conversion.source_name.span(),
+                    "`wrap_newtype` requires exactly one converted field",
+                ));
+            }
+            // Named fields placed with `position` build a tuple target
+            let positioned = fields
+                .iter()
+                .any(|field| matches!(field.target_name, FieldIdentifier::Unnamed(_)));
+            // A newtype target is built positionally, whatever the source fields look like
+            let named_target = named_struct && !conversion.wrap_newtype && !positioned;
+            if conversion.via_getters && !named_target {
+                return Err(syn::Error::new(
+                    conversion.source_name.span(),
+                    "`via_getters` is only supported between structs with named fields",
+                ));
+            }
+            if conversion.builder.is_some() {
+                let (bindings, setters) = builder_setters(&conversion, named_target, &fields)?;
+                return implement_struct_conversion(conversion, named_target, bindings, setters);
+            }
+            let bindings = if conversion.accumulate_errors {
+                build_accumulated_bindings(&conversion, true, &fields)
+            } else {
+                quote! {}
+            };
+            let (fields, nested) = group_nested_fields(&conversion, named_target, fields)?;
+            let mut field_conversions =
+                build_field_conversions(&conversion, named_target, true, &fields)?;
+            for (nest, nest_fields) in nested {
+                let Nest { field, path, .. } = nest;
+                let inner = build_field_conversions(&conversion, true, true, &nest_fields)?;
+                field_conversions.push(quote! { #field: #path { #(#inner)* }, });
+            }
+            if !conversion.computes.is_empty() && !named_target {
+                return Err(syn::Error::new(
+                    conversion.source_name.span(),
+                    "`compute` is only supported when converting into named fields",
+                ));
+            }
+            // Evaluated up front, while every field of the source is still there
+            let mut computed = Vec::new();
+            for Compute { field, expr } in &conversion.computes {
+                let binding = format_ident!("__computed_{}", field.unraw());
+                computed.push(quote! { let #binding = #expr; });
+                field_conversions.push(quote! { #field: #binding, });
+            }
+            // Nothing would initialize the target's fields, which rustc reports field by field
+            if named_target
+                && field_conversions.is_empty()
+                && !data_struct.fields.is_empty()
+                && !conversion.default_allowed
+                && conversion.default_fields.is_empty()
+            {
+                return Err(syn::Error::new(
+                    conversion.source_name.span(),
+                    format!(
+                        "Every field is skipped when converting to `{}`, so none of its fields are set. Add `default` to the conversion, e.g. `{}(path = \"...\", default)`, to fill them with `Default::default()`",
+                        conversion.target_name.to_token_stream(),
+                        conversion.method.attribute_name(),
+                    ),
+                ));
+            }
+            let (positional_bindings, field_conversions) = if named_target {
+                (quote! {}, field_conversions)
+            } else {
+                positional_conversions(&fields, field_conversions)
+            };
             implement_struct_conversion(
                 conversion.clone(),
-                named_struct,
-                build_field_conversions(
-                    &conversion,
-                    named_struct,
-                    true,
-                    &extract_convertible_fields(
-                        &data_struct.fields,
-                        conversion.method,
-                        &conversion.other_type(),
-                    )?,
-                )?,
+                named_target,
+                quote! { #(#computed)* #bindings #positional_bindings },
+                field_conversions,
             )
         })
         .collect::<Result<_, _>>()?;
@@ -44,9 +126,166 @@ pub(super) fn implement_all_struct_conversions(
     })
 }
 
+/// Converting from the other type, the `default_field`s are fields of the deriving
+/// type, so they are defaulted in place rather than added to the literal.
+fn default_own_fields(
+    meta: &mut ConversionMeta,
+    fields: &mut [ConvertibleField],
+) -> syn::Result<()> {
+    for name in std::mem::take(&mut meta.default_fields) {
+        let field = fields
+            .iter_mut()
+            .find(|field| name.unraw() == field.target_name.as_key().value())
+            .ok_or_else(|| {
+                syn::Error::new(
+                    name.span(),
+                    format!(
+                        "`{}` is not a converted field, so it can't be defaulted",
+                        name
+                    ),
+                )
+            })?;
+        field.default = true;
+    }
+    Ok(())
+}
+
+/// A `const fn` can't call trait methods like `.into()` or `Default::default()`, so
+/// with `const_fn` every converted field has to be moved as is.
+fn check_const_fields(meta: &ConversionMeta, fields: &[ConvertibleField]) -> syn::Result<()> {
+    if meta.const_fn.is_none() {
+        return Ok(());
+    }
+
+    if meta.default_allowed
+        || !meta.default_fields.is_empty()
+        || !meta.nests.is_empty()
+        || !meta.computes.is_empty()
+    {
+        return Err(syn::Error::new(
+            meta.source_name.span(),
+            "`const_fn` cannot be combined with `default`, `default_field`, `nest` or `compute`",
+        ));
+    }
+
+    let non_const = fields.iter().find(|field| {
+        !matches!(field.method, FieldConversionMethod::Keep)
+            || field.default
+            || field.map_method.is_some()
+            || field.cfg_skip.is_some()
+            || field.extra.is_some()
+    });
+    match non_const {
+        Some(field) => Err(syn::Error::new(
+            field.span,
+            "`const_fn` can only move fields as is, since a `const fn` can't call `.into()`. Mark the field `#[convert(identity)]`, or skip it",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// With `builder`, the converted fields are bound in declaration order and passed
+/// to the builder's setters, which are named after the target's fields.
+fn builder_setters(
+    meta: &ConversionMeta,
+    named_target: bool,
+    fields: &[ConvertibleField],
+) -> syn::Result<(TokenStream2, Vec<TokenStream2>)> {
+    if !named_target {
+        return Err(syn::Error::new(
+            meta.source_name.span(),
+            "`builder` is only supported when converting into named fields, whose names are the setters",
+        ));
+    }
+
+    if meta.accumulate_errors || !meta.nests.is_empty() || !meta.computes.is_empty() {
+        return Err(syn::Error::new(
+            meta.source_name.span(),
+            "`builder` cannot be combined with `accumulate_errors`, `nest` or `compute`",
+        ));
+    }
+
+    if let Some(field) = fields
+        .iter()
+        .find(|field| field.extra.is_some() || field.cfg_skip.is_some())
+    {
+        return Err(syn::Error::new(
+            field.span,
+            "`extra` and `cfg_skip` fields are not supported with `builder`",
+        ));
+    }
+
+    let conversions = build_field_conversions(meta, false, true, fields)?;
+    let values: Vec<_> = (0..fields.len())
+        .map(|index| format_ident!("__field_{}", index))
+        .collect();
+    let setters = fields
+        .iter()
+        .zip(&values)
+        .map(|(field, value)| {
+            let setter = field.target_name.as_named();
+            quote! { .#setter(#value) }
+        })
+        .collect();
+
+    Ok((quote! { let (#(#values,)*) = (#(#conversions)*); }, setters))
+}
+
+type NestedFields = Vec<(Nest, Vec<ConvertibleField>)>;
+
+/// Splits off the fields gathered by each `nest`, in the order of the `from` list.
+fn group_nested_fields(
+    meta: &ConversionMeta,
+    named_target: bool,
+    mut fields: Vec<ConvertibleField>,
+) -> syn::Result<(Vec<ConvertibleField>, NestedFields)> {
+    if meta.nests.is_empty() {
+        return Ok((fields, Vec::new()));
+    }
+
+    if !named_target {
+        return Err(syn::Error::new(
+            meta.source_name.span(),
+            "`nest` is only supported when converting into named fields",
+        ));
+    }
+
+    if meta.accumulate_errors {
+        return Err(syn::Error::new(
+            meta.source_name.span(),
+            "`nest` cannot be combined with `accumulate_errors`",
+        ));
+    }
+
+    let mut nested = Vec::new();
+    for nest in &meta.nests {
+        let mut nest_fields = Vec::new();
+        for name in &nest.from {
+            let position = fields
+                .iter()
+                .position(|field| field.source_name.as_key().value() == name.value())
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        name.span(),
+                        format!(
+                            "`{}` is not a converted field, so it can't be nested into `{}`",
+                            name.value(),
+                            nest.field
+                        ),
+                    )
+                })?;
+            nest_fields.push(fields.remove(position));
+        }
+        nested.push((nest.clone(), nest_fields));
+    }
+
+    Ok((fields, nested))
+}
+
 fn implement_struct_conversion(
     meta: ConversionMeta,
     named_struct: bool,
+    bindings: TokenStream2,
     fields: Vec<TokenStream2>,
 ) -> syn::Result<TokenStream2> {
     let ConversionMeta {
@@ -54,8 +293,86 @@ fn implement_struct_conversion(
         target_name,
         method,
         default_allowed,
-        validate,
-    } = meta;
+        from_map,
+        accumulate_errors,
+        reject_defaults,
+        target_tuple,
+        rename_all,
+        wrap_variant,
+        wrap_newtype,
+        expect_fields,
+        default_fields: defaulted_fields,
+        ..
+    } = meta.clone();
+
+    if meta.builder.is_some()
+        && (default_allowed
+            || !defaulted_fields.is_empty()
+            || wrap_variant.is_some()
+            || wrap_newtype
+            || expect_fields.is_some())
+    {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`builder` cannot be combined with `default`, `default_field`, `wrap_variant`, `wrap_newtype` or `expect_fields`, since fields left out are the builder's to fill",
+        ));
+    }
+
+    if !named_struct && !defaulted_fields.is_empty() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`default_field` is not supported for unnamed structs",
+        ));
+    }
+
+    // Only the listed fields need `Default`, unlike `..Default::default()`
+    let mut fields = fields;
+    fields.extend(defaulted_fields.iter().map(|field| {
+        quote! { #field: ::core::default::Default::default(), }
+    }));
+
+    // Each conversion initializes one target field
+    if let Some(expected) = expect_fields
+        && fields.len() != expected
+    {
+        return Err(syn::Error::new(
+            source_name.span(),
+            format!(
+                "Expected the conversion to {} to produce {} fields, but it produces {}. Check for fields that should be skipped or added",
+                quote!(#target_name),
+                expected,
+                fields.len()
+            ),
+        ));
+    }
+
+    if wrap_newtype && (default_allowed || target_tuple || wrap_variant.is_some()) {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`wrap_newtype` cannot be combined with `default`, `target_tuple` or `wrap_variant`",
+        ));
+    }
+
+    if meta.variant_names {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`variant_names` is only supported for enums",
+        ));
+    }
+
+    if rename_all.is_some() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`rename_all` is only supported for enums",
+        ));
+    }
+
+    if !meta.map_variants.is_empty() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`map_variants` is only supported for enums",
+        ));
+    }
 
     if !named_struct && default_allowed {
         return Err(syn::Error::new(
@@ -64,46 +381,116 @@ fn implement_struct_conversion(
         ));
     }
 
-    let default_fields = if default_allowed {
-        quote! { ..Default::default() }
+    if target_tuple && wrap_variant.is_some() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`target_tuple` cannot be combined with `wrap_variant`",
+        ));
+    }
+
+    // `..Default::default()` is struct update syntax, which enum variants don't have
+    if default_allowed && wrap_variant.is_some() {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`default` cannot be combined with `wrap_variant`, since enum variants don't support `..Default::default()`. Fill the variant's other fields with `default_field` instead",
+        ));
+    }
+
+    if named_struct && target_tuple {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`target_tuple` is only supported for tuple structs",
+        ));
+    }
+
+    if !named_struct && from_map {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`from_map` is not supported for unnamed structs",
+        ));
+    }
+
+    if from_map && meta.via_getters {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`via_getters` cannot be combined with `from_map`",
+        ));
+    }
+
+    if from_map && accumulate_errors {
+        return Err(syn::Error::new(
+            source_name.span(),
+            "`accumulate_errors` cannot be combined with `from_map`",
+        ));
+    }
+
+    // Map entries are taken out of the source one field at a time
+    let source_binding = if from_map {
+        quote! { let mut source = source; }
     } else {
         quote! {}
     };
 
-    let inner = if named_struct {
-        quote! { #target_name { #(#fields)* #default_fields } }
+    let default_fields = if default_allowed {
+        quote! { ..::core::default::Default::default() }
     } else {
-        quote! { #target_name(#(#fields)* #default_fields) }
+        quote! {}
     };
 
-    let error_type = if cfg!(feature = "anyhow") {
-        quote! { anyhow::Error }
+    // A type alias to a tuple can't be called like a tuple struct constructor
+    let inner = if let Some(builder) = &meta.builder {
+        quote! { <#builder as ::core::default::Default>::default() #(#fields)* }
+    } else if target_tuple && !method.is_from() {
+        quote! { (#(#fields)*) }
     } else {
-        quote! { String }
+        let constructor = meta.constructor();
+        let constructor = match &wrap_variant {
+            Some(variant) => quote! { #constructor::#variant },
+            None => constructor,
+        };
+        if named_struct {
+            quote! { #constructor { #(#fields)* #default_fields } }
+        } else {
+            quote! { #constructor(#(#fields)* #default_fields) }
+        }
     };
 
-    let validate_call = validate.map(|func| quote! {
-        #func(&source).map_err(|e| format!("Failed trying to convert {} to {}: {}",
-            stringify!(#source_name), stringify!(#target_name), e))?;
-    });
+    let error_creator = error_creator();
 
-    Ok(if method.is_falliable() {
+    let validate_call = validate_call(&meta);
+
+    let default_checks = reject_defaults.iter().map(|field| {
         quote! {
-            impl TryFrom<#source_name> for #target_name {
-                type Error = #error_type;
-                fn try_from(source: #source_name) -> Result<#target_name, Self::Error> {
-                    #validate_call
-                    Ok(#inner)
+            {
+                fn is_default<T: ::core::default::Default + ::core::cmp::PartialEq>(value: &T) -> bool {
+                    *value == T::default()
                 }
-            }
-        }
-    } else {
-        quote! {
-            impl From<#source_name> for #target_name {
-                fn from(source: #source_name) -> #target_name {
-                    #inner
+                if is_default(&source.#field) {
+                    return ::core::result::Result::Err(#error_creator("Failed trying to convert {} to {}: {} must not be the default value",
+                        ::core::stringify!(#source_name), ::core::stringify!(#target_name), ::core::stringify!(#field)).into());
                 }
             }
         }
+    });
+
+    Ok(if method.is_falliable() {
+        conversion_impl(
+            &meta,
+            quote! {
+                #validate_call
+                #(#default_checks)*
+                #source_binding
+                #bindings
+                ::core::result::Result::Ok(#inner)
+            },
+        )
+    } else {
+        conversion_impl(
+            &meta,
+            quote! {
+                #bindings
+                #inner
+            },
+        )
     })
 }
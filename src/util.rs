@@ -2,44 +2,215 @@ pub(super) fn is_surrounding_type(ty: &syn::Type, surrounding_type: &'static str
     extract_inner_type(ty, surrounding_type).is_some()
 }
 
+/// The last segment of a type's path, so that a fully-qualified type like
+/// `std::option::Option<T>` is recognized as an `Option<T>`.
+fn last_path_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last(),
+        _ => None,
+    }
+}
+
 pub(crate) fn extract_inner_type<'a>(
     ty: &'a syn::Type,
     surrounding_type: &str,
 ) -> Option<&'a syn::Type> {
-    if let syn::Type::Path(type_path) = ty {
-        if type_path.path.segments.len() == 1 {
-            let segment = &type_path.path.segments[0];
-            if segment.ident == surrounding_type {
-                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
-                    if let Some(syn::GenericArgument::Type(inner_ty)) = args.args.first() {
-                        return Some(inner_ty);
-                    }
-                }
+    if let Some(segment) = last_path_segment(ty)
+        && segment.ident == surrounding_type
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner_ty)) = args.args.first()
+    {
+        return Some(inner_ty);
+    }
+    None
+}
+
+/// The element type of a `heapless::Vec<T, N>`, told apart from a std `Vec` by its
+/// `heapless::` path or its capacity argument, e.g. `Vec<T, 8>`.
+pub(crate) fn extract_heapless_vec_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    if let syn::Type::Path(type_path) = ty
+        && let Some(segment) = last_path_segment(ty)
+        && segment.ident == "Vec"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner_ty)) = args.args.first()
+    {
+        let heapless_path = type_path.path.segments.len() > 1
+            && type_path
+                .path
+                .segments
+                .iter()
+                .any(|segment| segment.ident == "heapless");
+        let capacity = matches!(args.args.get(1), Some(syn::GenericArgument::Const(_)));
+        if heapless_path || capacity {
+            return Some(inner_ty);
+        }
+    }
+    None
+}
+
+/// The borrowed type of a `Cow`, `T` in `Cow<'a, T>`, skipping its lifetime.
+pub(crate) fn extract_cow_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    if let Some(segment) = last_path_segment(ty)
+        && segment.ident == "Cow"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+    {
+        return args.args.iter().find_map(|arg| match arg {
+            syn::GenericArgument::Type(inner_ty) => Some(inner_ty),
+            _ => None,
+        });
+    }
+    None
+}
+
+/// The element type of a borrowed slice, `T` in `&[T]` or `&'a [T]`.
+pub(crate) fn extract_slice_element(ty: &syn::Type) -> Option<&syn::Type> {
+    if let syn::Type::Reference(reference) = ty
+        && let syn::Type::Slice(slice) = &*reference.elem
+    {
+        return Some(&slice.elem);
+    }
+    None
+}
+
+/// The two type arguments of a type named `name`, e.g. `K` and `V` in `HashMap<K, V>`
+/// or `T` and `E` in `Result<T, E>`.
+pub(crate) fn extract_inner_type_pair<'a>(
+    ty: &'a syn::Type,
+    name: &str,
+) -> Option<(&'a syn::Type, &'a syn::Type)> {
+    if let Some(segment) = last_path_segment(ty)
+        && segment.ident == name
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+    {
+        let mut types = args.args.iter().filter_map(|arg| {
+            if let syn::GenericArgument::Type(ty) = arg {
+                Some(ty)
+            } else {
+                None
             }
+        });
+        if let (Some(key_ty), Some(val_ty)) = (types.next(), types.next()) {
+            return Some((key_ty, val_ty));
         }
     }
     None
 }
 
-pub(crate) fn extract_hashmap_inner_types(ty: &syn::Type) -> Option<(&syn::Type, &syn::Type)> {
-    if let syn::Type::Path(type_path) = ty {
-        if type_path.path.segments.len() == 1 {
-            let segment = &type_path.path.segments[0];
-            if segment.ident == "HashMap" {
-                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
-                    let mut types = args.args.iter().filter_map(|arg| {
-                        if let syn::GenericArgument::Type(ty) = arg {
-                            Some(ty)
-                        } else {
-                            None
-                        }
-                    });
-                    if let (Some(key_ty), Some(val_ty)) = (types.next(), types.next()) {
-                        return Some((key_ty, val_ty));
+/// A case convention for `rename_all`, spelled like the serde equivalents.
+#[derive(Clone, Copy, Debug, darling::FromMeta)]
+pub(crate) enum RenameRule {
+    #[darling(rename = "lowercase")]
+    Lower,
+    #[darling(rename = "UPPERCASE")]
+    Upper,
+    #[darling(rename = "PascalCase")]
+    Pascal,
+    #[darling(rename = "camelCase")]
+    Camel,
+    #[darling(rename = "snake_case")]
+    Snake,
+    #[darling(rename = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnake,
+}
+
+impl RenameRule {
+    pub(crate) fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+        match self {
+            RenameRule::Lower => words.concat().to_lowercase(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            RenameRule::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
                     }
-                }
+                })
+                .collect(),
+            RenameRule::Snake => words.join("_").to_lowercase(),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+        }
+    }
+}
+
+/// Splits an identifier into words at underscores and case boundaries,
+/// keeping acronyms together: `HTTPServer_v2` becomes `HTTP`, `Server`, `v2`.
+fn split_words(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in name.split('_').filter(|part| !part.is_empty()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let (index, current) = chars[i];
+            let previous = chars[i - 1].1;
+            let next_is_lower = chars.get(i + 1).is_some_and(|(_, c)| c.is_lowercase());
+            if current.is_uppercase() && (!previous.is_uppercase() || next_is_lower) {
+                words.push(&part[start..index]);
+                start = index;
             }
         }
+        words.push(&part[start..]);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+/// Parses the value of `with_func`-like attributes. The function is called by
+/// name in the generated code, so anything but a path, e.g. a closure, is rejected
+/// with an error on the value itself.
+pub(crate) fn parse_func_path(meta: &syn::Meta) -> darling::Result<Option<syn::Path>> {
+    <syn::Path as darling::FromMeta>::from_meta(meta).map(Some).map_err(|_| {
+        let name = meta.path().get_ident().map(ToString::to_string).unwrap_or_default();
+        let error = darling::Error::custom(format!(
+            "`{}` expects a path to a function in scope, like `{} = \"parse_name\"`. Move closures and other expressions into a function, they are not supported",
+            name, name
+        ));
+        match meta {
+            syn::Meta::NameValue(name_value) => error.with_span(&name_value.value),
+            _ => error.with_span(meta),
+        }
+    })
+}
+
+/// Parses the value of `error_variant`, the path of a tuple variant of the error
+/// type, rejecting anything else with an error on the value itself.
+pub(crate) fn parse_variant_path(meta: &syn::Meta) -> darling::Result<Option<syn::Path>> {
+    <syn::Path as darling::FromMeta>::from_meta(meta).map(Some).map_err(|_| {
+        let error = darling::Error::custom(
+            "`error_variant` expects a path to a variant of your error type, like `error_variant = \"MyError::BadName\"`",
+        );
+        match meta {
+            syn::Meta::NameValue(name_value) => error.with_span(&name_value.value),
+            _ => error.with_span(meta),
+        }
+    })
+}
+
+/// An identifier for a name given as a string, e.g. by `rename`. Keywords become
+/// raw identifiers, so `"type"` names the field `r#type`, and an explicit `"r#type"`
+/// is accepted too.
+pub(crate) fn ident_from_name(name: &str, span: proc_macro2::Span) -> syn::Ident {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    // `self`, `Self`, `super` and `crate` can't be raw identifiers
+    if syn::parse_str::<syn::Ident>(name).is_ok()
+        || matches!(name, "self" | "Self" | "super" | "crate")
+    {
+        syn::Ident::new(name, span)
+    } else {
+        syn::Ident::new_raw(name, span)
     }
-    None
 }
@@ -0,0 +1,13 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(into(path = "Record", accumulate_errors))]
+struct Account {
+    id: u32,
+}
+
+struct Record {
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(into(path = "Settings"))]
+struct LegacySettings {
+    #[convert(skip)]
+    palette: Vec<u32>,
+    #[convert(skip)]
+    zoom: f32,
+}
+
+#[derive(Default)]
+struct Settings {
+    theme: String,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use derive_into::Convert;
+
+struct Span {
+    start: u32,
+    end: u32,
+}
+
+fn range_to_span(range: Range) -> Span {
+    Span {
+        start: range.start,
+        end: range.end,
+    }
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Span", body = "range_to_span", expect_fields = 2))]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use derive_into::Convert;
+
+struct AccountBuilder;
+
+struct Account {
+    id: u64,
+}
+
+#[derive(Convert)]
+#[convert(from(path = "Account", builder = "AccountBuilder"))]
+struct Draft {
+    id: u64,
+}
+
+fn main() {}
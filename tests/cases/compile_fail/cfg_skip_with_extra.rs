@@ -0,0 +1,17 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(into(path = "Document"))]
+struct Draft {
+    title: String,
+    #[convert(extra = "extra", cfg_skip = "feature = \"legacy\"")]
+    author: String,
+}
+
+struct Document {
+    title: String,
+    author: String,
+    extra: Draft,
+}
+
+fn main() {}
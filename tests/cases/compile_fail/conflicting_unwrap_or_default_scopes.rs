@@ -0,0 +1,14 @@
+use derive_into::Convert;
+
+struct Target {
+    value: u8,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Target"))]
+struct Source {
+    #[convert(unwrap_or_default, into(path = "Target", unwrap))]
+    value: Option<u8>,
+}
+
+fn main() {}
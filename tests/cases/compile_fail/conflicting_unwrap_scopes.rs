@@ -0,0 +1,14 @@
+use derive_into::Convert;
+
+struct Target {
+    value: u8,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Target"))]
+struct Source {
+    #[convert(unwrap, into(unwrap_or_default))]
+    value: Option<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use derive_into::Convert;
+
+struct Target {
+    id: u8,
+}
+
+#[derive(Convert)]
+#[convert(try_into(path = "Target", const_fn = "to_target"))]
+struct Source {
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use derive_into::Convert;
+
+struct Target {
+    id: u64,
+    name: u8,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Target", const_fn = "to_target"))]
+struct Source {
+    id: u32,
+    #[convert(identity)]
+    name: u8,
+}
+
+fn main() {}
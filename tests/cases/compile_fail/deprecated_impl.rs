@@ -0,0 +1,13 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(into(path = "Target", deprecated = "Use TargetV2 instead"))]
+struct Source {
+    id: u32,
+}
+
+struct Target {
+    id: u64,
+}
+
+fn main() {}
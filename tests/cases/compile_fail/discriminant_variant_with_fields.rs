@@ -0,0 +1,10 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(try_from(path = "u8"))]
+enum Shape {
+    Empty,
+    Circle { radius: u8 },
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use derive_into::Convert;
+
+#[derive(Debug)]
+enum SignupError {
+    BadAge(String),
+}
+
+#[derive(Convert)]
+#[convert(try_from(path = "Form"))]
+struct Signup {
+    #[convert(error_variant = "|error| SignupError::BadAge(error)")]
+    age: u8,
+}
+
+struct Form {
+    age: i64,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(into(path = "Target", expect_fields = 3))]
+struct Source {
+    id: u32,
+    #[convert(skip)]
+    name: String,
+}
+
+struct Target {
+    id: u32,
+    name: String,
+    email: String,
+}
+
+fn main() {}
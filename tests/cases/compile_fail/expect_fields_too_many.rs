@@ -0,0 +1,17 @@
+use derive_into::Convert;
+
+// `cache` was meant to be skipped
+#[derive(Convert)]
+#[convert(into(path = "Target", expect_fields = 2))]
+struct Source {
+    id: u32,
+    name: String,
+    cache: Vec<u8>,
+}
+
+struct Target {
+    id: u32,
+    name: String,
+}
+
+fn main() {}
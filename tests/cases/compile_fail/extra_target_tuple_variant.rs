@@ -0,0 +1,19 @@
+use derive_into::Convert;
+use std::collections::HashMap;
+
+#[derive(Convert)]
+#[convert(into(path = "Event"))]
+enum RawEvent {
+    #[convert(target_tuple)]
+    Click {
+        x: u32,
+        #[convert(extra = "extra")]
+        label: String,
+    },
+}
+
+enum Event {
+    Click(u32, HashMap<String, String>),
+}
+
+fn main() {}
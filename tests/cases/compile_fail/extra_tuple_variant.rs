@@ -0,0 +1,14 @@
+use derive_into::Convert;
+use std::collections::HashMap;
+
+#[derive(Convert)]
+#[convert(into(path = "Event"))]
+enum RawEvent {
+    Click(u32, #[convert(extra = "extra")] String),
+}
+
+enum Event {
+    Click(u32, HashMap<String, String>),
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use derive_into::Convert;
+
+enum ApiPriority {
+    Level(i64),
+}
+
+#[derive(Convert)]
+#[convert(try_from(path = "ApiPriority"))]
+enum Priority {
+    Level(u8),
+}
+
+struct ApiTicket {
+    priority: ApiPriority,
+}
+
+// `Priority` only converts fallibly, so the ticket's conversion must be `try_from`
+#[derive(Convert)]
+#[convert(from(path = "ApiTicket"))]
+struct Ticket {
+    priority: Priority,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use arrayvec::ArrayVec;
+use derive_into::Convert;
+
+struct Raw {
+    readings: Vec<i32>,
+}
+
+#[derive(Convert)]
+#[convert(from(path = "Raw"))]
+struct Sample {
+    readings: ArrayVec<i32, 4>,
+}
+
+fn main() {}
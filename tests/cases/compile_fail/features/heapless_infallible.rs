@@ -0,0 +1,13 @@
+use derive_into::Convert;
+
+struct Raw {
+    temperatures: Vec<i16>,
+}
+
+#[derive(Convert)]
+#[convert(from(path = "Raw"))]
+struct Report {
+    temperatures: heapless::Vec<i16, 4>,
+}
+
+fn main() {}
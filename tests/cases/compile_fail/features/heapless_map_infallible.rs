@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+use derive_into::Convert;
+use heapless::FnvIndexMap;
+
+struct Raw {
+    limits: HashMap<String, i16>,
+}
+
+#[derive(Convert)]
+#[convert(from(path = "Raw"))]
+struct Report {
+    limits: FnvIndexMap<String, i16, 2>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(from(path = "Account", from_arc, by_ref))]
+struct Signup {
+    email: String,
+}
+
+struct Account {
+    email: String,
+}
+
+fn main() {}
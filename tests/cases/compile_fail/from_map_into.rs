@@ -0,0 +1,13 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(into(path = "Record", from_map))]
+struct Account {
+    id: u32,
+}
+
+struct Record {
+    id: u32,
+}
+
+fn main() {}
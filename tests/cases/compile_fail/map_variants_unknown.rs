@@ -0,0 +1,13 @@
+use derive_into::Convert;
+
+enum Target {
+    Crimson,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Target", map_variants(Rde = "Crimson")))]
+enum Source {
+    Red,
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+use derive_into::Convert;
+
+struct Meters(f64);
+
+#[derive(Convert)]
+#[convert(into(path = "Route"))]
+struct RawRoute {
+    name: String,
+    length: u32,
+    stops: Vec<String>,
+}
+
+struct Route {
+    name: String,
+    length: Meters,
+    stops: Vec<String>,
+}
+
+#[derive(Convert)]
+#[convert(try_into(path = "Leg"))]
+struct RawLeg {
+    from: String,
+    distance: Option<u32>,
+}
+
+struct Leg {
+    from: String,
+    distance: Option<Meters>,
+}
+
+fn main() {}
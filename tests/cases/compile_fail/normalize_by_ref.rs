@@ -0,0 +1,17 @@
+use derive_into::Convert;
+
+fn normalize_signup(signup: &mut Signup) {
+    signup.email = signup.email.trim().to_lowercase();
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Account", by_ref, normalize = "normalize_signup"))]
+struct Signup {
+    email: String,
+}
+
+struct Account {
+    email: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(into(path = "Target", option_impl))]
+struct Source {
+    id: u32,
+}
+
+struct Target {
+    id: u64,
+}
+
+fn main() {}
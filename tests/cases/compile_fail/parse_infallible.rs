@@ -0,0 +1,14 @@
+use derive_into::Convert;
+
+struct Raw {
+    port: String,
+}
+
+#[derive(Convert)]
+#[convert(from(path = "Raw"))]
+struct Config {
+    #[convert(parse)]
+    port: u16,
+}
+
+fn main() {}
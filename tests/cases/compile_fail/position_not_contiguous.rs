@@ -0,0 +1,14 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(into(path = "Pair"))]
+struct Named {
+    #[convert(position = 0)]
+    first: u32,
+    #[convert(position = 2)]
+    second: u32,
+}
+
+struct Pair(u32, u32);
+
+fn main() {}
@@ -0,0 +1,13 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(from(path = "Record", ref_method = "to_record"))]
+struct Account {
+    id: u32,
+}
+
+struct Record {
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use derive_into::Convert;
+
+// `note` is only annotated for the conversion into `Summary`, which doesn't
+// count for the one into `AuditRecord`
+#[derive(Convert)]
+#[convert(into(path = "AuditRecord", require_explicit))]
+#[convert(into(path = "Summary"))]
+struct Entry {
+    #[convert(auto)]
+    id: u32,
+    #[convert(into(path = "Summary", skip))]
+    note: String,
+}
+
+struct AuditRecord {
+    id: u32,
+    note: String,
+}
+
+#[derive(Default)]
+struct Summary {
+    id: u32,
+}
+
+fn main() {}
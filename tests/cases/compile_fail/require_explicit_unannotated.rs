@@ -0,0 +1,16 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(into(path = "AuditRecord", require_explicit))]
+struct Entry {
+    #[convert(auto)]
+    id: u32,
+    note: String,
+}
+
+struct AuditRecord {
+    id: u32,
+    note: String,
+}
+
+fn main() {}
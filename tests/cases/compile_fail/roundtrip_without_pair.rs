@@ -0,0 +1,14 @@
+use derive_into::Convert;
+
+#[derive(Convert, Clone, Debug, PartialEq)]
+#[convert(roundtrip_test)]
+#[convert(into(path = "UserDto"))]
+struct User {
+    id: u32,
+}
+
+struct UserDto {
+    id: u32,
+}
+
+fn main() {}
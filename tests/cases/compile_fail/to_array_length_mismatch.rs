@@ -0,0 +1,14 @@
+use derive_into::Convert;
+
+struct RawKey {
+    bytes: Vec<u8>,
+}
+
+#[derive(Convert)]
+#[convert(try_from(path = "RawKey"))]
+struct Key {
+    #[convert(to_array = 16)]
+    bytes: [u8; 32],
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use derive_into::Convert;
+
+union Target {
+    int: u32,
+    float: f32,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Target"))]
+union Source {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
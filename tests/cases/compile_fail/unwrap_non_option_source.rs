@@ -0,0 +1,15 @@
+use derive_into::Convert;
+
+struct Source {
+    value: u8,
+}
+
+// `unwrap` in the `from` direction expects the source's field to be an `Option`
+#[derive(Convert)]
+#[convert(from(path = "Source"))]
+struct Target {
+    #[convert(unwrap)]
+    value: u8,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(into(path = "Target"))]
+struct Source {
+    #[convert(with_func = "|source| source.name.len()")]
+    name: String,
+}
+
+struct Target {
+    name: usize,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use derive_into::Convert;
+
+fn parse(_source: &Source) -> Option<u32> {
+    None
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Target"))]
+struct Source {
+    #[convert(with_func_opt = "parse")]
+    value: String,
+}
+
+struct Target {
+    value: u32,
+}
+
+fn main() {}
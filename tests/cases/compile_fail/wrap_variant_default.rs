@@ -0,0 +1,19 @@
+use derive_into::Convert;
+
+#[derive(Convert)]
+#[convert(into(path = "Event", wrap_variant = "Transitioned", default))]
+struct Transition {
+    reason: String,
+}
+
+#[derive(Default)]
+enum Event {
+    #[default]
+    Idle,
+    Transitioned {
+        reason: String,
+        retries: u32,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,88 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Age(u8);
+
+impl TryFrom<i32> for Age {
+    type Error = String;
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        u8::try_from(value)
+            .map(Age)
+            .map_err(|_| format!("{} is not a valid age", value))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Email(String);
+
+impl TryFrom<String> for Email {
+    type Error = String;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.contains('@') {
+            Ok(Email(value))
+        } else {
+            Err(format!("{} is not a valid email", value))
+        }
+    }
+}
+
+struct Form {
+    name: String,
+    age: i32,
+    email: String,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "Form", accumulate_errors))]
+struct User {
+    name: String,
+    age: Age,
+    email: Email,
+}
+
+#[derive(Convert)]
+#[convert(try_into(path = "Pair", accumulate_errors))]
+struct RawPair(i32, i32);
+
+#[derive(Debug, PartialEq)]
+struct Pair(Age, Age);
+
+fn main() {
+    let user: User = Form {
+        name: "Alice".to_string(),
+        age: 30,
+        email: "alice@example.com".to_string(),
+    }
+    .try_into()
+    .unwrap();
+    assert_eq!(
+        user,
+        User {
+            name: "Alice".to_string(),
+            age: Age(30),
+            email: Email("alice@example.com".to_string()),
+        }
+    );
+
+    // Both failing fields are reported
+    let result = User::try_from(Form {
+        name: "Bob".to_string(),
+        age: -1,
+        email: "bob".to_string(),
+    });
+    assert_eq!(
+        result.unwrap_err(),
+        "Failed trying to convert source.age to User: \"-1 is not a valid age\"; \
+         Failed trying to convert source.email to User: \"bob is not a valid email\""
+    );
+
+    let pair: Pair = RawPair(1, 2).try_into().unwrap();
+    assert_eq!(pair, Pair(Age(1), Age(2)));
+
+    let result: Result<Pair, _> = RawPair(300, -5).try_into();
+    assert_eq!(
+        result.unwrap_err(),
+        "Failed trying to convert source.0 to Pair: \"300 is not a valid age\"; \
+         Failed trying to convert source.1 to Pair: \"-5 is not a valid age\""
+    );
+}
@@ -0,0 +1,40 @@
+use derive_into::Convert;
+
+#[derive(Debug, Default, PartialEq)]
+struct Settings {
+    theme: String,
+    font_size: u8,
+}
+
+// Nothing is carried over, so the target is built entirely from its `Default`
+#[derive(Convert)]
+#[convert(into(path = "Settings", default))]
+struct LegacySettings {
+    #[convert(skip)]
+    palette: Vec<u32>,
+    #[convert(skip)]
+    zoom: f32,
+}
+
+struct Snapshot {
+    taken_at: u64,
+}
+
+#[derive(Convert, Debug, Default, PartialEq)]
+#[convert(try_from(path = "Snapshot", default))]
+struct Cache {
+    #[convert(skip)]
+    entries: Vec<String>,
+}
+
+fn main() {
+    let settings: Settings = LegacySettings {
+        palette: vec![0xffffff],
+        zoom: 1.5,
+    }
+    .into();
+    assert_eq!(settings, Settings::default());
+
+    let cache = Cache::try_from(Snapshot { taken_at: 10 }).unwrap();
+    assert_eq!(cache, Cache::default());
+}
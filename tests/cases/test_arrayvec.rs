@@ -0,0 +1,59 @@
+use arrayvec::ArrayVec;
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Reading(i64);
+
+impl From<i32> for Reading {
+    fn from(value: i32) -> Self {
+        Reading(value.into())
+    }
+}
+
+impl TryFrom<Reading> for i32 {
+    type Error = String;
+    fn try_from(reading: Reading) -> Result<Self, Self::Error> {
+        reading.0.try_into().map_err(|_| "reading out of range".to_string())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiSample {
+    readings: Vec<Reading>,
+}
+
+// An `ArrayVec` is converted element by element. Filling one in a fallible
+// conversion fails once it's full, instead of panicking
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiSample"))]
+#[convert(try_from(path = "ApiSample"))]
+struct Sample {
+    readings: ArrayVec<i32, 3>,
+}
+
+fn main() {
+    let mut readings = ArrayVec::new();
+    readings.extend([1, 2]);
+    let api: ApiSample = Sample { readings }.into();
+    assert_eq!(api.readings, vec![Reading(1), Reading(2)]);
+
+    let sample = Sample::try_from(api).unwrap();
+    assert_eq!(sample.readings.as_slice(), &[1, 2]);
+
+    let result = Sample::try_from(ApiSample {
+        readings: (0..4).map(Reading).collect(),
+    });
+    assert!(
+        result
+            .unwrap_err()
+            .contains("more elements than the capacity of 3")
+    );
+
+    assert!(
+        Sample::try_from(ApiSample {
+            readings: vec![Reading(i64::MAX)],
+        })
+        .is_err()
+    );
+}
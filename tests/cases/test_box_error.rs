@@ -0,0 +1,82 @@
+// Only built with the `box-error` feature
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+impl TryFrom<u32> for Port {
+    type Error = String;
+    fn try_from(n: u32) -> Result<Self, Self::Error> {
+        u16::try_from(n)
+            .map(Port)
+            .map_err(|_| format!("{} is not a valid port", n))
+    }
+}
+
+fn check_host(source: &RawConfig) -> Result<(), String> {
+    if source.host.is_empty() {
+        return Err("host must not be empty".to_string());
+    }
+    Ok(())
+}
+
+#[derive(Convert, Debug)]
+#[convert(try_into(path = "Config", validate = "check_host"))]
+#[convert(try_into(path = "CheckedConfig", accumulate_errors, reject_defaults("host")))]
+struct RawConfig {
+    host: String,
+    port: u32,
+    #[convert(unwrap)]
+    admin_port: Option<u32>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Config {
+    host: String,
+    port: Port,
+    admin_port: Port,
+}
+
+#[derive(Debug, PartialEq)]
+struct CheckedConfig {
+    host: String,
+    port: Port,
+    admin_port: Port,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "RawLevel"))]
+enum Level {
+    Low(Port),
+}
+
+enum RawLevel {
+    Low(u32),
+}
+
+fn raw(host: &str, port: u32, admin_port: Option<u32>) -> RawConfig {
+    RawConfig {
+        host: host.to_string(),
+        port,
+        admin_port,
+    }
+}
+
+fn main() {
+    let config = Config::try_from(raw("localhost", 80, Some(8080))).unwrap();
+    assert_eq!(config.port, Port(80));
+
+    let error: Box<dyn std::error::Error + Send + Sync> =
+        Config::try_from(raw("localhost", 70000, Some(1))).unwrap_err();
+    assert!(error.to_string().contains("70000 is not a valid port"));
+    assert!(Config::try_from(raw("", 80, Some(1))).is_err());
+    assert!(Config::try_from(raw("localhost", 80, None)).is_err());
+
+    let error = CheckedConfig::try_from(raw("localhost", 70000, Some(70001))).unwrap_err();
+    assert!(error.to_string().contains("70000"));
+    assert!(error.to_string().contains("70001"));
+    assert!(CheckedConfig::try_from(raw("", 1, Some(1))).is_err());
+
+    assert_eq!(Level::try_from(RawLevel::Low(1)).unwrap(), Level::Low(Port(1)));
+    assert!(Level::try_from(RawLevel::Low(1 << 20)).is_err());
+}
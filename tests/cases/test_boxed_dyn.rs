@@ -0,0 +1,115 @@
+use derive_into::Convert;
+
+trait Handler {
+    fn handle(&self, input: &str) -> String;
+}
+
+#[derive(Debug)]
+struct Upper;
+
+impl Handler for Upper {
+    fn handle(&self, input: &str) -> String {
+        input.to_uppercase()
+    }
+}
+
+struct Prefix(String);
+
+impl Handler for Prefix {
+    fn handle(&self, input: &str) -> String {
+        format!("{}{}", self.0, input)
+    }
+}
+
+struct Route {
+    path: String,
+    handler: Box<dyn Handler>,
+}
+
+struct SharedRoute {
+    path: String,
+    handler: Box<dyn Handler + Send + Sync>,
+    retries: u8,
+}
+
+// A concrete handler is erased into the target's trait object
+#[derive(Convert, Debug)]
+#[convert(into(path = "Route"))]
+#[convert(try_into(path = "SharedRoute"))]
+struct UpperRoute {
+    path: String,
+    #[convert(into(boxed_dyn = "Handler"), try_into(boxed_dyn = "Handler + Send + Sync"))]
+    handler: Upper,
+    #[convert(into(skip))]
+    retries: i32,
+}
+
+struct PrefixConfig {
+    path: String,
+    handler: Prefix,
+}
+
+#[derive(Convert)]
+#[convert(from(path = "PrefixConfig"))]
+struct Endpoint {
+    path: String,
+    #[convert(boxed_dyn = "Handler")]
+    handler: Box<dyn Handler>,
+}
+
+enum Middleware {
+    Transform(Box<dyn Handler>),
+    Passthrough,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Middleware"))]
+enum UpperMiddleware {
+    Transform(#[convert(boxed_dyn = "Handler")] Upper),
+    Passthrough,
+}
+
+fn main() {
+    let route: Route = UpperRoute {
+        path: "/shout".to_string(),
+        handler: Upper,
+        retries: 1,
+    }
+    .into();
+    assert_eq!(route.path, "/shout");
+    assert_eq!(route.handler.handle("hi"), "HI");
+
+    let shared: SharedRoute = UpperRoute {
+        path: "/shared".to_string(),
+        handler: Upper,
+        retries: 3,
+    }
+    .try_into()
+    .unwrap();
+    assert_eq!(shared.handler.handle("ok"), "OK");
+    assert_eq!(shared.retries, 3);
+    let too_many: Result<SharedRoute, _> = UpperRoute {
+        path: "/shared".to_string(),
+        handler: Upper,
+        retries: 300,
+    }
+    .try_into();
+    assert!(too_many.is_err());
+
+    let endpoint: Endpoint = PrefixConfig {
+        path: "/greet".to_string(),
+        handler: Prefix("hello ".to_string()),
+    }
+    .into();
+    assert_eq!(endpoint.path, "/greet");
+    assert_eq!(endpoint.handler.handle("world"), "hello world");
+
+    match Middleware::from(UpperMiddleware::Transform(Upper)) {
+        Middleware::Transform(handler) => assert_eq!(handler.handle("a"), "A"),
+        Middleware::Passthrough => panic!("expected a transform"),
+    }
+    assert!(matches!(
+        Middleware::from(UpperMiddleware::Passthrough),
+        Middleware::Passthrough
+    ));
+}
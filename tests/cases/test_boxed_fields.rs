@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct ApiNode {
+    value: i64,
+    left: Option<Box<ApiNode>>,
+    right: Option<Box<ApiNode>>,
+}
+
+// With `unbox`, a recursive tree converts through its boxed children
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiNode"))]
+#[convert(try_from(path = "ApiNode"))]
+struct Node {
+    value: u8,
+    #[convert(unbox)]
+    left: Option<Box<Node>>,
+    #[convert(unbox)]
+    right: Option<Box<Node>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiDoc {
+    root: Box<ApiNode>,
+    title: Box<str>,
+    extra: Vec<Box<ApiNode>>,
+}
+
+// `Box<str>` is converted as a whole, like before
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiDoc"))]
+struct Doc {
+    #[convert(unbox)]
+    root: Box<Node>,
+    #[convert(unbox)]
+    title: Box<str>,
+    #[convert(unbox)]
+    extra: Vec<Box<Node>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Big {
+    payload: [u8; 16],
+}
+
+struct Unboxed {
+    big: Big,
+}
+
+#[derive(Debug)]
+struct Shared {
+    big: Arc<Big>,
+}
+
+// Without `unbox`, a box converts as a whole through the std impls, e.g. boxing a
+// value or turning a `Box` into an `Arc`
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "Unboxed"))]
+#[convert(into(path = "Shared"))]
+struct Boxed {
+    big: Box<Big>,
+}
+
+fn leaf(value: u8) -> Option<Box<Node>> {
+    Some(Box::new(Node {
+        value,
+        left: None,
+        right: None,
+    }))
+}
+
+fn api_leaf(value: i64) -> Option<Box<ApiNode>> {
+    Some(Box::new(ApiNode {
+        value,
+        left: None,
+        right: None,
+    }))
+}
+
+fn main() {
+    let tree = Node {
+        value: 1,
+        left: leaf(2),
+        right: Some(Box::new(Node {
+            value: 3,
+            left: leaf(4),
+            right: None,
+        })),
+    };
+    let api: ApiNode = tree.into();
+    assert_eq!(
+        api,
+        ApiNode {
+            value: 1,
+            left: api_leaf(2),
+            right: Some(Box::new(ApiNode {
+                value: 3,
+                left: api_leaf(4),
+                right: None,
+            })),
+        }
+    );
+
+    let back = Node::try_from(api).unwrap();
+    assert_eq!(back.right.unwrap().left, leaf(4));
+
+    // A value deep in the tree that doesn't fit fails the whole conversion
+    let invalid = ApiNode {
+        value: 1,
+        left: Some(Box::new(ApiNode {
+            value: 2,
+            left: api_leaf(-1),
+            right: None,
+        })),
+        right: None,
+    };
+    assert!(Node::try_from(invalid).is_err());
+
+    let doc: ApiDoc = Doc {
+        root: leaf(5).unwrap(),
+        title: "notes".into(),
+        extra: vec![leaf(6).unwrap()],
+    }
+    .into();
+    assert_eq!(doc.root.value, 5);
+    assert_eq!(&*doc.title, "notes");
+    assert_eq!(doc.extra[0].value, 6);
+
+    let boxed = Boxed::from(Unboxed {
+        big: Big { payload: [1; 16] },
+    });
+    assert_eq!(boxed.big.payload, [1; 16]);
+    let shared: Shared = boxed.into();
+    assert_eq!(shared.big.payload, [1; 16]);
+}
@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+
+use derive_into::Convert;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Key(String);
+
+impl From<String> for Key {
+    fn from(s: String) -> Self {
+        Key(s)
+    }
+}
+
+impl From<Key> for String {
+    fn from(key: Key) -> Self {
+        key.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Index {
+    counts: BTreeMap<Key, u64>,
+    groups: Option<BTreeMap<Key, Vec<u64>>>,
+    labels: BTreeMap<u8, String>,
+}
+
+// Ordered maps convert key/value-wise, also nested in other containers
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Index"))]
+#[convert(try_from(path = "Index"))]
+struct RawIndex {
+    counts: BTreeMap<String, u32>,
+    groups: Option<BTreeMap<String, Vec<u32>>>,
+    #[convert(into(map_keys(skip), map_values = "shout"))]
+    labels: BTreeMap<u8, String>,
+}
+
+fn shout(label: String) -> String {
+    label.to_uppercase()
+}
+
+fn main() {
+    let raw = RawIndex {
+        counts: [("a".to_string(), 1), ("b".to_string(), 2)].into(),
+        groups: Some([("odd".to_string(), vec![1, 3])].into()),
+        labels: [(1, "one".to_string())].into(),
+    };
+    let index: Index = raw.into();
+    assert_eq!(
+        index.counts.keys().collect::<Vec<_>>(),
+        vec![&Key("a".to_string()), &Key("b".to_string())]
+    );
+    assert_eq!(index.counts[&Key("b".to_string())], 2);
+    assert_eq!(
+        index.groups.as_ref().unwrap()[&Key("odd".to_string())],
+        vec![1, 3]
+    );
+    assert_eq!(index.labels[&1], "ONE");
+
+    // Back again, with values that must fit into a `u32`
+    let back = RawIndex::try_from(index).unwrap();
+    assert_eq!(back.counts["a"], 1);
+    assert_eq!(back.groups.unwrap()["odd"], vec![1, 3]);
+    assert_eq!(back.labels[&1], "ONE");
+
+    let too_big = Index {
+        counts: [(Key("big".to_string()), u64::MAX)].into(),
+        groups: None,
+        labels: BTreeMap::new(),
+    };
+    assert!(RawIndex::try_from(too_big).is_err());
+}
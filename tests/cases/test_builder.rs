@@ -0,0 +1,120 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Account {
+    name: String,
+    age: u8,
+    tags: Vec<String>,
+    plan: String,
+}
+
+// A hand-written builder with by-value setters, checked by `build`
+#[derive(Default)]
+struct AccountBuilder {
+    name: Option<String>,
+    age: Option<u8>,
+    tags: Vec<String>,
+    plan: Option<String>,
+}
+
+impl AccountBuilder {
+    fn name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    fn age(mut self, age: u8) -> Self {
+        self.age = Some(age);
+        self
+    }
+
+    fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    fn plan(mut self, plan: String) -> Self {
+        self.plan = Some(plan);
+        self
+    }
+
+    fn build(self) -> Result<Account, String> {
+        Ok(Account {
+            name: self.name.ok_or("missing name")?,
+            age: self.age.ok_or("missing age")?,
+            tags: self.tags,
+            plan: self.plan.ok_or("missing plan")?,
+        })
+    }
+}
+
+// The signup only knows some of the account's fields, the rest are up to the caller
+#[derive(Convert)]
+#[convert(into(path = "Account", builder = "AccountBuilder"))]
+struct Signup {
+    #[convert(rename = "name")]
+    username: String,
+    age: u8,
+    #[convert(skip)]
+    password: String,
+}
+
+#[derive(Convert)]
+#[convert(try_into(path = "Account", builder = "AccountBuilder"))]
+struct LegacySignup {
+    name: String,
+    age: i64,
+    tags: Vec<&'static str>,
+}
+
+fn main() {
+    let signup = Signup {
+        username: "ada".to_string(),
+        age: 36,
+        password: "secret".to_string(),
+    };
+    let account = signup
+        .into_partial()
+        .plan("free".to_string())
+        .build()
+        .unwrap();
+    assert_eq!(
+        account,
+        Account {
+            name: "ada".to_string(),
+            age: 36,
+            tags: Vec::new(),
+            plan: "free".to_string(),
+        }
+    );
+
+    // Fields the caller doesn't set are reported by the builder
+    let partial = Signup {
+        username: "bob".to_string(),
+        age: 20,
+        password: String::new(),
+    }
+    .into_partial();
+    assert_eq!(partial.build().unwrap_err(), "missing plan");
+
+    let legacy = LegacySignup {
+        name: "eve".to_string(),
+        age: 40,
+        tags: vec!["admin"],
+    };
+    let account = legacy
+        .into_partial()
+        .unwrap()
+        .plan("pro".to_string())
+        .build()
+        .unwrap();
+    assert_eq!(account.tags, vec!["admin".to_string()]);
+    assert_eq!(account.age, 40);
+
+    let too_old = LegacySignup {
+        name: "old".to_string(),
+        age: 300,
+        tags: Vec::new(),
+    };
+    assert!(too_old.into_partial().is_err());
+}
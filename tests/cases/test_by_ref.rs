@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Id(u32);
+
+impl From<u32> for Id {
+    fn from(n: u32) -> Self {
+        Id(n)
+    }
+}
+
+fn first_word(source: &Document) -> &str {
+    source.title.split_whitespace().next().unwrap_or_default()
+}
+
+fn checked_first_word(source: &Document) -> Result<&str, String> {
+    source
+        .title
+        .split_whitespace()
+        .next()
+        .ok_or_else(|| "empty title".to_string())
+}
+
+#[derive(Convert)]
+#[convert(into(path = "DocumentView<'a>", by_ref))]
+#[convert(try_into(path = "CheckedView<'a>", by_ref))]
+struct Document {
+    id: u32,
+    #[convert(into(with_func = "first_word", rename = "headline"))]
+    #[convert(try_into(with_func = "checked_first_word", rename = "headline"))]
+    title: String,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct DocumentView<'a> {
+    id: Id,
+    headline: &'a str,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct CheckedView<'a> {
+    id: Id,
+    headline: &'a str,
+    tags: Vec<String>,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "OwnedShape", by_ref))]
+enum Shape {
+    Circle(u32),
+    Square { side: u32 },
+}
+
+#[derive(Debug, PartialEq)]
+enum OwnedShape {
+    Circle(Id),
+    Square { side: Id },
+}
+
+// Collections are iterated by reference, only cloning their elements
+#[derive(Convert)]
+#[convert(into(path = "Index", by_ref))]
+#[convert(try_into(path = "SmallIndex", by_ref))]
+struct Catalog {
+    ids: Vec<u32>,
+    by_name: HashMap<String, u32>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Index {
+    ids: Vec<Id>,
+    by_name: HashMap<String, Id>,
+}
+
+#[derive(Debug, PartialEq)]
+struct SmallIndex {
+    ids: Vec<u8>,
+    by_name: HashMap<String, u8>,
+}
+
+fn main() {
+    let document = Document {
+        id: 1,
+        title: "Hello world".to_string(),
+        tags: vec!["greeting".to_string()],
+    };
+
+    let view: DocumentView = (&document).into();
+    assert_eq!(
+        view,
+        DocumentView {
+            id: Id(1),
+            headline: "Hello",
+            tags: vec!["greeting".to_string()],
+        }
+    );
+    // The view borrows from the document, which is still usable
+    assert!(document.title.starts_with(view.headline));
+
+    let checked = CheckedView::try_from(&document).unwrap();
+    assert_eq!(checked.headline, "Hello");
+
+    let untitled = Document {
+        id: 2,
+        title: String::new(),
+        tags: vec![],
+    };
+    assert!(CheckedView::try_from(&untitled).is_err());
+
+    let shape = Shape::Square { side: 3 };
+    let owned: OwnedShape = (&shape).into();
+    assert_eq!(owned, OwnedShape::Square { side: Id(3) });
+    assert_eq!(OwnedShape::from(&Shape::Circle(2)), OwnedShape::Circle(Id(2)));
+
+    let catalog = Catalog {
+        ids: vec![1, 2],
+        by_name: HashMap::from([("one".to_string(), 1)]),
+    };
+    let index: Index = (&catalog).into();
+    assert_eq!(
+        index,
+        Index {
+            ids: vec![Id(1), Id(2)],
+            by_name: HashMap::from([("one".to_string(), Id(1))]),
+        }
+    );
+    let small = SmallIndex::try_from(&catalog).unwrap();
+    assert_eq!(small.ids, vec![1, 2]);
+    assert_eq!(small.by_name["one"], 1);
+    // The catalog wasn't consumed
+    assert_eq!(catalog.ids.len(), 2);
+
+    let large = Catalog {
+        ids: vec![],
+        by_name: HashMap::from([("big".to_string(), 1000)]),
+    };
+    assert!(SmallIndex::try_from(&large).is_err());
+}
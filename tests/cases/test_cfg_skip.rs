@@ -0,0 +1,81 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq, Default)]
+struct Label(String);
+
+impl From<String> for Label {
+    fn from(s: String) -> Self {
+        Label(s)
+    }
+}
+
+// `all()` is always active and `any()` never is, so both branches are exercised
+#[derive(Convert, Clone)]
+#[convert(into(path = "Target"))]
+#[convert(try_into(path = "TryTarget"))]
+struct Source {
+    id: u32,
+    #[convert(cfg_skip = "all()")]
+    skipped: String,
+    #[convert(cfg_skip = "any()")]
+    kept: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Target {
+    id: u32,
+    skipped: Label,
+    kept: Label,
+}
+
+#[derive(Debug, PartialEq)]
+struct TryTarget {
+    id: u32,
+    skipped: Label,
+    kept: Label,
+}
+
+// Only the `anyhow` build drops the field
+#[derive(Convert, Clone)]
+#[convert(into(path = "FeatureTarget"))]
+struct FeatureSource {
+    #[convert(into(cfg_skip = "feature = \"anyhow\""))]
+    legacy: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct FeatureTarget {
+    legacy: Label,
+}
+
+fn main() {
+    let source = Source {
+        id: 1,
+        skipped: "skipped".to_string(),
+        kept: "kept".to_string(),
+    };
+
+    let target: Target = source.clone().into();
+    assert_eq!(
+        target,
+        Target {
+            id: 1,
+            skipped: Label::default(),
+            kept: Label("kept".to_string()),
+        }
+    );
+
+    let target: TryTarget = source.try_into().unwrap();
+    assert_eq!(target.skipped, Label::default());
+    assert_eq!(target.kept, Label("kept".to_string()));
+
+    let target: FeatureTarget = FeatureSource {
+        legacy: "legacy".to_string(),
+    }
+    .into();
+
+    #[cfg(feature = "anyhow")]
+    assert_eq!(target.legacy, Label::default());
+    #[cfg(not(feature = "anyhow"))]
+    assert_eq!(target.legacy, Label("legacy".to_string()));
+}
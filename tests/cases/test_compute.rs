@@ -0,0 +1,104 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Profile {
+    full_name: String,
+    initials: String,
+    first: String,
+    age: u32,
+}
+
+// `full_name` and `initials` are computed from the source before `first` is moved
+// into the target, so they can read it too
+#[derive(Convert, Debug)]
+#[convert(into(
+    path = "Profile",
+    compute(field = "full_name", expr = "format!(\"{} {}\", source.first, source.last)"),
+    compute(field = "initials", expr = "initials(&source.first, &source.last)")
+))]
+#[convert(try_into(
+    path = "Badge",
+    compute(
+        field = "label",
+        expr = "format!(\"{} ({})\", source.last, u8::try_from(source.age).map_err(|e| e.to_string())?)"
+    )
+))]
+struct User {
+    #[convert(try_into(skip))]
+    first: String,
+    #[convert(skip)]
+    last: String,
+    #[convert(try_into(skip))]
+    age: u16,
+}
+
+fn initials(first: &str, last: &str) -> String {
+    first.chars().take(1).chain(last.chars().take(1)).collect()
+}
+
+struct Badge {
+    label: String,
+}
+
+// Converting from the other type, the computed field is one of the deriving type's
+struct Row {
+    street: String,
+    city: String,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(
+    path = "Row",
+    compute(field = "line", expr = "[source.street.as_str(), source.city.as_str()].join(\", \")")
+))]
+struct Address {
+    city: String,
+    #[convert(skip)]
+    line: String,
+}
+
+fn main() {
+    let profile: Profile = User {
+        first: "Ada".to_string(),
+        last: "Lovelace".to_string(),
+        age: 36,
+    }
+    .into();
+    assert_eq!(
+        profile,
+        Profile {
+            full_name: "Ada Lovelace".to_string(),
+            initials: "AL".to_string(),
+            first: "Ada".to_string(),
+            age: 36,
+        }
+    );
+
+    let badge = Badge::try_from(User {
+        first: "Alan".to_string(),
+        last: "Turing".to_string(),
+        age: 41,
+    })
+    .unwrap();
+    assert_eq!(badge.label, "Turing (41)");
+    assert!(
+        Badge::try_from(User {
+            first: "Old".to_string(),
+            last: "Timer".to_string(),
+            age: 300,
+        })
+        .is_err()
+    );
+
+    let address = Address::from(Row {
+        street: "1 Main St".to_string(),
+        city: "Springfield".to_string(),
+    });
+    assert_eq!(
+        address,
+        Address {
+            city: "Springfield".to_string(),
+            line: "1 Main St, Springfield".to_string(),
+        }
+    );
+}
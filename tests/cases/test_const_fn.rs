@@ -0,0 +1,49 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Point3 {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+// Fields moved as is allow the conversion to be used in const contexts too
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Point3", const_fn = "to_point3"))]
+#[convert(from(path = "Point3", const_fn = "from_point3"))]
+struct Position {
+    #[convert(identity)]
+    x: i32,
+    #[convert(identity)]
+    y: i32,
+    #[convert(identity)]
+    z: i32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Rgb(u8, u8, u8);
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Rgb", const_fn = "to_rgb"))]
+struct Rgba(
+    #[convert(identity)] u8,
+    #[convert(identity)] u8,
+    #[convert(identity)] u8,
+    #[convert(skip)] u8,
+);
+
+const ORIGIN: Point3 = Position { x: 0, y: 1, z: 2 }.to_point3();
+const BACK: Position = Position::from_point3(Point3 { x: 3, y: 4, z: 5 });
+
+fn main() {
+    assert_eq!(ORIGIN, Point3 { x: 0, y: 1, z: 2 });
+    assert_eq!(BACK, Position { x: 3, y: 4, z: 5 });
+
+    // The trait impls are still generated
+    let point: Point3 = Position { x: 1, y: 1, z: 1 }.into();
+    assert_eq!(point, Point3 { x: 1, y: 1, z: 1 });
+    assert_eq!(Position::from(point), Position { x: 1, y: 1, z: 1 });
+
+    const RED: Rgb = Rgba(255, 0, 0, 9).to_rgb();
+    assert_eq!(RED, Rgb(255, 0, 0));
+}
@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Name(String);
+
+impl From<String> for Name {
+    fn from(name: String) -> Self {
+        Name(name)
+    }
+}
+
+impl From<Name> for String {
+    fn from(name: Name) -> Self {
+        name.0
+    }
+}
+
+// A `Cow<str>` on the target is converted into as a whole
+impl From<Name> for Cow<'_, str> {
+    fn from(name: Name) -> Self {
+        Cow::Owned(name.0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Limits {
+    max: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiLimits {
+    max: u64,
+}
+
+impl From<Limits> for ApiLimits {
+    fn from(limits: Limits) -> Self {
+        ApiLimits {
+            max: limits.max.into(),
+        }
+    }
+}
+
+impl TryFrom<ApiLimits> for Limits {
+    type Error = String;
+    fn try_from(limits: ApiLimits) -> Result<Self, Self::Error> {
+        Ok(Limits {
+            max: limits.max.try_into().map_err(|_| "limit too large")?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Account {
+    name: Name,
+    label: String,
+    scores: Vec<u64>,
+    limits: ApiLimits,
+}
+
+// Borrowed or owned, the `Cow`s are converted through their owned values
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Account"))]
+#[convert(try_from(path = "Account"))]
+struct AccountView<'a> {
+    name: Cow<'a, str>,
+    label: Cow<'a, str>,
+    scores: Cow<'a, [u32]>,
+    limits: Cow<'a, Limits>,
+}
+
+struct Tag<'a> {
+    label: &'a str,
+    owner: String,
+}
+
+// A borrowed `&str` stays borrowed in a `Cow<str>`
+#[derive(Convert)]
+#[convert(from(path = "Tag<'a>"))]
+struct TagView<'a> {
+    label: Cow<'a, str>,
+    owner: Cow<'a, str>,
+}
+
+fn main() {
+    let scores = [1, 2];
+    let limits = Limits { max: 10 };
+    let view = AccountView {
+        name: Cow::Borrowed("ada"),
+        label: Cow::Owned("admin".to_string()),
+        scores: Cow::Borrowed(&scores),
+        limits: Cow::Borrowed(&limits),
+    };
+    let account: Account = view.into();
+    assert_eq!(
+        account,
+        Account {
+            name: Name("ada".to_string()),
+            label: "admin".to_string(),
+            scores: vec![1, 2],
+            limits: ApiLimits { max: 10 },
+        }
+    );
+
+    // Converting back, the view owns everything
+    let view = AccountView::try_from(account).unwrap();
+    assert_eq!(view.name, "ada");
+    assert!(matches!(view.limits, Cow::Owned(Limits { max: 10 })));
+    assert_eq!(&*view.scores, &[1, 2]);
+
+    let too_large = Account {
+        name: Name("bob".to_string()),
+        label: String::new(),
+        scores: vec![u64::MAX],
+        limits: ApiLimits { max: 1 },
+    };
+    assert!(AccountView::try_from(too_large).is_err());
+
+    let tag = TagView::from(Tag {
+        label: "core",
+        owner: "ada".to_string(),
+    });
+    assert!(matches!(tag.label, Cow::Borrowed("core")));
+    assert!(matches!(tag.owner, Cow::Owned(_)));
+}
@@ -0,0 +1,126 @@
+use std::collections::{BTreeSet, HashSet};
+
+use derive_into::Convert;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Tag(String);
+
+impl From<String> for Tag {
+    fn from(s: String) -> Self {
+        Tag(s)
+    }
+}
+
+impl TryFrom<i64> for Tag {
+    type Error = String;
+    fn try_from(n: i64) -> Result<Self, Self::Error> {
+        if n < 0 {
+            return Err(format!("negative tag id {}", n));
+        }
+        Ok(Tag(format!("#{}", n)))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Article {
+    tags: HashSet<Tag>,
+}
+
+#[derive(Debug, PartialEq)]
+struct IndexedArticle {
+    tags: BTreeSet<Tag>,
+}
+
+// A `Vec` on the deriving side is collected into whatever set the target holds
+#[derive(Convert)]
+#[convert(into(path = "Article"))]
+struct Draft {
+    tags: Vec<String>,
+}
+
+#[derive(Convert)]
+#[convert(try_into(path = "IndexedArticle"))]
+struct RawDraft {
+    tags: Vec<i64>,
+}
+
+struct Feed {
+    tags: Vec<String>,
+}
+
+struct RawFeed {
+    tags: Vec<i64>,
+}
+
+struct Selection {
+    tags: HashSet<String>,
+}
+
+// A set on the deriving side is collected from the source's `Vec`, and a `Vec`
+// from the source's set
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "Feed"))]
+#[convert(try_from(path = "RawFeed"))]
+struct TagSet {
+    tags: HashSet<Tag>,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "Selection"))]
+struct TagList {
+    tags: Vec<Tag>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Listing {
+    tags: Vec<Tag>,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Listing"))]
+struct Picked {
+    tags: BTreeSet<String>,
+}
+
+fn tags<const N: usize>(names: [&str; N]) -> HashSet<Tag> {
+    names.iter().map(|n| Tag(n.to_string())).collect()
+}
+
+fn main() {
+    let article: Article = Draft {
+        tags: vec!["rust".into(), "macros".into(), "rust".into()],
+    }
+    .into();
+    assert_eq!(article.tags, tags(["rust", "macros"]));
+
+    let indexed: IndexedArticle = RawDraft { tags: vec![2, 1, 2] }.try_into().unwrap();
+    assert_eq!(
+        indexed.tags.into_iter().collect::<Vec<_>>(),
+        vec![Tag("#1".into()), Tag("#2".into())]
+    );
+    let invalid: Result<IndexedArticle, _> = RawDraft { tags: vec![1, -1] }.try_into();
+    assert!(invalid.is_err());
+
+    let set: TagSet = Feed {
+        tags: vec!["a".into(), "b".into(), "a".into()],
+    }
+    .into();
+    assert_eq!(set.tags, tags(["a", "b"]));
+
+    let set = TagSet::try_from(RawFeed { tags: vec![3, 3] }).unwrap();
+    assert_eq!(set.tags, HashSet::from([Tag("#3".into())]));
+    assert!(TagSet::try_from(RawFeed { tags: vec![-3] }).is_err());
+
+    let mut list: TagList = Selection {
+        tags: HashSet::from(["x".to_string(), "y".to_string()]),
+    }
+    .into();
+    list.tags.sort();
+    assert_eq!(list.tags, vec![Tag("x".into()), Tag("y".into())]);
+
+    let listing: Listing = Picked {
+        tags: BTreeSet::from(["b".to_string(), "a".to_string()]),
+    }
+    .into();
+    assert_eq!(listing.tags, vec![Tag("a".into()), Tag("b".into())]);
+}
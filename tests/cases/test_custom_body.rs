@@ -0,0 +1,67 @@
+use derive_into::Convert;
+
+// Fields that have no one-to-one mapping to the target at all
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Span", body = "range_to_span"))]
+#[convert(try_from(path = "Span", body = "span_to_range"))]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Span {
+    offset: u32,
+    len: u32,
+}
+
+fn range_to_span(range: Range) -> Span {
+    Span {
+        offset: range.start,
+        len: range.end - range.start,
+    }
+}
+
+fn span_to_range(span: Span) -> Result<Range, String> {
+    let end = span
+        .offset
+        .checked_add(span.len)
+        .ok_or_else(|| "span overflows".to_string())?;
+    Ok(Range {
+        start: span.offset,
+        end,
+    })
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "u8", body = "helpers::light_to_code"))]
+enum Light {
+    Red,
+    Green,
+}
+
+mod helpers {
+    pub fn light_to_code(light: super::Light) -> u8 {
+        match light {
+            super::Light::Red => 1,
+            super::Light::Green => 2,
+        }
+    }
+}
+
+fn main() {
+    let span: Span = Range { start: 3, end: 10 }.into();
+    assert_eq!(span, Span { offset: 3, len: 7 });
+
+    let range = Range::try_from(Span { offset: 1, len: 2 }).unwrap();
+    assert_eq!(range, Range { start: 1, end: 3 });
+
+    let overflow = Range::try_from(Span {
+        offset: u32::MAX,
+        len: 1,
+    });
+    assert_eq!(overflow.unwrap_err(), "span overflows");
+
+    assert_eq!(u8::from(Light::Red), 1);
+    assert_eq!(u8::from(Light::Green), 2);
+}
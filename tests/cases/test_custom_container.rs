@@ -0,0 +1,100 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Score(u32);
+
+impl From<u8> for Score {
+    fn from(n: u8) -> Self {
+        Score(n.into())
+    }
+}
+
+impl TryFrom<Score> for u8 {
+    type Error = String;
+    fn try_from(score: Score) -> Result<Self, Self::Error> {
+        u8::try_from(score.0).map_err(|e| e.to_string())
+    }
+}
+
+/// A user-defined container the macro knows nothing about
+#[derive(Debug, PartialEq)]
+struct Grid<T>(Vec<T>);
+
+impl<T> IntoIterator for Grid<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T> FromIterator<T> for Grid<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Grid(iter.into_iter().collect())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Stack<T>(Vec<T>);
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Stack(iter.into_iter().collect())
+    }
+}
+
+// Registered for every field of the type
+#[derive(Convert, Debug, PartialEq)]
+#[convert(container(path = "Grid", iter))]
+#[convert(into(path = "Board"))]
+#[convert(try_from(path = "Board"))]
+struct RawBoard {
+    cells: Grid<u8>,
+    rows: Vec<Grid<u8>>,
+    // Registered for this field only
+    #[convert(container(path = "Stack", iter))]
+    history: Stack<Option<u8>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Board {
+    cells: Grid<Score>,
+    rows: Vec<Grid<Score>>,
+    history: Stack<Option<Score>>,
+}
+
+fn main() {
+    let board: Board = RawBoard {
+        cells: Grid(vec![1, 2]),
+        rows: vec![Grid(vec![3])],
+        history: Stack(vec![Some(4), None]),
+    }
+    .into();
+    assert_eq!(
+        board,
+        Board {
+            cells: Grid(vec![Score(1), Score(2)]),
+            rows: vec![Grid(vec![Score(3)])],
+            history: Stack(vec![Some(Score(4)), None]),
+        }
+    );
+
+    let raw = RawBoard::try_from(board).unwrap();
+    assert_eq!(raw.cells, Grid(vec![1, 2]));
+    assert_eq!(raw.history, Stack(vec![Some(4), None]));
+
+    let too_big = Board {
+        cells: Grid(vec![Score(1000)]),
+        rows: vec![],
+        history: Stack(vec![]),
+    };
+    assert!(RawBoard::try_from(too_big).is_err());
+}
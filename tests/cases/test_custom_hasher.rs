@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::BuildHasherDefault;
+
+use derive_into::Convert;
+
+type FixedState = BuildHasherDefault<DefaultHasher>;
+
+#[derive(Debug, PartialEq)]
+struct ApiIndex {
+    counts: HashMap<String, u64, FixedState>,
+    totals: HashMap<String, u64>,
+}
+
+// The hasher of a `HashMap` is taken from the target's type, whichever one each side uses
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiIndex"))]
+#[convert(try_from(path = "ApiIndex"))]
+struct Index {
+    counts: HashMap<String, u32, FixedState>,
+    totals: HashMap<String, u32, FixedState>,
+}
+
+#[derive(Convert, Debug)]
+#[convert(from(path = "Index"))]
+#[convert(try_into(path = "ApiIndex"))]
+#[convert(reserve_capacity)]
+struct ReservedIndex {
+    counts: HashMap<String, i64, FixedState>,
+    totals: HashMap<String, i64>,
+}
+
+fn index() -> Index {
+    let mut counts = HashMap::default();
+    counts.insert("a".to_string(), 1);
+    let mut totals = HashMap::default();
+    totals.insert("b".to_string(), 2);
+    Index { counts, totals }
+}
+
+fn main() {
+    let api: ApiIndex = index().into();
+    assert_eq!(api.counts["a"], 1);
+    assert_eq!(api.totals["b"], 2);
+
+    let back = Index::try_from(api).unwrap();
+    assert_eq!(back, index());
+
+    let mut too_big = HashMap::default();
+    too_big.insert("c".to_string(), u64::MAX);
+    assert!(
+        Index::try_from(ApiIndex {
+            counts: too_big,
+            totals: HashMap::new(),
+        })
+        .is_err()
+    );
+
+    let reserved = ReservedIndex::from(index());
+    assert_eq!(reserved.counts["a"], 1);
+    assert_eq!(reserved.totals["b"], 2);
+
+    let api: ApiIndex = reserved.try_into().unwrap();
+    assert_eq!(api.counts["a"], 1);
+    assert_eq!(api.totals["b"], 2);
+}
@@ -0,0 +1,48 @@
+use derive_into::Convert;
+
+// `debug` prints the generated impls to stderr while compiling; the
+// conversion itself must be unaffected
+#[derive(Convert, Debug, PartialEq)]
+#[convert(debug)]
+#[convert(into(path = "Target"))]
+struct Source {
+    id: u32,
+    name: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Target {
+    id: u64,
+    name: String,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(debug, from(path = "TargetKind"))]
+enum Kind {
+    A,
+    B(u32),
+}
+
+#[derive(Debug, PartialEq)]
+enum TargetKind {
+    A,
+    B(u32),
+}
+
+fn main() {
+    let target: Target = Source {
+        id: 1,
+        name: "one".to_string(),
+    }
+    .into();
+    assert_eq!(
+        target,
+        Target {
+            id: 1,
+            name: "one".to_string(),
+        }
+    );
+
+    assert_eq!(Kind::from(TargetKind::B(2)), Kind::B(2));
+    assert_eq!(Kind::from(TargetKind::A), Kind::A);
+}
@@ -0,0 +1,93 @@
+use derive_into::Convert;
+
+// Deliberately not `Default`, so `..Default::default()` couldn't be used
+#[derive(Debug, PartialEq)]
+struct Token(String);
+
+#[derive(Convert, Clone)]
+#[convert(into(path = "Session", default_field = "visits", default_field = "tags"))]
+#[convert(try_into(
+    path = "CheckedSession",
+    default_field = "visits",
+    default_field = "tags",
+    expect_fields = 3
+))]
+struct Login {
+    token: String,
+}
+
+impl From<String> for Token {
+    fn from(s: String) -> Self {
+        Token(s)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Session {
+    token: Token,
+    visits: u32,
+    tags: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct CheckedSession {
+    token: Token,
+    visits: u32,
+    tags: Vec<String>,
+}
+
+struct Credentials {
+    user: String,
+}
+
+// Converting from the other type, the listed fields are this type's own
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "Credentials", default_field = "attempts", default_field = "locked"))]
+#[convert(try_from(path = "Login", default_field = "attempts", default_field = "locked"))]
+struct Account {
+    #[convert(try_from(rename = "token"))]
+    user: String,
+    attempts: u8,
+    locked: bool,
+}
+
+fn main() {
+    let login = Login {
+        token: "abc".to_string(),
+    };
+    assert_eq!(
+        Session::from(login.clone()),
+        Session {
+            token: Token("abc".to_string()),
+            visits: 0,
+            tags: vec![],
+        }
+    );
+    assert_eq!(
+        CheckedSession::try_from(login).unwrap(),
+        CheckedSession {
+            token: Token("abc".to_string()),
+            visits: 0,
+            tags: vec![],
+        }
+    );
+
+    assert_eq!(
+        Account::from(Credentials {
+            user: "ann".to_string(),
+        }),
+        Account {
+            user: "ann".to_string(),
+            attempts: 0,
+            locked: false,
+        }
+    );
+    assert_eq!(
+        Account::try_from(Login {
+            token: "t".to_string(),
+        })
+        .unwrap()
+        .user,
+        "t"
+    );
+}
@@ -0,0 +1,51 @@
+use derive_into::Convert;
+
+// Integers convert into the variant with that discriminant, explicit or not
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "u8"))]
+#[convert(try_from(path = "i64"))]
+enum Status {
+    Active = 1,
+    Closed = 2,
+    // Follows `Closed`, like rustc numbers it
+    Pending,
+    Archived = 10,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "i8"))]
+enum Direction {
+    Left = -1,
+    Center,
+    Right,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "usize"))]
+enum Level {
+    Low,
+    Mid,
+    High,
+}
+
+fn main() {
+    assert_eq!(Status::try_from(1u8).unwrap(), Status::Active);
+    assert_eq!(Status::try_from(2u8).unwrap(), Status::Closed);
+    assert_eq!(Status::try_from(3u8).unwrap(), Status::Pending);
+    assert_eq!(Status::try_from(10u8).unwrap(), Status::Archived);
+    assert_eq!(Status::try_from(10i64).unwrap(), Status::Archived);
+
+    let err = Status::try_from(0u8).unwrap_err();
+    assert!(err.to_string().contains("unknown discriminant 0"), "{}", err);
+    let err = Status::try_from(-4i64).unwrap_err();
+    assert!(err.to_string().contains("unknown discriminant -4"), "{}", err);
+
+    assert_eq!(Direction::try_from(-1i8).unwrap(), Direction::Left);
+    assert_eq!(Direction::try_from(0i8).unwrap(), Direction::Center);
+    assert_eq!(Direction::try_from(1i8).unwrap(), Direction::Right);
+    assert!(Direction::try_from(2i8).is_err());
+
+    assert_eq!(Level::try_from(0usize).unwrap(), Level::Low);
+    assert_eq!(Level::try_from(2usize).unwrap(), Level::High);
+    assert!(Level::try_from(3usize).is_err());
+}
@@ -0,0 +1,71 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Feature {
+    Search,
+    Export,
+    DarkMode,
+}
+
+impl From<Feature> for String {
+    fn from(feature: Feature) -> String {
+        match feature {
+            Feature::Search => "search",
+            Feature::Export => "export",
+            Feature::DarkMode => "dark_mode",
+        }
+        .to_string()
+    }
+}
+
+impl TryFrom<String> for Feature {
+    type Error = String;
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        match name.as_str() {
+            "search" => Ok(Feature::Search),
+            "export" => Ok(Feature::Export),
+            "dark_mode" => Ok(Feature::DarkMode),
+            _ => Err(format!("unknown feature {}", name)),
+        }
+    }
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "SettingsDto"))]
+#[convert(try_from(path = "SettingsDto"))]
+struct Settings {
+    enabled: Vec<Feature>,
+    pinned: Option<Vec<Feature>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct SettingsDto {
+    enabled: Vec<String>,
+    pinned: Option<Vec<String>>,
+}
+
+fn main() {
+    let settings = Settings {
+        enabled: vec![Feature::Search, Feature::DarkMode],
+        pinned: Some(vec![Feature::Export]),
+    };
+
+    let dto: SettingsDto = settings.into();
+    assert_eq!(
+        dto,
+        SettingsDto {
+            enabled: vec!["search".to_string(), "dark_mode".to_string()],
+            pinned: Some(vec!["export".to_string()]),
+        }
+    );
+
+    let settings = Settings::try_from(dto).unwrap();
+    assert_eq!(settings.enabled, vec![Feature::Search, Feature::DarkMode]);
+    assert_eq!(settings.pinned, Some(vec![Feature::Export]));
+
+    let result = Settings::try_from(SettingsDto {
+        enabled: vec!["search".to_string(), "teleport".to_string()],
+        pinned: None,
+    });
+    assert!(result.unwrap_err().contains("unknown feature teleport"));
+}
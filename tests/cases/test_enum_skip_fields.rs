@@ -0,0 +1,76 @@
+use derive_into::Convert;
+
+#[derive(Convert, Debug, Clone)]
+#[convert(into(path = "Event"))]
+#[convert(try_into(path = "CompactEvent"))]
+enum RawEvent {
+    Click {
+        x: i32,
+        y: i32,
+        // Only tracked on the source side
+        #[convert(skip)]
+        trace_id: String,
+    },
+    Key {
+        #[convert(skip)]
+        scancode: u16,
+        key: char,
+    },
+    // Every field is dropped, the target is a unit variant
+    Ping {
+        #[convert(skip)]
+        sent_at: u64,
+    },
+    Close,
+}
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Click { x: i64, y: i64 },
+    Key { key: char },
+    Ping,
+    Close,
+}
+
+#[derive(Debug, PartialEq)]
+enum CompactEvent {
+    Click { x: i8, y: i8 },
+    Key { key: char },
+    Ping,
+    Close,
+}
+
+fn main() {
+    let click = RawEvent::Click {
+        x: 3,
+        y: -4,
+        trace_id: "abc".to_string(),
+    };
+    let event: Event = click.clone().into();
+    assert_eq!(event, Event::Click { x: 3, y: -4 });
+    assert_eq!(
+        CompactEvent::try_from(click).unwrap(),
+        CompactEvent::Click { x: 3, y: -4 }
+    );
+    assert!(
+        CompactEvent::try_from(RawEvent::Click {
+            x: 300,
+            y: 0,
+            trace_id: String::new(),
+        })
+        .is_err()
+    );
+
+    let event: Event = RawEvent::Key {
+        scancode: 30,
+        key: 'a',
+    }
+    .into();
+    assert_eq!(event, Event::Key { key: 'a' });
+
+    let event: Event = RawEvent::Ping { sent_at: 1 }.into();
+    assert_eq!(event, Event::Ping);
+
+    let event: Event = RawEvent::Close.into();
+    assert_eq!(event, Event::Close);
+}
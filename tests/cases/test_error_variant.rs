@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::num::ParseIntError;
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+enum SignupError {
+    BadName(String),
+    BadAge(String),
+    BadZip(ParseIntError),
+}
+
+impl std::fmt::Display for SignupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+// The generated impls return `String` errors, so the variants convert into one
+impl From<SignupError> for String {
+    fn from(error: SignupError) -> Self {
+        error.to_string()
+    }
+}
+
+#[derive(Debug)]
+struct Name(String);
+
+impl TryFrom<String> for Name {
+    type Error = String;
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        if name.is_empty() {
+            Err("empty".to_string())
+        } else {
+            Ok(Name(name))
+        }
+    }
+}
+
+fn parse_zip(form: &Form) -> Result<u32, ParseIntError> {
+    form.zip.parse()
+}
+
+struct Form {
+    name: String,
+    age: i64,
+    zip: String,
+}
+
+#[derive(Convert, Debug)]
+#[convert(try_from(path = "Form"))]
+struct Signup {
+    #[convert(try_from(error_variant = "SignupError::BadName"))]
+    name: Name,
+    #[convert(error_variant = SignupError::BadAge)]
+    age: u8,
+    // With `with_func`, the variant wraps the function's own error
+    #[convert(with_func = parse_zip, error_variant = SignupError::BadZip)]
+    zip: u32,
+}
+
+// Parse errors of map sources are wrapped as a message
+#[derive(Convert, Debug)]
+#[convert(try_from(path = "HashMap<String, String>", from_map))]
+struct Settings {
+    #[convert(error_variant = SignupError::BadAge)]
+    age: u8,
+}
+
+fn form(name: &str, age: i64, zip: &str) -> Form {
+    Form {
+        name: name.to_string(),
+        age,
+        zip: zip.to_string(),
+    }
+}
+
+fn main() {
+    let signup = Signup::try_from(form("ann", 30, "12345")).unwrap();
+    assert_eq!(signup.name.0, "ann");
+    assert_eq!(signup.age, 30);
+    assert_eq!(signup.zip, 12345);
+
+    let error = Signup::try_from(form("", 30, "12345")).unwrap_err();
+    assert_eq!(error, SignupError::BadName("\"empty\"".to_string()).to_string());
+
+    let error = Signup::try_from(form("ann", -1, "12345")).unwrap_err();
+    assert_eq!(
+        error,
+        SignupError::BadAge("out of range integral type conversion attempted".to_string()).to_string()
+    );
+
+    let error = Signup::try_from(form("ann", 30, "none")).unwrap_err();
+    let expected = SignupError::BadZip("none".parse::<u32>().unwrap_err());
+    assert_eq!(error, expected.to_string());
+
+    let settings = HashMap::from([("age".to_string(), "old".to_string())]);
+    let error = Settings::try_from(settings).unwrap_err();
+    let expected = SignupError::BadAge(format!("{:?}", "old".parse::<u8>().unwrap_err()));
+    assert_eq!(error, expected.to_string());
+}
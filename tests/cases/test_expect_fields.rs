@@ -0,0 +1,40 @@
+use derive_into::Convert;
+
+#[derive(Convert, Clone)]
+#[convert(into(path = "Target", expect_fields = 2))]
+#[convert(into(path = "Summary", expect_fields = 1))]
+struct Source {
+    id: u32,
+    #[convert(into(path = "Summary", skip))]
+    name: String,
+    #[convert(skip)]
+    #[allow(dead_code)]
+    cache: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Target {
+    id: u32,
+    name: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Summary {
+    id: u32,
+}
+
+fn main() {
+    let source = Source {
+        id: 1,
+        name: "one".to_string(),
+        cache: vec![],
+    };
+    assert_eq!(Summary::from(source.clone()), Summary { id: 1 });
+    assert_eq!(
+        Target::from(source),
+        Target {
+            id: 1,
+            name: "one".to_string(),
+        }
+    );
+}
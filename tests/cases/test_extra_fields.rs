@@ -0,0 +1,96 @@
+use derive_into::Convert;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq)]
+enum Value {
+    Number(u64),
+    Text(String),
+}
+
+impl From<u32> for Value {
+    fn from(n: u32) -> Self {
+        Value::Number(n as u64)
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::Text(s)
+    }
+}
+
+impl TryFrom<i64> for Value {
+    type Error = String;
+    fn try_from(n: i64) -> Result<Self, Self::Error> {
+        u64::try_from(n)
+            .map(Value::Number)
+            .map_err(|_| format!("{} is negative", n))
+    }
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Document"))]
+struct Article {
+    id: u32,
+    title: String,
+    #[convert(extra = "extra")]
+    views: u32,
+    #[convert(extra = "extra", rename = "writer")]
+    author: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Document {
+    id: u32,
+    title: String,
+    // #[serde(flatten)]
+    extra: HashMap<String, Value>,
+}
+
+#[derive(Convert)]
+#[convert(try_into(path = "Metrics"))]
+struct RawMetrics {
+    name: String,
+    #[convert(try_into(extra = "values"))]
+    delta: i64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Metrics {
+    name: String,
+    values: HashMap<String, Value>,
+}
+
+fn main() {
+    let document: Document = Article {
+        id: 7,
+        title: "Hello".to_string(),
+        views: 42,
+        author: "Alice".to_string(),
+    }
+    .into();
+
+    assert_eq!(document.id, 7);
+    assert_eq!(document.title, "Hello");
+    assert_eq!(document.extra.len(), 2);
+    assert_eq!(document.extra.get("views"), Some(&Value::Number(42)));
+    assert_eq!(
+        document.extra.get("writer"),
+        Some(&Value::Text("Alice".to_string()))
+    );
+
+    let metrics: Metrics = RawMetrics {
+        name: "latency".to_string(),
+        delta: 5,
+    }
+    .try_into()
+    .unwrap();
+    assert_eq!(metrics.values.get("delta"), Some(&Value::Number(5)));
+
+    let result: Result<Metrics, _> = RawMetrics {
+        name: "latency".to_string(),
+        delta: -5,
+    }
+    .try_into();
+    assert!(result.is_err());
+}
@@ -0,0 +1,60 @@
+use derive_into::Convert;
+
+// The source carries fields the target doesn't care about, none of which
+// need to be skipped since the conversion only reads the fields it maps
+struct BigSource {
+    id: u32,
+    name: String,
+    audit_log: Vec<String>,
+    cache: Option<Box<BigSource>>,
+    score: i64,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "BigSource"))]
+struct Slim {
+    id: u64,
+    name: String,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "BigSource"))]
+struct Scored {
+    #[convert(rename = "score")]
+    points: u8,
+}
+
+struct Wide(u8, String, Vec<u8>);
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "Wide"))]
+struct Narrow(u16, String);
+
+fn source(score: i64) -> BigSource {
+    BigSource {
+        id: 1,
+        name: "Ada".to_string(),
+        audit_log: vec!["created".to_string()],
+        cache: None,
+        score,
+    }
+}
+
+fn main() {
+    assert_eq!(
+        Slim::from(source(3)),
+        Slim {
+            id: 1,
+            name: "Ada".to_string(),
+        }
+    );
+
+    assert_eq!(Scored::try_from(source(42)).unwrap(), Scored { points: 42 });
+    assert!(Scored::try_from(source(-1)).is_err());
+
+    // Trailing tuple fields are ignored the same way
+    assert_eq!(
+        Narrow::from(Wide(7, "x".to_string(), vec![1, 2])),
+        Narrow(7, "x".to_string())
+    );
+}
@@ -0,0 +1,148 @@
+use derive_into::Convert;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Byte(u8);
+
+impl From<u8> for Byte {
+    fn from(byte: u8) -> Self {
+        Byte(byte)
+    }
+}
+
+impl From<Byte> for u8 {
+    fn from(byte: Byte) -> Self {
+        byte.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiPacket {
+    header: [u8; 4],
+    checksum: Option<[u32; 2]>,
+    rows: Vec<[u8; 2]>,
+    raw: Vec<u8>,
+    flags: Vec<Option<Byte>>,
+}
+
+struct RawPacket {
+    header: Vec<u8>,
+    checksum: Option<Vec<u64>>,
+    rows: Vec<Vec<u8>>,
+    raw: Vec<u8>,
+    flags: [Option<u8>; 2],
+}
+
+struct WirePacket {
+    header: [Byte; 4],
+    checksum: Option<[u16; 2]>,
+    rows: Vec<[Byte; 2]>,
+    raw: [u8; 4],
+    flags: [Option<Byte>; 2],
+}
+
+// Arrays are converted element by element. Converting from one, the other side may
+// be an array or a `Vec` of the converted elements.
+// Fallible conversions fill an array from whatever the other side holds, failing when
+// the lengths don't match
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiPacket"))]
+#[convert(try_from(path = "RawPacket"))]
+#[convert(from(path = "WirePacket"))]
+struct Packet {
+    header: [u8; 4],
+    checksum: Option<[u32; 2]>,
+    rows: Vec<[u8; 2]>,
+    raw: [u8; 4],
+    flags: [Option<u8>; 2],
+}
+
+#[derive(Debug, PartialEq)]
+struct WireFrame {
+    bytes: [Byte; 4],
+}
+
+// Elements of an array target are converted in place, without going through a `Vec`
+#[derive(Convert)]
+#[convert(into(path = "WireFrame"))]
+struct Frame {
+    bytes: [u8; 4],
+}
+
+fn main() {
+    let frame: WireFrame = Frame {
+        bytes: [1, 2, 3, 4],
+    }
+    .into();
+    assert_eq!(
+        frame,
+        WireFrame {
+            bytes: [Byte(1), Byte(2), Byte(3), Byte(4)],
+        }
+    );
+
+    let api: ApiPacket = Packet {
+        header: [1, 2, 3, 4],
+        checksum: Some([5, 6]),
+        rows: vec![[7, 8]],
+        raw: [9, 10, 11, 12],
+        flags: [Some(1), None],
+    }
+    .into();
+    assert_eq!(
+        api,
+        ApiPacket {
+            header: [1, 2, 3, 4],
+            checksum: Some([5, 6]),
+            rows: vec![[7, 8]],
+            raw: vec![9, 10, 11, 12],
+            flags: vec![Some(Byte(1)), None],
+        }
+    );
+
+    let packet = Packet::from(WirePacket {
+        header: [Byte(1); 4],
+        checksum: None,
+        rows: vec![[Byte(2), Byte(3)]],
+        raw: [0; 4],
+        flags: [None, Some(Byte(4))],
+    });
+    assert_eq!(packet.header, [1; 4]);
+    assert_eq!(packet.rows, vec![[2, 3]]);
+    assert_eq!(packet.flags, [None, Some(4)]);
+
+    let packet = Packet::try_from(RawPacket {
+        header: vec![1, 2, 3, 4],
+        checksum: Some(vec![5, 6]),
+        rows: vec![vec![7, 8]],
+        raw: vec![0; 4],
+        flags: [Some(1), None],
+    })
+    .unwrap();
+    assert_eq!(packet.header, [1, 2, 3, 4]);
+    assert_eq!(packet.checksum, Some([5, 6]));
+    assert_eq!(packet.rows, vec![[7, 8]]);
+
+    assert!(
+        Packet::try_from(RawPacket {
+            header: vec![0; 4],
+            checksum: Some(vec![u64::MAX, 0]),
+            rows: vec![],
+            raw: vec![0; 4],
+            flags: [None, None],
+        })
+        .is_err()
+    );
+
+    let result = Packet::try_from(RawPacket {
+        header: vec![0; 4],
+        checksum: Some(vec![1, 2, 3]),
+        rows: vec![],
+        raw: vec![0; 4],
+        flags: [None, None],
+    });
+    assert!(
+        result
+            .unwrap_err()
+            .contains("3 elements don't match the array length")
+    );
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct ApiUser {
+    id: u64,
+    name: String,
+    roles: Vec<String>,
+}
+
+// Domain objects are often shared behind an `Arc`, so the conversion takes one
+// and only clones the user out of it when it's still shared
+#[derive(Convert, Clone, Debug, PartialEq)]
+#[convert(into(path = "ApiUser", from_arc))]
+#[convert(try_into(path = "CheckedUser", from_arc))]
+struct DomainUser {
+    id: u32,
+    name: String,
+    roles: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct CheckedUser {
+    id: u16,
+    name: String,
+    roles: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum DomainRole {
+    Admin,
+    Member { team: String },
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "DomainRole", from_arc))]
+enum ApiRole {
+    Admin,
+    Member { team: String },
+}
+
+fn main() {
+    let user = Arc::new(DomainUser {
+        id: 7,
+        name: "Ada".to_string(),
+        roles: vec!["admin".to_string()],
+    });
+
+    // Shared: the user is cloned out and the other handle stays usable
+    let api: ApiUser = Arc::clone(&user).into();
+    assert_eq!(
+        api,
+        ApiUser {
+            id: 7,
+            name: "Ada".to_string(),
+            roles: vec!["admin".to_string()],
+        }
+    );
+    assert_eq!(user.name, "Ada");
+
+    // Unique: the user is moved out of the `Arc`
+    let checked: CheckedUser = user.try_into().unwrap();
+    assert_eq!(checked.id, 7);
+    assert_eq!(checked.roles, vec!["admin".to_string()]);
+
+    let too_big = Arc::new(DomainUser {
+        id: 70_000,
+        name: "Bob".to_string(),
+        roles: Vec::new(),
+    });
+    let result: Result<CheckedUser, _> = too_big.try_into();
+    assert!(result.is_err());
+
+    let role = Arc::new(DomainRole::Member {
+        team: "core".to_string(),
+    });
+    let api_role: ApiRole = role.into();
+    assert_eq!(
+        api_role,
+        ApiRole::Member {
+            team: "core".to_string(),
+        }
+    );
+    assert_eq!(ApiRole::from(Arc::new(DomainRole::Admin)), ApiRole::Admin);
+}
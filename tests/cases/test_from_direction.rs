@@ -0,0 +1,105 @@
+use derive_into::Convert;
+
+struct LegacyAccount {
+    old_name: String,
+    mail: String,
+    balance_cents: i32,
+    legacy_flags: u8,
+}
+
+struct ApiAccount {
+    name: String,
+    email: String,
+    balance_cents: i64,
+}
+
+#[derive(Debug, PartialEq, Default)]
+struct Preferences {
+    newsletter: bool,
+}
+
+// In the `from` direction, `rename` names the *source's* field
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "LegacyAccount"))]
+#[convert(try_from(path = "ApiAccount"))]
+struct Account {
+    #[convert(from(path = "LegacyAccount", rename = "old_name"))]
+    name: String,
+    #[convert(from(rename = "mail"), try_from(rename = "email"))]
+    email: String,
+    balance_cents: i64,
+    // Not on either source, filled with its default
+    #[convert(default)]
+    preferences: Preferences,
+    // Not on either source, and skipped rather than defaulted
+    #[convert(skip, default)]
+    note: Option<String>,
+}
+
+struct Draft {
+    title: Option<String>,
+    views: Option<u32>,
+}
+
+struct PartialDraft {
+    title: Option<String>,
+    views: Option<u32>,
+}
+
+// In the `from` direction, `unwrap` and `unwrap_or_default` take the *source's* `Option`
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "Draft"))]
+#[convert(try_from(path = "PartialDraft"))]
+struct Post {
+    #[convert(unwrap)]
+    title: String,
+    #[convert(unwrap_or_default)]
+    views: u64,
+}
+
+fn main() {
+    let account = Account::from(LegacyAccount {
+        old_name: "ann".to_string(),
+        mail: "ann@example.com".to_string(),
+        balance_cents: -5,
+        legacy_flags: 0,
+    });
+    assert_eq!(
+        account,
+        Account {
+            name: "ann".to_string(),
+            email: "ann@example.com".to_string(),
+            balance_cents: -5,
+            preferences: Preferences::default(),
+            note: None,
+        }
+    );
+
+    let account = Account::try_from(ApiAccount {
+        name: "bob".to_string(),
+        email: "bob@example.com".to_string(),
+        balance_cents: 12,
+    })
+    .unwrap();
+    assert_eq!(account.name, "bob");
+    assert_eq!(account.email, "bob@example.com");
+    assert_eq!(account.balance_cents, 12);
+
+    let post = Post::from(Draft {
+        title: Some("hello".to_string()),
+        views: None,
+    });
+    assert_eq!(
+        post,
+        Post {
+            title: "hello".to_string(),
+            views: 0,
+        }
+    );
+
+    let post = Post::try_from(PartialDraft {
+        title: None,
+        views: Some(3),
+    });
+    assert!(post.is_err());
+}
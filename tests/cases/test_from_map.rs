@@ -0,0 +1,67 @@
+use derive_into::Convert;
+use std::collections::HashMap;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "HashMap<String, String>", from_map))]
+struct Config {
+    host: String,
+    port: u16,
+    #[convert(rename = "connections")]
+    max_connections: u32,
+    timeout: Option<u64>,
+    #[convert(default)]
+    retries: u8,
+}
+
+fn config_map(entries: &[(&str, &str)]) -> HashMap<String, String> {
+    entries
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn main() {
+    let config: Config = config_map(&[
+        ("host", "localhost"),
+        ("port", "8080"),
+        ("connections", "16"),
+        ("timeout", "30"),
+    ])
+    .try_into()
+    .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            host: "localhost".to_string(),
+            port: 8080,
+            max_connections: 16,
+            timeout: Some(30),
+            retries: 0,
+        }
+    );
+
+    // Optional fields tolerate a missing key
+    let config = Config::try_from(config_map(&[
+        ("host", "localhost"),
+        ("port", "8080"),
+        ("connections", "16"),
+    ]))
+    .unwrap();
+    assert_eq!(config.timeout, None);
+
+    let missing = Config::try_from(config_map(&[("host", "localhost"), ("port", "8080")]));
+    assert_eq!(
+        missing.unwrap_err(),
+        "Missing key connections when converting to Config"
+    );
+
+    let invalid = Config::try_from(config_map(&[
+        ("host", "localhost"),
+        ("port", "not a port"),
+        ("connections", "16"),
+    ]));
+    assert_eq!(
+        invalid.unwrap_err(),
+        "Failed trying to convert port to Config: ParseIntError { kind: InvalidDigit }"
+    );
+}
@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+
+use derive_into::Convert;
+
+// `T` only shows up through `.into()` calls and `PhantomData`, so a plain
+// `Measurement { .. }` literal couldn't infer it
+#[derive(Debug, PartialEq)]
+struct Measurement<T, Unit> {
+    value: T,
+    samples: Vec<T>,
+    unit: PhantomData<Unit>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Meters;
+
+#[derive(Debug, PartialEq)]
+struct Pair<T>(T, T);
+
+#[derive(Debug, PartialEq)]
+enum Reading<T> {
+    Single(T),
+    Range { low: T, high: T },
+    Missing,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Measurement<u64, Meters>"))]
+#[convert(try_into(path = "Measurement<u8, Meters>"))]
+struct RawMeasurement {
+    value: u32,
+    samples: Vec<u32>,
+    #[convert(default)]
+    unit: PhantomData<Meters>,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Pair<i64>"))]
+#[convert(try_into(path = "Pair<i8>"))]
+struct RawPair(i32, i32);
+
+#[derive(Convert)]
+#[convert(into(path = "Reading<f64>"))]
+#[convert(try_into(path = "Reading<u8>"))]
+enum RawReading {
+    Single(u8),
+    Range { low: u8, high: u8 },
+    Missing,
+}
+
+fn main() {
+    let raw = || RawMeasurement {
+        value: 7,
+        samples: vec![1, 2],
+        unit: PhantomData,
+    };
+    let wide: Measurement<u64, Meters> = raw().into();
+    assert_eq!(
+        wide,
+        Measurement {
+            value: 7u64,
+            samples: vec![1, 2],
+            unit: PhantomData,
+        }
+    );
+    let narrow = Measurement::<u8, Meters>::try_from(raw()).unwrap();
+    assert_eq!(narrow.value, 7u8);
+
+    let pair: Pair<i64> = RawPair(1, -1).into();
+    assert_eq!(pair, Pair(1, -1));
+    assert!(Pair::<i8>::try_from(RawPair(1000, 0)).is_err());
+
+    let reading: Reading<f64> = RawReading::Range { low: 1, high: 2 }.into();
+    assert_eq!(reading, Reading::Range { low: 1.0, high: 2.0 });
+    let reading: Reading<f64> = RawReading::Single(3).into();
+    assert_eq!(reading, Reading::Single(3.0));
+    assert_eq!(
+        Reading::<u8>::try_from(RawReading::Missing).unwrap(),
+        Reading::Missing
+    );
+}
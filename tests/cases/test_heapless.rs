@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use heapless::FnvIndexMap;
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Celsius(f64);
+
+impl From<i16> for Celsius {
+    fn from(value: i16) -> Self {
+        Celsius(value.into())
+    }
+}
+
+impl TryFrom<Celsius> for i16 {
+    type Error = String;
+    fn try_from(value: Celsius) -> Result<Self, Self::Error> {
+        if value.0.fract() != 0.0 {
+            return Err(format!("{} isn't a whole degree", value.0));
+        }
+        Ok(value.0 as i16)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiReport {
+    temperatures: Vec<Celsius>,
+    limits: HashMap<String, Celsius>,
+}
+
+// Firmware DTOs hold fixed-capacity collections, which fail a fallible conversion
+// once they're full
+#[derive(Convert, Debug)]
+#[convert(into(path = "ApiReport"))]
+#[convert(try_from(path = "ApiReport"))]
+struct Report {
+    temperatures: heapless::Vec<i16, 4>,
+    limits: FnvIndexMap<String, i16, 2>,
+}
+
+fn main() {
+    let mut report = Report {
+        temperatures: heapless::Vec::new(),
+        limits: FnvIndexMap::new(),
+    };
+    report.temperatures.push(21).unwrap();
+    report.limits.insert("max".to_string(), 80).unwrap();
+
+    let api: ApiReport = report.into();
+    assert_eq!(api.temperatures, vec![Celsius(21.0)]);
+    assert_eq!(api.limits["max"], Celsius(80.0));
+
+    let report = Report::try_from(api).unwrap();
+    assert_eq!(report.temperatures.as_slice(), &[21]);
+    assert_eq!(report.limits["max"], 80);
+
+    let result = Report::try_from(ApiReport {
+        temperatures: (0..5).map(|i| Celsius(i.into())).collect(),
+        limits: HashMap::new(),
+    });
+    assert!(
+        result
+            .unwrap_err()
+            .contains("more elements than the capacity of 4")
+    );
+
+    let result = Report::try_from(ApiReport {
+        temperatures: Vec::new(),
+        limits: ["a", "b", "c"]
+            .into_iter()
+            .map(|name| (name.to_string(), Celsius(1.0)))
+            .collect(),
+    });
+    assert!(
+        result
+            .unwrap_err()
+            .contains("more elements than the capacity of 2")
+    );
+
+    assert!(
+        Report::try_from(ApiReport {
+            temperatures: vec![Celsius(0.5)],
+            limits: HashMap::new(),
+        })
+        .is_err()
+    );
+}
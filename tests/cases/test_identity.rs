@@ -0,0 +1,73 @@
+use std::time::{Duration, SystemTime};
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Job {
+    id: u64,
+    timeout: Duration,
+    started: SystemTime,
+    tags: Vec<String>,
+}
+
+// Same-typed fields are moved as is, without going through `From`, and
+// collections aren't rebuilt element by element
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Job"))]
+#[convert(try_from(path = "RawJob"))]
+struct QueuedJob {
+    id: u32,
+    #[convert(identity)]
+    timeout: Duration,
+    #[convert(identity)]
+    started: SystemTime,
+    #[convert(identity, try_from(rename = "labels"))]
+    tags: Vec<String>,
+}
+
+struct RawJob {
+    id: i64,
+    timeout: Duration,
+    started: SystemTime,
+    labels: Vec<String>,
+}
+
+fn main() {
+    let started = SystemTime::UNIX_EPOCH + Duration::from_secs(60);
+
+    let job: Job = QueuedJob {
+        id: 1,
+        timeout: Duration::from_secs(5),
+        started,
+        tags: vec!["nightly".to_string()],
+    }
+    .into();
+    assert_eq!(
+        job,
+        Job {
+            id: 1,
+            timeout: Duration::from_secs(5),
+            started,
+            tags: vec!["nightly".to_string()],
+        }
+    );
+
+    let queued = QueuedJob::try_from(RawJob {
+        id: 2,
+        timeout: Duration::from_millis(10),
+        started,
+        labels: vec![],
+    })
+    .unwrap();
+    assert_eq!(queued.timeout, Duration::from_millis(10));
+    assert_eq!(queued.started, started);
+    assert!(
+        QueuedJob::try_from(RawJob {
+            id: -1,
+            timeout: Duration::ZERO,
+            started,
+            labels: vec![],
+        })
+        .is_err()
+    );
+}
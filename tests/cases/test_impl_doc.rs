@@ -0,0 +1,41 @@
+#![deny(missing_docs)]
+//! The generated impls carry the given doc comment.
+
+use derive_into::Convert;
+
+/// The v1 user model.
+#[derive(Convert, Clone)]
+#[convert(into(path = "UserV2", doc = "Converts to the v2 API model"))]
+#[convert(try_from(path = "UserV2", doc = "Converts back, failing if the id doesn't fit"))]
+pub struct User {
+    /// The user's id.
+    pub id: u32,
+}
+
+/// The v2 user model.
+pub struct UserV2 {
+    /// The user's id.
+    pub id: u64,
+}
+
+/// A v1 status.
+#[derive(Convert)]
+#[convert(into(path = "StatusV2", doc = "Converts to the v2 status"))]
+pub enum Status {
+    /// Active.
+    Active,
+}
+
+/// A v2 status.
+#[derive(Debug, PartialEq)]
+pub enum StatusV2 {
+    /// Active.
+    Active,
+}
+
+fn main() {
+    let user: UserV2 = User { id: 7 }.into();
+    assert_eq!(user.id, 7);
+    assert!(User::try_from(UserV2 { id: u64::MAX }).is_err());
+    assert_eq!(StatusV2::from(Status::Active), StatusV2::Active);
+}
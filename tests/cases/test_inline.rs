@@ -0,0 +1,53 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Tick {
+    price: i64,
+    size: u64,
+}
+
+#[derive(Debug, PartialEq)]
+struct NarrowTick {
+    price: i32,
+    size: u32,
+}
+
+// Hot-path conversions can ask for the generated functions to be inlined
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Tick", inline))]
+#[convert(try_into(path = "NarrowTick", inline = "always"))]
+struct RawTick {
+    price: i32,
+    size: u64,
+}
+
+#[derive(Debug, PartialEq)]
+enum Side {
+    Bid,
+    Ask,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "Side", inline = "never"))]
+enum RawSide {
+    Bid,
+    Ask,
+}
+
+fn main() {
+    let tick: Tick = RawTick { price: -5, size: 10 }.into();
+    assert_eq!(tick, Tick { price: -5, size: 10 });
+
+    let narrow: NarrowTick = RawTick { price: 3, size: 7 }.try_into().unwrap();
+    assert_eq!(narrow, NarrowTick { price: 3, size: 7 });
+
+    let too_big: Result<NarrowTick, _> = RawTick {
+        price: 3,
+        size: u64::MAX,
+    }
+    .try_into();
+    assert!(too_big.is_err());
+
+    assert_eq!(RawSide::from(Side::Bid), RawSide::Bid);
+    assert_eq!(RawSide::from(Side::Ask), RawSide::Ask);
+}
@@ -0,0 +1,102 @@
+use derive_into::Convert;
+
+struct RawCounter {
+    count: i64,
+    limits: Vec<i64>,
+    step: Option<i32>,
+}
+
+// Integer fields are range checked with their own `TryFrom`, so the error explains
+// what went wrong
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "RawCounter"))]
+struct Counter {
+    count: u32,
+    limits: Vec<u8>,
+    step: Option<u16>,
+}
+
+#[derive(Debug)]
+struct NotDisplay;
+
+struct Raw(i64);
+
+impl TryFrom<Raw> for u32 {
+    type Error = NotDisplay;
+    fn try_from(raw: Raw) -> Result<Self, Self::Error> {
+        raw.0.try_into().map_err(|_| NotDisplay)
+    }
+}
+
+struct RawGauge {
+    level: Raw,
+}
+
+// A source with its own `TryFrom` impl for the integer may have an error that only
+// implements `Debug`, which is reported instead
+#[derive(Convert, Debug)]
+#[convert(try_from(path = "RawGauge"))]
+struct Gauge {
+    level: u32,
+}
+
+fn main() {
+    let counter = Counter::try_from(RawCounter {
+        count: 4_000_000_000,
+        limits: vec![0, 255],
+        step: Some(2),
+    })
+    .unwrap();
+    assert_eq!(
+        counter,
+        Counter {
+            count: 4_000_000_000,
+            limits: vec![0, 255],
+            step: Some(2),
+        }
+    );
+
+    let negative = Counter::try_from(RawCounter {
+        count: -1,
+        limits: Vec::new(),
+        step: None,
+    })
+    .unwrap_err();
+    assert_eq!(
+        negative.to_string(),
+        "Failed trying to convert source.count to Counter: out of range integral type conversion attempted"
+    );
+
+    let too_big = Counter::try_from(RawCounter {
+        count: i64::from(u32::MAX) + 1,
+        limits: Vec::new(),
+        step: None,
+    });
+    assert!(too_big.is_err());
+
+    let element = Counter::try_from(RawCounter {
+        count: 1,
+        limits: vec![1, 256],
+        step: None,
+    })
+    .unwrap_err();
+    assert_eq!(
+        element.to_string(),
+        "Failed trying to convert source.limits to Counter: out of range integral type conversion attempted"
+    );
+
+    let step = Counter::try_from(RawCounter {
+        count: 1,
+        limits: Vec::new(),
+        step: Some(-2),
+    });
+    assert!(step.is_err());
+
+    let gauge = Gauge::try_from(RawGauge { level: Raw(7) }).unwrap();
+    assert_eq!(gauge.level, 7);
+    let error = Gauge::try_from(RawGauge { level: Raw(-7) }).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Failed trying to convert source.level to Gauge: NotDisplay"
+    );
+}
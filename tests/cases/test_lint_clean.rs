@@ -0,0 +1,59 @@
+#![deny(warnings, clippy::all)]
+
+use derive_into::Convert;
+
+fn describe(source: &Source) -> String {
+    format!("{}{} items", source.label, source.count)
+}
+
+fn checked_describe(source: &Source) -> Result<String, String> {
+    Ok(describe(source))
+}
+
+// Same-typed fields and borrowed helper arguments would trip lints in user code
+#[derive(Convert)]
+#[convert(into(path = "Target"))]
+#[convert(try_into(path = "CheckedTarget"))]
+struct Source {
+    count: u32,
+    #[convert(into(with_func = describe), try_into(with_func = checked_describe))]
+    label: String,
+}
+
+struct Target {
+    count: u32,
+    label: String,
+}
+
+struct CheckedTarget {
+    count: u32,
+    label: String,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Mode"))]
+enum Kind {
+    Fast,
+}
+
+enum Mode {
+    Fast,
+}
+
+fn main() {
+    let target: Target = Source {
+        count: 2,
+        label: String::new(),
+    }
+    .into();
+    assert_eq!(target.count, 2);
+    assert_eq!(target.label, "2 items");
+    let checked = CheckedTarget::try_from(Source {
+        count: 3,
+        label: String::new(),
+    })
+    .unwrap();
+    assert_eq!(checked.count, 3);
+    assert_eq!(checked.label, "3 items");
+    let Mode::Fast = Mode::from(Kind::Fast);
+}
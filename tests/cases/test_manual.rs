@@ -0,0 +1,94 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct TagSet(String);
+
+// Hand-written conversion for the whole `Vec`, not its elements
+impl From<Vec<String>> for TagSet {
+    fn from(tags: Vec<String>) -> Self {
+        TagSet(tags.join(","))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct NonEmptyTagSet(String);
+
+impl TryFrom<Vec<String>> for NonEmptyTagSet {
+    type Error = String;
+    fn try_from(tags: Vec<String>) -> Result<Self, Self::Error> {
+        if tags.is_empty() {
+            Err("at least one tag is required".to_string())
+        } else {
+            Ok(NonEmptyTagSet(tags.join(",")))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Score(u32);
+
+impl From<u32> for Score {
+    fn from(n: u32) -> Self {
+        Score(n)
+    }
+}
+
+#[derive(Convert, Clone)]
+#[convert(into(path = "Post"))]
+struct RawPost {
+    #[convert(manual)]
+    tags: Vec<String>,
+    // Converted element-wise as usual
+    scores: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Post {
+    tags: TagSet,
+    scores: Vec<Score>,
+}
+
+#[derive(Convert, Clone)]
+#[convert(try_into(path = "CheckedPost"))]
+struct RawCheckedPost {
+    #[convert(try_into(manual))]
+    tags: Vec<String>,
+    scores: Option<Vec<u32>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct CheckedPost {
+    tags: NonEmptyTagSet,
+    scores: Option<Vec<Score>>,
+}
+
+fn main() {
+    let post: Post = RawPost {
+        tags: vec!["rust".to_string(), "macros".to_string()],
+        scores: vec![1, 2],
+    }
+    .into();
+    assert_eq!(
+        post,
+        Post {
+            tags: TagSet("rust,macros".to_string()),
+            scores: vec![Score(1), Score(2)],
+        }
+    );
+
+    let post: CheckedPost = RawCheckedPost {
+        tags: vec!["rust".to_string()],
+        scores: Some(vec![3]),
+    }
+    .try_into()
+    .unwrap();
+    assert_eq!(post.tags, NonEmptyTagSet("rust".to_string()));
+    assert_eq!(post.scores, Some(vec![Score(3)]));
+
+    let result: Result<CheckedPost, _> = RawCheckedPost {
+        tags: vec![],
+        scores: None,
+    }
+    .try_into();
+    assert!(result.unwrap_err().contains("at least one tag is required"));
+}
@@ -0,0 +1,101 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Username(String);
+
+impl From<String> for Username {
+    fn from(s: String) -> Self {
+        Username(s)
+    }
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Account"))]
+struct SignupForm {
+    #[convert(map_method = "trim")]
+    display_name: String,
+    #[convert(map_method = "to_lowercase")]
+    username: String,
+    #[convert(into(map_method = "len", rename = "password_length"))]
+    password: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Account {
+    display_name: String,
+    username: Username,
+    password_length: usize,
+}
+
+#[derive(Convert)]
+#[convert(try_into(path = "ShortName"))]
+struct LongName {
+    // `u8::try_from(usize)` fails for names longer than 255 bytes
+    #[convert(map_method = "len")]
+    name: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct ShortName {
+    name: u8,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Command"))]
+enum RawCommand {
+    Say(#[convert(map_method = "to_uppercase")] String),
+    Rename {
+        #[convert(map_method = "to_lowercase")]
+        name: String,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Say(String),
+    Rename { name: Username },
+}
+
+fn main() {
+    let account: Account = SignupForm {
+        display_name: "  Alice  ".to_string(),
+        username: "ALICE".to_string(),
+        password: "hunter2".to_string(),
+    }
+    .into();
+    assert_eq!(
+        account,
+        Account {
+            display_name: "Alice".to_string(),
+            username: Username("alice".to_string()),
+            password_length: 7,
+        }
+    );
+
+    let short: ShortName = LongName {
+        name: "Bob".to_string(),
+    }
+    .try_into()
+    .unwrap();
+    assert_eq!(short.name, 3);
+
+    let result: Result<ShortName, _> = LongName {
+        name: "x".repeat(300),
+    }
+    .try_into();
+    assert!(result.is_err());
+
+    let command: Command = RawCommand::Say("hi".to_string()).into();
+    assert_eq!(command, Command::Say("HI".to_string()));
+
+    let command: Command = RawCommand::Rename {
+        name: "Carol".to_string(),
+    }
+    .into();
+    assert_eq!(
+        command,
+        Command::Rename {
+            name: Username("carol".to_string())
+        }
+    );
+}
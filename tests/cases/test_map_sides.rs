@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct SensorId(String);
+
+#[derive(Debug, PartialEq)]
+struct Celsius(f64);
+
+impl From<i16> for Celsius {
+    fn from(tenths: i16) -> Self {
+        Celsius(f64::from(tenths) / 10.0)
+    }
+}
+
+fn parse_reading(raw: String) -> Result<Celsius, std::num::ParseFloatError> {
+    raw.parse().map(Celsius)
+}
+
+fn label(id: SensorId) -> String {
+    format!("sensor:{}", id.0)
+}
+
+fn word_count(text: String) -> usize {
+    text.split_whitespace().count()
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Readings"))]
+struct RawReadings {
+    // Keys pass through unchanged, only the values are converted
+    #[convert(map_keys(skip))]
+    by_sensor: HashMap<SensorId, i16>,
+    #[convert(map_keys(with_func = "label"), map_values(skip))]
+    labels: HashMap<SensorId, String>,
+    #[convert(map_values = "word_count")]
+    notes: HashMap<String, String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Readings {
+    by_sensor: HashMap<SensorId, Celsius>,
+    labels: HashMap<String, String>,
+    notes: HashMap<String, usize>,
+}
+
+struct Report {
+    by_sensor: HashMap<SensorId, String>,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "Report"))]
+struct ParsedReport {
+    #[convert(try_from(map_keys(skip), map_values = "parse_reading"))]
+    by_sensor: HashMap<SensorId, Celsius>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Channel(u8);
+
+// Only `Into` is implemented, so `try_into` isn't available for the keys
+#[allow(clippy::from_over_into)]
+impl Into<Channel> for SensorId {
+    fn into(self) -> Channel {
+        Channel(self.0.len() as u8)
+    }
+}
+
+struct Levels {
+    by_channel: HashMap<SensorId, i64>,
+}
+
+// Infallible keys next to fallible values
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "Levels"))]
+struct CheckedLevels {
+    #[convert(map_keys(into))]
+    by_channel: HashMap<Channel, u8>,
+}
+
+fn main() {
+    let id = SensorId("a1".to_string());
+
+    let readings: Readings = RawReadings {
+        by_sensor: HashMap::from([(id.clone(), 215)]),
+        labels: HashMap::from([(id.clone(), "kitchen".to_string())]),
+        notes: HashMap::from([("a1".to_string(), "runs a bit hot".to_string())]),
+    }
+    .into();
+    assert_eq!(
+        readings,
+        Readings {
+            by_sensor: HashMap::from([(id.clone(), Celsius(21.5))]),
+            labels: HashMap::from([("sensor:a1".to_string(), "kitchen".to_string())]),
+            notes: HashMap::from([("a1".to_string(), 4)]),
+        }
+    );
+
+    let report = ParsedReport::try_from(Report {
+        by_sensor: HashMap::from([(id.clone(), "19.5".to_string())]),
+    })
+    .unwrap();
+    assert_eq!(report.by_sensor[&id], Celsius(19.5));
+
+    assert!(
+        ParsedReport::try_from(Report {
+            by_sensor: HashMap::from([(id, "warm".to_string())]),
+        })
+        .is_err()
+    );
+
+    let levels = CheckedLevels::try_from(Levels {
+        by_channel: HashMap::from([(SensorId("a1".to_string()), 7)]),
+    })
+    .unwrap();
+    assert_eq!(levels.by_channel[&Channel(2)], 7);
+
+    assert!(
+        CheckedLevels::try_from(Levels {
+            by_channel: HashMap::from([(SensorId("a1".to_string()), 300)]),
+        })
+        .is_err()
+    );
+}
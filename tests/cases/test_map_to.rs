@@ -0,0 +1,51 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+enum Severity {
+    Info,
+    Error(String),
+    Other,
+}
+
+#[derive(Debug, PartialEq)]
+enum Status {
+    Up,
+    Down(String),
+    Unknown,
+}
+
+// The detailed enum is mapped onto coarser ones, collapsing the rare
+// variants into a catch-all
+#[derive(Convert, Debug)]
+#[convert(into(path = "Severity"))]
+#[convert(try_into(path = "Status"))]
+enum Event {
+    #[convert(try_into(rename = "Up"))]
+    Info,
+    #[convert(try_into(rename = "Down"))]
+    Error(String),
+    #[convert(map_to = "Severity::Other", try_into(map_to = "Status::Unknown"))]
+    Deprecated { since: u32 },
+    #[convert(map_to = "Severity::Other", try_into(map_to = "Status::Unknown"))]
+    Trace(u64, String),
+}
+
+fn main() {
+    assert_eq!(Severity::from(Event::Info), Severity::Info);
+    assert_eq!(
+        Severity::from(Event::Error("disk".to_string())),
+        Severity::Error("disk".to_string())
+    );
+    assert_eq!(Severity::from(Event::Deprecated { since: 2 }), Severity::Other);
+    assert_eq!(
+        Severity::from(Event::Trace(1, "tick".to_string())),
+        Severity::Other
+    );
+
+    let status: Status = Event::Trace(1, "tick".to_string()).try_into().unwrap();
+    assert_eq!(status, Status::Unknown);
+    let status: Status = Event::Deprecated { since: 1 }.try_into().unwrap();
+    assert_eq!(status, Status::Unknown);
+    let status: Status = Event::Info.try_into().unwrap();
+    assert_eq!(status, Status::Up);
+}
@@ -0,0 +1,53 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+enum LegacyColor {
+    Crimson,
+    Navy,
+    Green,
+    Beige,
+}
+
+// Names that no `rename_all` rule relates, mapped one by one
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "LegacyColor", map_variants(Red = "Crimson", Blue = "Navy")))]
+#[convert(from(path = "LegacyColor", map_variants(Red = "Crimson", Blue = "Navy")))]
+enum Color {
+    Red,
+    Blue,
+    // Not in the table, so the name stays the same
+    Green,
+    // A variant's own rename takes precedence over the table
+    #[convert(rename = "Beige")]
+    Sand,
+}
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    UserCreated { id: u32 },
+    Gone(u32),
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Event", map_variants(Signup = "UserCreated", Removed = "Gone")))]
+enum Activity {
+    Signup { id: u32 },
+    Removed(u32),
+}
+
+fn main() {
+    assert_eq!(LegacyColor::from(Color::Red), LegacyColor::Crimson);
+    assert_eq!(LegacyColor::from(Color::Blue), LegacyColor::Navy);
+    assert_eq!(LegacyColor::from(Color::Green), LegacyColor::Green);
+    assert_eq!(LegacyColor::from(Color::Sand), LegacyColor::Beige);
+
+    assert_eq!(Color::from(LegacyColor::Crimson), Color::Red);
+    assert_eq!(Color::from(LegacyColor::Navy), Color::Blue);
+    assert_eq!(Color::from(LegacyColor::Beige), Color::Sand);
+
+    assert_eq!(
+        Event::from(Activity::Signup { id: 1 }),
+        Event::UserCreated { id: 1 }
+    );
+    assert_eq!(Event::from(Activity::Removed(2)), Event::Gone(2));
+}
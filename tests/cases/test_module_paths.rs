@@ -0,0 +1,76 @@
+use derive_into::Convert;
+
+mod api {
+    #[derive(Debug, PartialEq)]
+    pub struct Model {
+        pub id: u32,
+        pub name: String,
+        pub status: Status,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum Status {
+        Active,
+        Disabled { reason: String },
+    }
+}
+
+mod domain {
+    use derive_into::Convert;
+
+    // Both types are called `Model`; the deriving side is always the local ident
+    #[derive(Convert, Debug, PartialEq)]
+    #[convert(into(path = "crate::api::Model"))]
+    #[convert(try_from(path = "crate::api::Model"))]
+    pub struct Model {
+        pub id: u32,
+        pub name: String,
+        pub status: Status,
+    }
+
+    #[derive(Convert, Debug, PartialEq)]
+    #[convert(into(path = "super::api::Status"))]
+    #[convert(try_from(path = "super::api::Status"))]
+    pub enum Status {
+        Active,
+        Disabled { reason: String },
+    }
+}
+
+// Deriving at the crate root against a same-named type in a module
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "api::Status"))]
+enum Status {
+    Active,
+    Disabled { reason: String },
+}
+
+fn main() {
+    let model = domain::Model {
+        id: 1,
+        name: "model".to_string(),
+        status: domain::Status::Disabled {
+            reason: "maintenance".to_string(),
+        },
+    };
+
+    let api_model: api::Model = model.into();
+    assert_eq!(
+        api_model,
+        api::Model {
+            id: 1,
+            name: "model".to_string(),
+            status: api::Status::Disabled {
+                reason: "maintenance".to_string(),
+            },
+        }
+    );
+
+    let model: domain::Model = api_model.try_into().unwrap();
+    assert_eq!(model.status, domain::Status::Disabled {
+        reason: "maintenance".to_string(),
+    });
+
+    let status: Status = api::Status::Active.into();
+    assert_eq!(status, Status::Active);
+}
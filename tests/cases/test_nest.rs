@@ -0,0 +1,92 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct City(String);
+
+impl From<String> for City {
+    fn from(s: String) -> Self {
+        City(s)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Address {
+    street: String,
+    city: City,
+}
+
+#[derive(Debug, PartialEq)]
+struct Coordinates {
+    lat: f64,
+    lon: f64,
+}
+
+#[derive(Convert, Clone)]
+#[convert(into(
+    path = "Customer",
+    nest(field = "address", path = "Address", from = ["street", "town"]),
+    nest(field = "location", path = "Coordinates", from("lat", "lon"))
+))]
+#[convert(try_into(
+    path = "CheckedCustomer",
+    nest(field = "address", path = "Address", from = ["street", "town"])
+))]
+struct FlatCustomer {
+    name: String,
+    street: String,
+    #[convert(rename = "city")]
+    town: String,
+    #[convert(try_into(skip))]
+    lat: f32,
+    #[convert(try_into(skip))]
+    lon: f32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Customer {
+    name: String,
+    address: Address,
+    location: Coordinates,
+}
+
+#[derive(Debug, PartialEq)]
+struct CheckedCustomer {
+    name: String,
+    address: Address,
+}
+
+fn main() {
+    let flat = FlatCustomer {
+        name: "Ann".to_string(),
+        street: "Main St".to_string(),
+        town: "Springfield".to_string(),
+        lat: 1.5,
+        lon: -2.5,
+    };
+
+    assert_eq!(
+        Customer::from(flat.clone()),
+        Customer {
+            name: "Ann".to_string(),
+            address: Address {
+                street: "Main St".to_string(),
+                city: City("Springfield".to_string()),
+            },
+            location: Coordinates {
+                lat: 1.5,
+                lon: -2.5,
+            },
+        }
+    );
+
+    assert_eq!(
+        CheckedCustomer::try_from(flat).unwrap(),
+        CheckedCustomer {
+            name: "Ann".to_string(),
+            address: Address {
+                street: "Main St".to_string(),
+                city: City("Springfield".to_string()),
+            },
+        }
+    );
+}
@@ -32,6 +32,19 @@ impl From<Score> for u32 {
     }
 }
 
+impl From<u8> for Score {
+    fn from(n: u8) -> Self {
+        Score(n.into())
+    }
+}
+
+impl TryFrom<Score> for u8 {
+    type Error = std::num::TryFromIntError;
+    fn try_from(s: Score) -> Result<Self, Self::Error> {
+        s.0.try_into()
+    }
+}
+
 // --- Option<Vec<T>> ---
 
 #[derive(Convert, Debug, PartialEq)]
@@ -130,6 +143,72 @@ struct TargetG {
     items: Vec<Tag>,
 }
 
+// --- Unwrap or default on Option<Vec<T>> ---
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "TargetH"))]
+#[convert(try_into(path = "TryTargetH"))]
+struct SourceH {
+    #[convert(unwrap_or_default)]
+    scores: Option<Vec<u32>>,
+}
+
+#[derive(Debug, PartialEq, Default)]
+struct TargetH {
+    scores: Vec<Score>,
+}
+
+#[derive(Debug, PartialEq, Default)]
+struct TryTargetH {
+    scores: Vec<Score>,
+}
+
+// From direction: the other side holds the Option
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "SourceI"))]
+struct TargetI {
+    #[convert(unwrap_or_default)]
+    scores: Vec<Score>,
+}
+
+#[derive(Debug, PartialEq, Default)]
+struct SourceI {
+    scores: Option<Vec<u32>>,
+}
+
+// --- Unwrap on Option<Vec<T>> / Option<Option<T>>, fallible ---
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_into(path = "TargetJ"))]
+struct SourceJ {
+    #[convert(unwrap)]
+    items: Option<Vec<String>>,
+    #[convert(unwrap)]
+    score: Option<Option<u32>>,
+}
+
+#[derive(Debug, PartialEq, Default)]
+struct TargetJ {
+    items: Vec<Tag>,
+    score: Option<Score>,
+}
+
+// --- Vec<Option<Vec<T>>>, three levels deep ---
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "TargetK"))]
+#[convert(try_from(path = "TargetK"))]
+struct SourceK {
+    batches: Vec<Option<Vec<u8>>>,
+    grouped: Option<HashMap<String, Vec<Option<u8>>>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct TargetK {
+    batches: Vec<Option<Vec<Score>>>,
+    grouped: Option<HashMap<String, Vec<Option<Score>>>>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -344,5 +423,81 @@ fn main() {
     let target: TargetC = source.into();
     println!("Vec<Vec<T>> conversion: {:?}", target);
 
+    let target: TargetH = SourceH {
+        scores: Some(vec![1, 2]),
+    }
+    .into();
+    assert_eq!(target.scores, vec![Score(1), Score(2)]);
+
+    let target: TargetH = SourceH { scores: None }.into();
+    assert_eq!(target.scores, vec![]);
+
+    let target: TryTargetH = SourceH {
+        scores: Some(vec![3]),
+    }
+    .try_into()
+    .unwrap();
+    assert_eq!(target.scores, vec![Score(3)]);
+
+    let target: TargetI = SourceI {
+        scores: Some(vec![4, 5]),
+    }
+    .into();
+    assert_eq!(target.scores, vec![Score(4), Score(5)]);
+
+    let target: TargetI = SourceI { scores: None }.into();
+    assert_eq!(target.scores, vec![]);
+
+    let target: TargetG = SourceG {
+        items: Some(vec!["a".to_string()]),
+    }
+    .into();
+    assert_eq!(target.items, vec![Tag("a".to_string())]);
+
+    let target: TargetJ = SourceJ {
+        items: Some(vec!["b".to_string()]),
+        score: Some(Some(6)),
+    }
+    .try_into()
+    .unwrap();
+    assert_eq!(target.items, vec![Tag("b".to_string())]);
+    assert_eq!(target.score, Some(Score(6)));
+
+    let result: Result<TargetJ, _> = SourceJ {
+        items: None,
+        score: Some(None),
+    }
+    .try_into();
+    assert!(result.is_err());
+
+    let target: TargetK = SourceK {
+        batches: vec![Some(vec![1, 2]), None, Some(vec![])],
+        grouped: Some(HashMap::from([("a".to_string(), vec![Some(4), None])])),
+    }
+    .into();
+    assert_eq!(
+        target.batches,
+        vec![Some(vec![Score(1), Score(2)]), None, Some(vec![])]
+    );
+    assert_eq!(
+        target.grouped.as_ref().unwrap()["a"],
+        vec![Some(Score(4)), None]
+    );
+    let source = SourceK::try_from(target).unwrap();
+    assert_eq!(source.batches, vec![Some(vec![1, 2]), None, Some(vec![])]);
+    assert_eq!(source.grouped.unwrap()["a"], vec![Some(4), None]);
+
+    // A failure in the innermost layer propagates out through every level
+    let too_big = TargetK {
+        batches: vec![None, Some(vec![Score(3), Score(300)])],
+        grouped: None,
+    };
+    assert!(SourceK::try_from(too_big).is_err());
+    let too_big = TargetK {
+        batches: vec![],
+        grouped: Some(HashMap::from([("b".to_string(), vec![Some(Score(256))])])),
+    };
+    assert!(SourceK::try_from(too_big).is_err());
+
     println!("All nested container tests passed!");
 }
@@ -0,0 +1,73 @@
+use derive_into::Convert;
+
+struct ApiItem {
+    sku: String,
+    quantity: i64,
+}
+
+struct ApiCart {
+    owner: String,
+    items: Vec<ApiItem>,
+}
+
+// The element type derives its own `TryFrom`, which the container's `Vec` field reuses
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "ApiItem"))]
+struct Item {
+    sku: String,
+    quantity: u8,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "ApiCart"))]
+struct Cart {
+    owner: String,
+    items: Vec<Item>,
+}
+
+fn main() {
+    let cart = Cart::try_from(ApiCart {
+        owner: "ann".to_string(),
+        items: vec![
+            ApiItem {
+                sku: "a-1".to_string(),
+                quantity: 2,
+            },
+            ApiItem {
+                sku: "b-2".to_string(),
+                quantity: 1,
+            },
+        ],
+    })
+    .unwrap();
+    assert_eq!(
+        cart,
+        Cart {
+            owner: "ann".to_string(),
+            items: vec![
+                Item {
+                    sku: "a-1".to_string(),
+                    quantity: 2,
+                },
+                Item {
+                    sku: "b-2".to_string(),
+                    quantity: 1,
+                },
+            ],
+        }
+    );
+
+    // The element's error is kept, wrapped with the container's field
+    let error = Cart::try_from(ApiCart {
+        owner: "ann".to_string(),
+        items: vec![ApiItem {
+            sku: "a-1".to_string(),
+            quantity: -3,
+        }],
+    })
+    .unwrap_err();
+    assert_eq!(
+        error,
+        "Failed trying to convert source.items to Cart: \"Failed trying to convert source.quantity to Item: out of range integral type conversion attempted\""
+    );
+}
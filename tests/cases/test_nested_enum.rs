@@ -0,0 +1,100 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+enum WireStatus {
+    Open,
+    Closed { reason: String },
+}
+
+#[derive(Debug, PartialEq)]
+enum ApiPriority {
+    Low,
+    Level(i64),
+}
+
+// Each enum derives its own conversion, which the structs' fields reuse
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "WireStatus"))]
+#[convert(from(path = "WireStatus"))]
+enum Status {
+    Open,
+    Closed { reason: String },
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "ApiPriority"))]
+enum Priority {
+    Low,
+    Level(u8),
+}
+
+#[derive(Debug, PartialEq)]
+struct WireTicket {
+    id: u64,
+    status: WireStatus,
+}
+
+struct ApiTicket {
+    id: u64,
+    status: WireStatus,
+    priority: ApiPriority,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "WireTicket"))]
+struct Ticket {
+    id: u64,
+    status: Status,
+}
+
+// The infallible `Status` conversion and the fallible `Priority` one both
+// work in a fallible conversion
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "ApiTicket"))]
+struct CheckedTicket {
+    id: u64,
+    status: Status,
+    priority: Priority,
+}
+
+fn main() {
+    let wire: WireTicket = Ticket {
+        id: 1,
+        status: Status::Closed {
+            reason: "done".to_string(),
+        },
+    }
+    .into();
+    assert_eq!(
+        wire,
+        WireTicket {
+            id: 1,
+            status: WireStatus::Closed {
+                reason: "done".to_string(),
+            },
+        }
+    );
+
+    let ticket = CheckedTicket::try_from(ApiTicket {
+        id: 2,
+        status: WireStatus::Open,
+        priority: ApiPriority::Level(3),
+    })
+    .unwrap();
+    assert_eq!(
+        ticket,
+        CheckedTicket {
+            id: 2,
+            status: Status::Open,
+            priority: Priority::Level(3),
+        }
+    );
+
+    let error = CheckedTicket::try_from(ApiTicket {
+        id: 3,
+        status: WireStatus::Open,
+        priority: ApiPriority::Level(-1),
+    })
+    .unwrap_err();
+    assert!(error.contains("source.priority"), "{}", error);
+}
@@ -0,0 +1,97 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct RawMeta {
+    version: i64,
+}
+
+#[derive(Debug, PartialEq)]
+struct Meta {
+    version: u8,
+}
+
+impl TryFrom<RawMeta> for Meta {
+    type Error = String;
+
+    fn try_from(raw: RawMeta) -> Result<Self, Self::Error> {
+        Ok(Meta {
+            version: raw.version.try_into().map_err(|_| "version out of range")?,
+        })
+    }
+}
+
+// An invalid `meta` is dropped instead of failing the whole conversion, while
+// `tags` still fails on an invalid value
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "RawDocument"))]
+#[convert(try_into(path = "RawDocument"))]
+struct Document {
+    #[convert(try_from(none_on_err))]
+    meta: Option<Meta>,
+    #[convert(none_on_err)]
+    score: Option<u8>,
+    tags: Option<Vec<u8>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct RawDocument {
+    meta: Option<RawMeta>,
+    score: Option<i32>,
+    tags: Option<Vec<i32>>,
+}
+
+impl TryFrom<Meta> for RawMeta {
+    type Error = String;
+
+    fn try_from(meta: Meta) -> Result<Self, Self::Error> {
+        Ok(RawMeta {
+            version: meta.version.into(),
+        })
+    }
+}
+
+fn main() {
+    let valid = Document::try_from(RawDocument {
+        meta: Some(RawMeta { version: 2 }),
+        score: Some(90),
+        tags: Some(vec![1]),
+    })
+    .unwrap();
+    assert_eq!(valid.meta, Some(Meta { version: 2 }));
+    assert_eq!(valid.score, Some(90));
+
+    let invalid = Document::try_from(RawDocument {
+        meta: Some(RawMeta { version: 1000 }),
+        score: Some(-1),
+        tags: None,
+    })
+    .unwrap();
+    assert_eq!(invalid.meta, None);
+    assert_eq!(invalid.score, None);
+
+    let missing = Document::try_from(RawDocument {
+        meta: None,
+        score: None,
+        tags: None,
+    })
+    .unwrap();
+    assert_eq!(
+        missing,
+        Document {
+            meta: None,
+            score: None,
+            tags: None,
+        }
+    );
+
+    let invalid_tags = Document::try_from(RawDocument {
+        meta: None,
+        score: None,
+        tags: Some(vec![-1]),
+    });
+    assert!(invalid_tags.is_err());
+
+    let raw: RawDocument = valid.try_into().unwrap();
+    assert_eq!(raw.meta, Some(RawMeta { version: 2 }));
+    assert_eq!(raw.score, Some(90));
+}
@@ -0,0 +1,95 @@
+use derive_into::Convert;
+
+#[derive(Debug)]
+struct RawSignup {
+    email: String,
+    age: i32,
+}
+
+fn normalize_signup(signup: &mut RawSignup) {
+    signup.email = signup.email.trim().to_lowercase();
+    signup.age = signup.age.clamp(0, 150);
+}
+
+fn email_present(signup: &RawSignup) -> Result<(), String> {
+    if signup.email.is_empty() {
+        Err("email is empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+// Normalization runs first, so validation and the field conversions only see
+// the cleaned up source
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(
+    path = "RawSignup",
+    normalize = "normalize_signup",
+    validate = "email_present"
+))]
+#[convert(from(path = "LegacySignup", normalize = "normalize_legacy"))]
+struct Signup {
+    email: String,
+    age: u8,
+}
+
+struct LegacySignup {
+    email: String,
+    age: u8,
+}
+
+fn normalize_legacy(signup: &mut LegacySignup) {
+    signup.email.make_ascii_lowercase();
+}
+
+#[derive(Convert, Debug)]
+#[convert(into(path = "Tag", normalize = "Label::normalize"))]
+enum Label {
+    Named(String),
+    Unnamed,
+}
+
+impl Label {
+    fn normalize(&mut self) {
+        if let Label::Named(name) = self {
+            *name = name.to_lowercase();
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Tag {
+    Named(String),
+    Unnamed,
+}
+
+fn main() {
+    let signup = Signup::try_from(RawSignup {
+        email: "  Ada@Example.COM ".to_string(),
+        age: 300,
+    })
+    .unwrap();
+    assert_eq!(
+        signup,
+        Signup {
+            email: "ada@example.com".to_string(),
+            age: 150,
+        }
+    );
+
+    let blank = Signup::try_from(RawSignup {
+        email: "   ".to_string(),
+        age: 20,
+    });
+    assert!(blank.is_err());
+
+    let legacy = Signup::from(LegacySignup {
+        email: "BOB@EXAMPLE.COM".to_string(),
+        age: 30,
+    });
+    assert_eq!(legacy.email, "bob@example.com");
+
+    let tag: Tag = Label::Named("Rust".to_string()).into();
+    assert_eq!(tag, Tag::Named("rust".to_string()));
+    assert_eq!(Tag::from(Label::Unnamed), Tag::Unnamed);
+}
@@ -0,0 +1,57 @@
+use derive_into::Convert;
+
+#[derive(Debug)]
+struct ApiThing {
+    code: i32,
+}
+
+#[derive(Debug, PartialEq)]
+struct DomainThing {
+    code: u8,
+}
+
+impl TryFrom<ApiThing> for DomainThing {
+    type Error = String;
+    fn try_from(thing: ApiThing) -> Result<Self, Self::Error> {
+        u8::try_from(thing.code)
+            .map(|code| DomainThing { code })
+            .map_err(|_| format!("code {} out of range", thing.code))
+    }
+}
+
+struct ApiOrder {
+    thing: Option<ApiThing>,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "ApiOrder"))]
+struct Order {
+    thing: Option<DomainThing>,
+}
+
+fn main() {
+    // None stays None without calling the inner conversion
+    let order = Order::try_from(ApiOrder { thing: None }).unwrap();
+    assert_eq!(order, Order { thing: None });
+
+    let order = Order::try_from(ApiOrder {
+        thing: Some(ApiThing { code: 7 }),
+    })
+    .unwrap();
+    assert_eq!(
+        order,
+        Order {
+            thing: Some(DomainThing { code: 7 })
+        }
+    );
+
+    // The inner error is kept, wrapped with the field and types that failed
+    let error = Order::try_from(ApiOrder {
+        thing: Some(ApiThing { code: -1 }),
+    })
+    .unwrap_err();
+    assert_eq!(
+        error,
+        "Failed trying to convert source.thing to Order: \"code -1 out of range\""
+    );
+}
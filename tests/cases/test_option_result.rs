@@ -0,0 +1,153 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct MissingErr;
+
+#[derive(Debug, PartialEq)]
+struct MissingField(&'static str);
+
+// The boundary type uses `Option`s where the domain keeps a `Result`
+#[derive(Convert, Debug)]
+#[convert(into(path = "Domain"))]
+#[convert(try_into(path = "CheckedDomain"))]
+struct Boundary {
+    #[convert(option_to_result = "MissingErr")]
+    port: Option<u16>,
+    #[convert(option_to_result = "MissingField(\"host\")")]
+    host: Option<String>,
+    #[convert(result_to_option)]
+    retries: Result<u8, String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Domain {
+    port: Result<u32, MissingErr>,
+    host: Result<String, MissingField>,
+    retries: Option<u64>,
+}
+
+#[derive(Debug, PartialEq)]
+struct CheckedDomain {
+    port: Result<u8, MissingErr>,
+    host: Result<String, MissingField>,
+    retries: Option<i8>,
+}
+
+// The inverse, declared on the domain side
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "Stored"))]
+#[convert(try_from(path = "RawStored"))]
+struct Record {
+    #[convert(option_to_result = "MissingErr")]
+    owner: Result<u64, MissingErr>,
+    #[convert(result_to_option)]
+    parent: Option<u32>,
+}
+
+struct Stored {
+    owner: Option<u32>,
+    parent: Result<u16, ()>,
+}
+
+struct RawStored {
+    owner: Option<i64>,
+    parent: Result<i64, String>,
+}
+
+fn main() {
+    let domain: Domain = Boundary {
+        port: Some(8080),
+        host: Some("localhost".to_string()),
+        retries: Ok(3),
+    }
+    .into();
+    assert_eq!(
+        domain,
+        Domain {
+            port: Ok(8080),
+            host: Ok("localhost".to_string()),
+            retries: Some(3),
+        }
+    );
+
+    let empty: Domain = Boundary {
+        port: None,
+        host: None,
+        retries: Err("unset".to_string()),
+    }
+    .into();
+    assert_eq!(
+        empty,
+        Domain {
+            port: Err(MissingErr),
+            host: Err(MissingField("host")),
+            retries: None,
+        }
+    );
+
+    let checked = CheckedDomain::try_from(Boundary {
+        port: None,
+        host: Some("db".to_string()),
+        retries: Ok(1),
+    })
+    .unwrap();
+    assert_eq!(checked.port, Err(MissingErr));
+    assert_eq!(checked.retries, Some(1));
+
+    // The value inside still has to convert
+    let too_big = CheckedDomain::try_from(Boundary {
+        port: Some(300),
+        host: None,
+        retries: Ok(1),
+    });
+    assert!(too_big.is_err());
+    let too_many = CheckedDomain::try_from(Boundary {
+        port: None,
+        host: None,
+        retries: Ok(200),
+    });
+    assert!(too_many.is_err());
+
+    assert_eq!(
+        Record::from(Stored {
+            owner: Some(1),
+            parent: Ok(2),
+        }),
+        Record {
+            owner: Ok(1),
+            parent: Some(2),
+        }
+    );
+    assert_eq!(
+        Record::from(Stored {
+            owner: None,
+            parent: Err(()),
+        }),
+        Record {
+            owner: Err(MissingErr),
+            parent: None,
+        }
+    );
+
+    let record = Record::try_from(RawStored {
+        owner: None,
+        parent: Err("orphan".to_string()),
+    })
+    .unwrap();
+    assert_eq!(record.owner, Err(MissingErr));
+    assert_eq!(record.parent, None);
+    assert!(
+        Record::try_from(RawStored {
+            owner: Some(-1),
+            parent: Ok(1),
+        })
+        .is_err()
+    );
+    assert!(
+        Record::try_from(RawStored {
+            owner: Some(1),
+            parent: Ok(-1),
+        })
+        .is_err()
+    );
+}
@@ -0,0 +1,87 @@
+use std::net::IpAddr;
+
+use derive_into::Convert;
+
+struct RawServer {
+    port: String,
+    host: String,
+    timeout: Option<String>,
+    weights: Vec<String>,
+    name: String,
+}
+
+// Strings from e.g. the environment, parsed into typed fields with `FromStr`
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "RawServer"))]
+struct Server {
+    #[convert(parse)]
+    port: u16,
+    #[convert(parse)]
+    host: IpAddr,
+    #[convert(parse)]
+    timeout: Option<u64>,
+    #[convert(parse)]
+    weights: Vec<u8>,
+    name: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Version {
+    major: u32,
+}
+
+#[derive(Convert)]
+#[convert(try_into(path = "Version"))]
+struct RawVersion {
+    #[convert(try_into(parse))]
+    major: String,
+}
+
+fn raw(port: &str) -> RawServer {
+    RawServer {
+        port: port.to_string(),
+        host: "127.0.0.1".to_string(),
+        timeout: Some("30".to_string()),
+        weights: vec!["1".to_string(), "2".to_string()],
+        name: "api".to_string(),
+    }
+}
+
+fn main() {
+    let server = Server::try_from(raw("8080")).unwrap();
+    assert_eq!(
+        server,
+        Server {
+            port: 8080,
+            host: IpAddr::from([127, 0, 0, 1]),
+            timeout: Some(30),
+            weights: vec![1, 2],
+            name: "api".to_string(),
+        }
+    );
+
+    // The parse error's own message is surfaced
+    let err = Server::try_from(raw("http")).unwrap_err();
+    assert!(err.contains("source.port"), "{}", err);
+    assert!(err.contains("invalid digit found in string"), "{}", err);
+
+    let err = Server::try_from(raw("70000")).unwrap_err();
+    assert!(err.contains("number too large"), "{}", err);
+
+    let mut bad_host = raw("1");
+    bad_host.host = "localhost".to_string();
+    let err = Server::try_from(bad_host).unwrap_err();
+    assert!(err.contains("invalid IP address syntax"), "{}", err);
+
+    let version: Version = RawVersion {
+        major: "2".to_string(),
+    }
+    .try_into()
+    .unwrap();
+    assert_eq!(version, Version { major: 2 });
+    let invalid: Result<Version, _> = RawVersion {
+        major: "two".to_string(),
+    }
+    .try_into();
+    assert!(invalid.is_err());
+}
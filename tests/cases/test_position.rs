@@ -0,0 +1,64 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Rgb(u16, u16, u16);
+
+#[derive(Debug, PartialEq)]
+struct Bgr(u8, u8, u8);
+
+fn doubled_green(color: &Color) -> u16 {
+    u16::from(color.green) * 2
+}
+
+// Named fields placed at explicit positions of a tuple struct
+#[derive(Convert, Debug, PartialEq, Clone)]
+#[convert(into(path = "Rgb"))]
+#[convert(try_into(path = "Bgr"))]
+#[convert(from(path = "Bgr"))]
+struct Color {
+    #[convert(position = 2)]
+    #[convert(into(position = 0))]
+    red: u8,
+    #[convert(position = 1)]
+    #[convert(into(position = 1, with_func = "doubled_green"))]
+    green: u8,
+    #[convert(position = 0)]
+    #[convert(into(position = 2))]
+    blue: u8,
+}
+
+#[derive(Debug, PartialEq)]
+struct Point(f64, f64);
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "Point"))]
+struct Coordinates {
+    #[convert(position = 1)]
+    latitude: f64,
+    #[convert(position = 0)]
+    longitude: f64,
+}
+
+fn main() {
+    let color = Color {
+        red: 1,
+        green: 2,
+        blue: 3,
+    };
+
+    let rgb: Rgb = color.clone().into();
+    assert_eq!(rgb, Rgb(1, 4, 3));
+
+    let bgr = Bgr::try_from(color.clone()).unwrap();
+    assert_eq!(bgr, Bgr(3, 2, 1));
+
+    assert_eq!(Color::from(bgr), color);
+
+    assert_eq!(
+        Coordinates::try_from(Point(13.4, 52.5)).unwrap(),
+        Coordinates {
+            latitude: 52.5,
+            longitude: 13.4,
+        }
+    );
+}
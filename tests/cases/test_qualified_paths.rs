@@ -0,0 +1,59 @@
+use derive_into::Convert;
+
+mod model {
+    // Named like the std container, but converted as a whole with `manual`
+    #[derive(Debug, PartialEq)]
+    pub struct Vec<T>(pub T);
+}
+
+#[derive(Debug, PartialEq)]
+struct Id(u64);
+
+impl From<u32> for Id {
+    fn from(id: u32) -> Self {
+        Id(id.into())
+    }
+}
+
+impl From<model::Vec<u32>> for model::Vec<Id> {
+    fn from(wrapper: model::Vec<u32>) -> Self {
+        model::Vec(wrapper.0.into())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiRecord {
+    parent: Option<Id>,
+    children: Vec<Id>,
+    tags: std::collections::HashMap<String, Id>,
+    result: Result<Id, String>,
+    wrapped: model::Vec<Id>,
+}
+
+// Fully-qualified std containers are converted like their short names
+#[derive(Convert)]
+#[convert(into(path = "ApiRecord"))]
+struct Record {
+    parent: std::option::Option<u32>,
+    children: ::std::vec::Vec<u32>,
+    tags: ::std::collections::HashMap<String, u32>,
+    result: core::result::Result<u32, String>,
+    #[convert(manual)]
+    wrapped: model::Vec<u32>,
+}
+
+fn main() {
+    let api: ApiRecord = Record {
+        parent: Some(1),
+        children: vec![2, 3],
+        tags: [("a".to_string(), 4)].into_iter().collect(),
+        result: Ok(5),
+        wrapped: model::Vec(6),
+    }
+    .into();
+    assert_eq!(api.parent, Some(Id(1)));
+    assert_eq!(api.children, vec![Id(2), Id(3)]);
+    assert_eq!(api.tags["a"], Id(4));
+    assert_eq!(api.result, Ok(Id(5)));
+    assert_eq!(api.wrapped, model::Vec(Id(6)));
+}
@@ -0,0 +1,77 @@
+use std::collections::{LinkedList, VecDeque};
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Job(u64);
+
+impl From<u32> for Job {
+    fn from(id: u32) -> Self {
+        Job(u64::from(id))
+    }
+}
+
+impl TryFrom<Job> for u32 {
+    type Error = String;
+    fn try_from(job: Job) -> Result<Self, Self::Error> {
+        u32::try_from(job.0).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Scheduler {
+    pending: VecDeque<Job>,
+    history: LinkedList<Job>,
+    retries: Option<VecDeque<Job>>,
+}
+
+// Queues convert element-wise like a `Vec`, keeping their order
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Scheduler"))]
+#[convert(try_from(path = "Scheduler"))]
+struct QueueState {
+    pending: VecDeque<u32>,
+    history: LinkedList<u32>,
+    retries: Option<VecDeque<u32>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Backlog {
+    items: VecDeque<Job>,
+}
+
+// A `Vec` on one side is collected into the queue on the other
+#[derive(Convert)]
+#[convert(into(path = "Backlog"))]
+struct Snapshot {
+    items: Vec<u32>,
+}
+
+fn main() {
+    let state = QueueState {
+        pending: VecDeque::from([3, 1, 2]),
+        history: LinkedList::from([7, 8]),
+        retries: Some(VecDeque::from([5])),
+    };
+    let scheduler: Scheduler = state.into();
+    assert_eq!(
+        scheduler.pending,
+        VecDeque::from([Job(3), Job(1), Job(2)])
+    );
+    assert_eq!(scheduler.history, LinkedList::from([Job(7), Job(8)]));
+    assert_eq!(scheduler.retries, Some(VecDeque::from([Job(5)])));
+
+    let back = QueueState::try_from(scheduler).unwrap();
+    assert_eq!(back.pending, VecDeque::from([3, 1, 2]));
+    assert_eq!(back.history, LinkedList::from([7, 8]));
+
+    let overflow = Scheduler {
+        pending: VecDeque::from([Job(u64::MAX)]),
+        history: LinkedList::new(),
+        retries: None,
+    };
+    assert!(QueueState::try_from(overflow).is_err());
+
+    let backlog: Backlog = Snapshot { items: vec![4, 2] }.into();
+    assert_eq!(backlog.items, VecDeque::from([Job(4), Job(2)]));
+}
@@ -0,0 +1,76 @@
+use derive_into::Convert;
+
+// Renaming to a keyword refers to the raw identifier on the other type
+#[derive(Debug, PartialEq)]
+struct ApiToken {
+    r#type: String,
+    r#match: u32,
+    value: String,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiToken"))]
+#[convert(try_from(path = "ApiToken"))]
+struct Token {
+    #[convert(rename = "type")]
+    kind: String,
+    #[convert(rename = "match")]
+    matched: u16,
+    #[convert(rename = "r#value")]
+    text: String,
+}
+
+#[derive(Debug, PartialEq)]
+enum ApiNode {
+    Leaf { r#type: String },
+    Empty,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "ApiNode"))]
+enum Node {
+    Leaf {
+        #[convert(rename = "type")]
+        kind: String,
+    },
+    Empty,
+}
+
+fn main() {
+    let token = Token {
+        kind: "ident".to_string(),
+        matched: 2,
+        text: "x".to_string(),
+    };
+    let api: ApiToken = token.into();
+    assert_eq!(
+        api,
+        ApiToken {
+            r#type: "ident".to_string(),
+            r#match: 2,
+            value: "x".to_string(),
+        }
+    );
+
+    let back = Token::try_from(api).unwrap();
+    assert_eq!(back.kind, "ident");
+    assert_eq!(back.matched, 2);
+    assert!(
+        Token::try_from(ApiToken {
+            r#type: String::new(),
+            r#match: 70_000,
+            value: String::new(),
+        })
+        .is_err()
+    );
+
+    assert_eq!(
+        Node::from(ApiNode::Leaf {
+            r#type: "lit".to_string(),
+        }),
+        Node::Leaf {
+            kind: "lit".to_string(),
+        }
+    );
+    assert_eq!(Node::from(ApiNode::Empty), Node::Empty);
+}
@@ -0,0 +1,82 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq, Clone)]
+struct Job {
+    name: String,
+    attempts: u32,
+    priority: i64,
+}
+
+fn check_name(job: &Job) -> Result<(), String> {
+    if job.name.is_empty() {
+        Err("name is empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+// A failed conversion hands the job back, so it can be retried later
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "Job", recoverable, validate = "check_name"))]
+struct QueuedJob {
+    name: String,
+    attempts: u8,
+    priority: i8,
+}
+
+#[derive(Convert, Debug, PartialEq, Clone)]
+#[convert(try_into(path = "Signal", recoverable))]
+enum RawSignal {
+    Level(u32),
+    Named { code: u32 },
+    Off,
+}
+
+#[derive(Debug, PartialEq)]
+enum Signal {
+    Level(u8),
+    Named { code: u8 },
+    Off,
+}
+
+fn main() {
+    let job = Job {
+        name: "build".to_string(),
+        attempts: 3,
+        priority: -1,
+    };
+    assert_eq!(
+        QueuedJob::try_from(job).unwrap(),
+        QueuedJob {
+            name: "build".to_string(),
+            attempts: 3,
+            priority: -1,
+        }
+    );
+
+    let too_many = Job {
+        name: "deploy".to_string(),
+        attempts: 300,
+        priority: 0,
+    };
+    let (recovered, error) = QueuedJob::try_from(too_many.clone()).unwrap_err();
+    assert_eq!(recovered, too_many);
+    assert!(error.contains("attempts"));
+
+    let unnamed = Job {
+        name: String::new(),
+        attempts: 1,
+        priority: 0,
+    };
+    let (recovered, error) = QueuedJob::try_from(unnamed.clone()).unwrap_err();
+    assert_eq!(recovered, unnamed);
+    assert!(error.contains("name is empty"));
+
+    assert_eq!(
+        Signal::try_from(RawSignal::Named { code: 7 }).unwrap(),
+        Signal::Named { code: 7 }
+    );
+    assert_eq!(Signal::try_from(RawSignal::Off).unwrap(), Signal::Off);
+    let (recovered, _) = Signal::try_from(RawSignal::Level(1000)).unwrap_err();
+    assert_eq!(recovered, RawSignal::Level(1000));
+}
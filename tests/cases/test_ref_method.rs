@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct UserDto {
+    id: u64,
+    name: String,
+    tags: Vec<String>,
+    scores: HashMap<String, i64>,
+}
+
+#[derive(Debug, PartialEq)]
+struct CompactUser {
+    id: u16,
+    name: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Labeled<T> {
+    label: String,
+    value: T,
+}
+
+// The methods borrow the user, so it stays usable after converting. The
+// consuming `From`/`TryFrom` impls are still generated alongside
+#[derive(Convert, Debug)]
+#[convert(into(path = "UserDto", ref_method = "to_dto"))]
+#[convert(try_into(path = "CompactUser", ref_method = "to_compact"))]
+#[convert(into(path = "Labeled<u64>", ref_method = "to_labeled"))]
+pub struct User {
+    #[convert(into(path = "Labeled<u64>", skip))]
+    id: u32,
+    #[convert(into(path = "Labeled<u64>", rename = "label"))]
+    name: String,
+    #[convert(try_into(skip), into(path = "Labeled<u64>", skip))]
+    tags: Vec<String>,
+    #[convert(try_into(skip), into(path = "Labeled<u64>", skip))]
+    scores: HashMap<String, i32>,
+    #[convert(
+        into(path = "UserDto", skip),
+        try_into(skip),
+        into(path = "Labeled<u64>", rename = "value", with_func = "total")
+    )]
+    visits: Vec<u32>,
+}
+
+fn total(user: &User) -> u64 {
+    user.visits.iter().map(|&v| u64::from(v)).sum()
+}
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Circle { radius: f64 },
+    Square(f64),
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Shape", ref_method = "to_shape"))]
+enum RawShape {
+    Circle { radius: f32 },
+    Square(f32),
+}
+
+fn main() {
+    let user = User {
+        id: 7,
+        name: "Ada".to_string(),
+        tags: vec!["admin".to_string()],
+        scores: HashMap::from([("chess".to_string(), 3)]),
+        visits: vec![2, 5],
+    };
+
+    let dto = user.to_dto();
+    assert_eq!(
+        dto,
+        UserDto {
+            id: 7,
+            name: "Ada".to_string(),
+            tags: vec!["admin".to_string()],
+            scores: HashMap::from([("chess".to_string(), 3)]),
+        }
+    );
+    assert_eq!(
+        user.to_compact().unwrap(),
+        CompactUser {
+            id: 7,
+            name: "Ada".to_string(),
+        }
+    );
+    assert_eq!(
+        user.to_labeled(),
+        Labeled {
+            label: "Ada".to_string(),
+            value: 7,
+        }
+    );
+
+    // The user is still owned here, and can be consumed by the trait impl
+    assert_eq!(user.name, "Ada");
+    let moved: UserDto = user.into();
+    assert_eq!(moved, dto);
+
+    let too_big = User {
+        id: 70_000,
+        name: "Bob".to_string(),
+        tags: Vec::new(),
+        scores: HashMap::new(),
+        visits: Vec::new(),
+    };
+    assert!(too_big.to_compact().is_err());
+
+    let square = RawShape::Square(2.0);
+    assert_eq!(square.to_shape(), Shape::Square(2.0));
+    assert_eq!(
+        RawShape::Circle { radius: 1.5 }.to_shape(),
+        Shape::Circle { radius: 1.5 }
+    );
+    assert_eq!(Shape::from(square), Shape::Square(2.0));
+}
@@ -0,0 +1,68 @@
+use derive_into::Convert;
+
+struct ApiUser {
+    id: u64,
+    name: String,
+    bio: String,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "ApiUser", reject_defaults("id", "name")))]
+struct User {
+    id: u64,
+    name: String,
+    bio: String,
+}
+
+#[derive(Convert)]
+#[convert(try_into(path = "Point", reject_defaults("1")))]
+struct RawPoint(i32, i32);
+
+#[derive(Debug, PartialEq)]
+struct Point(i32, i32);
+
+fn main() {
+    let user = User::try_from(ApiUser {
+        id: 1,
+        name: "Alice".to_string(),
+        bio: String::new(),
+    })
+    .unwrap();
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            name: "Alice".to_string(),
+            bio: String::new(),
+        }
+    );
+
+    let result = User::try_from(ApiUser {
+        id: 0,
+        name: "Alice".to_string(),
+        bio: String::new(),
+    });
+    assert_eq!(
+        result.unwrap_err(),
+        "Failed trying to convert ApiUser to User: id must not be the default value"
+    );
+
+    let result = User::try_from(ApiUser {
+        id: 1,
+        name: String::new(),
+        bio: String::new(),
+    });
+    assert_eq!(
+        result.unwrap_err(),
+        "Failed trying to convert ApiUser to User: name must not be the default value"
+    );
+
+    let point: Point = RawPoint(0, 5).try_into().unwrap();
+    assert_eq!(point, Point(0, 5));
+
+    let result: Result<Point, _> = RawPoint(5, 0).try_into();
+    assert_eq!(
+        result.unwrap_err(),
+        "Failed trying to convert RawPoint to Point: 1 must not be the default value"
+    );
+}
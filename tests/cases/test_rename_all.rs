@@ -0,0 +1,74 @@
+use derive_into::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "WireStatus", rename_all = "SCREAMING_SNAKE_CASE"))]
+#[convert(from(path = "WireStatus", rename_all = "SCREAMING_SNAKE_CASE"))]
+enum Status {
+    Active,
+    PendingReview,
+    HTTPError { code: u16 },
+    // An explicit rename wins over rename_all
+    #[convert(rename = "GONE")]
+    Deleted,
+}
+
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
+#[derive(Debug, PartialEq)]
+enum WireStatus {
+    ACTIVE,
+    PENDING_REVIEW,
+    HTTP_ERROR { code: u16 },
+    GONE,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_into(path = "Level", rename_all = "PascalCase"))]
+enum level {
+    very_low,
+    high(u8),
+}
+
+#[derive(Debug, PartialEq)]
+enum Level {
+    VeryLow,
+    High(u8),
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Mode", rename_all = "camelCase"))]
+enum AccessMode {
+    ReadOnly,
+    ReadWrite,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq)]
+enum Mode {
+    readOnly,
+    readWrite,
+}
+
+fn main() {
+    assert_eq!(WireStatus::from(Status::Active), WireStatus::ACTIVE);
+    assert_eq!(
+        WireStatus::from(Status::PendingReview),
+        WireStatus::PENDING_REVIEW
+    );
+    assert_eq!(
+        WireStatus::from(Status::HTTPError { code: 404 }),
+        WireStatus::HTTP_ERROR { code: 404 }
+    );
+    assert_eq!(WireStatus::from(Status::Deleted), WireStatus::GONE);
+    assert_eq!(
+        Status::from(WireStatus::PENDING_REVIEW),
+        Status::PendingReview
+    );
+    assert_eq!(Status::from(WireStatus::GONE), Status::Deleted);
+
+    assert_eq!(Level::try_from(level::very_low).unwrap(), Level::VeryLow);
+    assert_eq!(Level::try_from(level::high(3)).unwrap(), Level::High(3));
+
+    assert_eq!(Mode::from(AccessMode::ReadOnly), Mode::readOnly);
+    assert_eq!(Mode::from(AccessMode::ReadWrite), Mode::readWrite);
+}
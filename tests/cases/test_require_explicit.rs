@@ -0,0 +1,55 @@
+use derive_into::Convert;
+
+// Every field says how it's converted, so a field added later without an
+// annotation fails to compile instead of being converted silently
+#[derive(Convert, Debug)]
+#[convert(into(path = "AuditRecord", require_explicit))]
+#[convert(try_from(path = "RawEntry", require_explicit))]
+struct Entry {
+    #[convert(auto)]
+    id: u32,
+    #[convert(rename = "actor")]
+    user: String,
+    #[convert(into(skip), try_from(auto))]
+    session: u64,
+}
+
+struct AuditRecord {
+    id: u64,
+    actor: String,
+}
+
+struct RawEntry {
+    id: i64,
+    actor: String,
+    session: u64,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Event", require_explicit))]
+enum Action {
+    Login(#[convert(auto)] u32),
+    Logout,
+}
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Login(u64),
+    Logout,
+}
+
+fn main() {
+    let entry = Entry::try_from(RawEntry {
+        id: 1,
+        actor: "ada".to_string(),
+        session: 9,
+    })
+    .unwrap();
+    assert_eq!(entry.session, 9);
+
+    let record: AuditRecord = entry.into();
+    assert_eq!((record.id, record.actor.as_str()), (1, "ada"));
+
+    assert_eq!(Event::from(Action::Login(3)), Event::Login(3));
+    assert_eq!(Event::from(Action::Logout), Event::Logout);
+}
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Reading(u32);
+
+impl From<u16> for Reading {
+    fn from(n: u16) -> Self {
+        Reading(n.into())
+    }
+}
+
+impl TryFrom<Reading> for u16 {
+    type Error = String;
+    fn try_from(reading: Reading) -> Result<Self, Self::Error> {
+        u16::try_from(reading.0).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(reserve_capacity)]
+#[convert(into(path = "Series"))]
+#[convert(try_from(path = "Series"))]
+struct RawSeries {
+    readings: Vec<u16>,
+    batches: Vec<Vec<u16>>,
+    latest: Option<Vec<u16>>,
+    by_sensor: HashMap<String, u16>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Series {
+    readings: Vec<Reading>,
+    batches: Vec<Vec<Reading>>,
+    latest: Option<Vec<Reading>>,
+    by_sensor: HashMap<String, Reading>,
+}
+
+const LEN: usize = 100_000;
+
+fn main() {
+    let readings: Vec<u16> = (0..LEN).map(|i| (i % 60_000) as u16).collect();
+    let by_sensor: HashMap<String, u16> = (0..LEN)
+        .map(|i| (format!("sensor-{}", i), (i % 1000) as u16))
+        .collect();
+
+    let series: Series = RawSeries {
+        readings: readings.clone(),
+        batches: vec![readings[..10].to_vec(), vec![]],
+        latest: Some(readings[..3].to_vec()),
+        by_sensor: by_sensor.clone(),
+    }
+    .into();
+
+    assert_eq!(series.readings.len(), LEN);
+    assert_eq!(series.readings[59_999], Reading(59_999));
+    assert_eq!(series.readings[60_000], Reading(0));
+    assert_eq!(series.batches[0].len(), 10);
+    assert!(series.batches[1].is_empty());
+    assert_eq!(
+        series.latest,
+        Some(vec![Reading(0), Reading(1), Reading(2)])
+    );
+    assert_eq!(series.by_sensor.len(), LEN);
+    assert_eq!(series.by_sensor["sensor-1234"], Reading(234));
+
+    let raw = RawSeries::try_from(series).unwrap();
+    assert_eq!(raw.readings, readings);
+    assert_eq!(raw.by_sensor, by_sensor);
+    assert_eq!(raw.latest, Some(vec![0, 1, 2]));
+
+    // A failure part way through a large vector still fails the conversion
+    let mut too_big = vec![Reading(1); LEN];
+    too_big[LEN - 1] = Reading(70_000);
+    let result = RawSeries::try_from(Series {
+        readings: too_big,
+        batches: vec![],
+        latest: None,
+        by_sensor: HashMap::new(),
+    });
+    assert!(result.is_err());
+
+    let result = RawSeries::try_from(Series {
+        readings: vec![],
+        batches: vec![vec![Reading(1), Reading(70_000)]],
+        latest: None,
+        by_sensor: HashMap::new(),
+    });
+    assert!(result.is_err());
+}
@@ -0,0 +1,107 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct ApiError {
+    code: u16,
+}
+
+#[derive(Debug, PartialEq)]
+struct DomainError {
+    code: u32,
+}
+
+impl From<DomainError> for ApiError {
+    fn from(error: DomainError) -> Self {
+        ApiError {
+            code: error.code as u16,
+        }
+    }
+}
+
+impl TryFrom<ApiError> for DomainError {
+    type Error = String;
+    fn try_from(error: ApiError) -> Result<Self, Self::Error> {
+        if error.code == 0 {
+            return Err("missing error code".to_string());
+        }
+        Ok(DomainError {
+            code: error.code.into(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiResponse {
+    id: u64,
+    outcome: Result<Vec<u64>, ApiError>,
+    retry: Option<Result<u16, ApiError>>,
+}
+
+// Both halves of a `Result` are converted, so error payloads can be typed
+// differently on each side
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiResponse"))]
+#[convert(try_from(path = "ApiResponse"))]
+struct Response {
+    id: u64,
+    outcome: Result<Vec<u32>, DomainError>,
+    retry: Option<Result<u8, DomainError>>,
+}
+
+fn main() {
+    let api: ApiResponse = Response {
+        id: 1,
+        outcome: Ok(vec![1, 2]),
+        retry: Some(Err(DomainError { code: 503 })),
+    }
+    .into();
+    assert_eq!(
+        api,
+        ApiResponse {
+            id: 1,
+            outcome: Ok(vec![1, 2]),
+            retry: Some(Err(ApiError { code: 503 })),
+        }
+    );
+
+    let response = Response::try_from(ApiResponse {
+        id: 2,
+        outcome: Err(ApiError { code: 404 }),
+        retry: Some(Ok(3)),
+    })
+    .unwrap();
+    assert_eq!(
+        response,
+        Response {
+            id: 2,
+            outcome: Err(DomainError { code: 404 }),
+            retry: Some(Ok(3)),
+        }
+    );
+
+    // Either half failing to convert fails the conversion
+    assert!(
+        Response::try_from(ApiResponse {
+            id: 3,
+            outcome: Ok(vec![u64::MAX]),
+            retry: None,
+        })
+        .is_err()
+    );
+    assert!(
+        Response::try_from(ApiResponse {
+            id: 4,
+            outcome: Err(ApiError { code: 0 }),
+            retry: None,
+        })
+        .is_err()
+    );
+    assert!(
+        Response::try_from(ApiResponse {
+            id: 5,
+            outcome: Ok(vec![]),
+            retry: Some(Ok(300)),
+        })
+        .is_err()
+    );
+}
@@ -0,0 +1,91 @@
+use derive_into::Convert;
+
+#[derive(Convert, Clone, Debug, PartialEq)]
+#[convert(roundtrip_test)]
+#[convert(into(path = "UserDto"), from(path = "UserDto"))]
+#[convert(try_into(path = "CompactUser"), try_from(path = "CompactUser"))]
+struct User {
+    id: u32,
+    name: String,
+    tags: Vec<String>,
+}
+
+#[derive(Debug)]
+struct UserDto {
+    id: u32,
+    name: String,
+    tags: Vec<String>,
+}
+
+#[derive(Debug)]
+struct CompactUser {
+    id: u16,
+    name: String,
+    tags: Vec<String>,
+}
+
+// A lossy conversion, which the round trip catches
+#[derive(Convert, Clone, Debug, PartialEq)]
+#[convert(roundtrip_test)]
+#[convert(into(path = "Summary"), from(path = "Summary"))]
+struct Report {
+    title: String,
+    #[convert(skip, from(default))]
+    pages: u32,
+}
+
+struct Summary {
+    title: String,
+}
+
+#[derive(Convert, Clone, Debug, PartialEq)]
+#[convert(roundtrip_test)]
+#[convert(into(path = "ApiStatus"), from(path = "ApiStatus"))]
+enum Status {
+    Active,
+    Suspended { reason: String },
+}
+
+enum ApiStatus {
+    Active,
+    Suspended { reason: String },
+}
+
+fn main() {
+    let user = User {
+        id: 7,
+        name: "Ada".to_string(),
+        tags: vec!["admin".to_string()],
+    };
+    User::assert_roundtrip::<UserDto>(&user);
+    User::assert_try_roundtrip::<CompactUser>(&user);
+
+    Status::assert_roundtrip::<ApiStatus>(&Status::Active);
+    Status::assert_roundtrip::<ApiStatus>(&Status::Suspended {
+        reason: "spam".to_string(),
+    });
+
+    Report::assert_roundtrip::<Summary>(&Report {
+        title: "Q1".to_string(),
+        pages: 0,
+    });
+
+    // The failing assertions below are expected, so their messages aren't printed
+    std::panic::set_hook(Box::new(|_| {}));
+    let lossy = std::panic::catch_unwind(|| {
+        Report::assert_roundtrip::<Summary>(&Report {
+            title: "Q2".to_string(),
+            pages: 12,
+        })
+    });
+    assert!(lossy.is_err());
+
+    let out_of_range = std::panic::catch_unwind(|| {
+        User::assert_try_roundtrip::<CompactUser>(&User {
+            id: 70_000,
+            name: "Bob".to_string(),
+            tags: Vec::new(),
+        })
+    });
+    assert!(out_of_range.is_err());
+}
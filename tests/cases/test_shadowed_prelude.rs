@@ -0,0 +1,185 @@
+// The generated code only uses absolute paths, so it keeps compiling next to
+// items shadowing the prelude names, std macros and crate roots it relies on.
+// `Vec` and `Option` are left alone, since fields are recognized by those names
+mod shadowed {
+    #![allow(dead_code, unused_macros)]
+
+    use ::std::collections::HashMap;
+
+    use derive_into::Convert;
+
+    mod anyhow {}
+    mod core {}
+    mod std {}
+
+    pub struct Some;
+    pub struct None;
+    pub struct Ok;
+    pub struct Err;
+    pub struct String;
+    pub struct Box;
+    pub type Result<T> = ::core::result::Result<T, ()>;
+    trait Default {}
+    trait PartialEq {}
+    trait From {}
+    trait TryFrom {}
+    trait Send {}
+    trait Sync {}
+
+    macro_rules! format {
+        ($($tt:tt)*) => {
+            compile_error!("the shadowing `format!` was used")
+        };
+    }
+    macro_rules! stringify {
+        ($($tt:tt)*) => {
+            compile_error!("the shadowing `stringify!` was used")
+        };
+    }
+    macro_rules! concat {
+        ($($tt:tt)*) => {
+            compile_error!("the shadowing `concat!` was used")
+        };
+    }
+    macro_rules! panic {
+        ($($tt:tt)*) => {
+            compile_error!("the shadowing `panic!` was used")
+        };
+    }
+
+    pub type Text = ::std::string::String;
+
+    pub struct Order {
+        pub id: u32,
+        pub items: Vec<u8>,
+        pub prices: HashMap<Text, u16>,
+        pub note: Option<u8>,
+        pub coupon: u8,
+    }
+
+    #[derive(Convert, Debug)]
+    #[convert(try_into(
+        path = "Order",
+        reject_defaults("id"),
+        validate = |order: &RawOrder| if order.items.len() < 3 {
+            ::core::result::Result::Ok(())
+        } else {
+            ::core::result::Result::Err("too many items".into())
+        }
+    ))]
+    #[convert(into(path = "Summary", default))]
+    pub struct RawOrder {
+        pub id: u64,
+        pub items: Vec<i32>,
+        #[convert(into(skip))]
+        pub prices: HashMap<Text, i64>,
+        #[convert(into(skip))]
+        pub note: Option<i16>,
+        #[convert(unwrap, into(skip))]
+        pub coupon: Option<i8>,
+    }
+
+    #[derive(Default)]
+    pub struct Summary {
+        pub id: u64,
+        pub items: Vec<i32>,
+        pub total: i64,
+    }
+
+    #[derive(Convert, Debug)]
+    #[convert(try_from(path = "RawLine", accumulate_errors))]
+    #[convert(from(path = "Line"))]
+    pub struct Entry {
+        pub sku: u8,
+        #[convert(unwrap_or_default)]
+        pub quantity: u8,
+    }
+
+    pub struct RawLine {
+        pub sku: i32,
+        pub quantity: Option<i32>,
+    }
+
+    pub struct Line {
+        pub sku: u8,
+        pub quantity: Option<u8>,
+    }
+
+    #[derive(Convert, Debug, ::core::cmp::PartialEq)]
+    #[convert(try_from(path = "RawKind"))]
+    #[convert(into(path = "Text", variant_names))]
+    pub enum Kind {
+        Unit,
+        Sized { size: u8 },
+    }
+
+    pub enum RawKind {
+        Unit,
+        Sized { size: i32 },
+    }
+}
+
+use shadowed::{Entry, Kind, Line, Order, RawKind, RawLine, RawOrder, Summary};
+
+fn main() {
+    let order: Order = RawOrder {
+        id: 1,
+        items: vec![1, 2],
+        prices: [("tea".to_string(), 3)].into_iter().collect(),
+        note: None,
+        coupon: Some(5),
+    }
+    .try_into()
+    .unwrap();
+    assert_eq!(order.items, vec![1, 2]);
+    assert_eq!(order.prices["tea"], 3);
+    assert_eq!(order.note, None);
+    assert_eq!(order.coupon, 5);
+
+    let invalid: Result<Order, _> = RawOrder {
+        id: 0,
+        items: vec![],
+        prices: Default::default(),
+        note: None,
+        coupon: Some(1),
+    }
+    .try_into();
+    assert!(invalid.is_err());
+
+    let summary: Summary = RawOrder {
+        id: 2,
+        items: vec![3],
+        prices: Default::default(),
+        note: Some(1),
+        coupon: None,
+    }
+    .into();
+    assert_eq!((summary.id, summary.items, summary.total), (2, vec![3], 0));
+
+    let entry = Entry::try_from(RawLine {
+        sku: 4,
+        quantity: None,
+    })
+    .unwrap();
+    assert_eq!((entry.sku, entry.quantity), (4, 0));
+    assert!(
+        Entry::try_from(RawLine {
+            sku: -1,
+            quantity: Some(-1),
+        })
+        .is_err()
+    );
+
+    let entry = Entry::from(Line {
+        sku: 1,
+        quantity: Some(2),
+    });
+    assert_eq!(entry.quantity, 2);
+
+    assert_eq!(
+        Kind::try_from(RawKind::Sized { size: 3 }).unwrap(),
+        Kind::Sized { size: 3 }
+    );
+    assert_eq!(Kind::try_from(RawKind::Unit).unwrap(), Kind::Unit);
+    assert_eq!(String::from(Kind::Sized { size: 1 }), "Sized");
+}
@@ -0,0 +1,93 @@
+use std::{rc::Rc, sync::Arc};
+
+use derive_into::Convert;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Settings {
+    retries: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiSettings {
+    retries: u64,
+}
+
+impl From<Settings> for ApiSettings {
+    fn from(settings: Settings) -> Self {
+        ApiSettings {
+            retries: settings.retries.into(),
+        }
+    }
+}
+
+impl TryFrom<ApiSettings> for Settings {
+    type Error = String;
+    fn try_from(settings: ApiSettings) -> Result<Self, Self::Error> {
+        Ok(Settings {
+            retries: settings.retries.try_into().map_err(|_| "too many retries")?,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiService {
+    settings: Arc<ApiSettings>,
+    name: Rc<String>,
+}
+
+// A shared pointer is cloned out of when it's still shared
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiService"))]
+struct Service {
+    #[convert(shared = "clone")]
+    settings: Arc<Settings>,
+    #[convert(shared = "clone")]
+    name: Rc<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Worker {
+    settings: Arc<Settings>,
+}
+
+// Or the conversion fails instead of cloning
+#[derive(Convert, Debug)]
+#[convert(try_into(path = "Worker"))]
+struct ApiWorker {
+    #[convert(shared = "try_unwrap")]
+    settings: Arc<ApiSettings>,
+}
+
+fn main() {
+    let settings = Arc::new(Settings { retries: 3 });
+    let service = Service {
+        settings: Arc::clone(&settings),
+        name: Rc::new("billing".to_string()),
+    };
+    let api: ApiService = service.into();
+    assert_eq!(*api.settings, ApiSettings { retries: 3 });
+    assert_eq!(*api.name, "billing");
+    // The other handle is left untouched
+    assert_eq!(settings.retries, 3);
+
+    let worker: Worker = ApiWorker {
+        settings: Arc::new(ApiSettings { retries: 2 }),
+    }
+    .try_into()
+    .unwrap();
+    assert_eq!(*worker.settings, Settings { retries: 2 });
+
+    let shared = Arc::new(ApiSettings { retries: 2 });
+    let result: Result<Worker, _> = ApiWorker {
+        settings: Arc::clone(&shared),
+    }
+    .try_into();
+    let err = result.unwrap_err();
+    assert!(err.contains("the value is still shared"), "{}", err);
+
+    let result: Result<Worker, _> = ApiWorker {
+        settings: Arc::new(ApiSettings { retries: u64::MAX }),
+    }
+    .try_into();
+    assert!(result.is_err());
+}
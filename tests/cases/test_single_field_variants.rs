@@ -0,0 +1,80 @@
+use derive_into::Convert;
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiInner"))]
+#[convert(try_from(path = "ApiInner"))]
+struct Inner {
+    id: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiInner {
+    id: i64,
+}
+
+// Single-field tuple variants convert their one field, whether it's a plain
+// type or one deriving `Convert` itself
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiEvent"))]
+#[convert(try_from(path = "ApiEvent"))]
+enum Event {
+    Id(u32),
+    Wrapped(Inner),
+    Named(String),
+}
+
+#[derive(Debug, PartialEq)]
+enum ApiEvent {
+    Id(i64),
+    Wrapped(ApiInner),
+    Named(String),
+}
+
+// Skipping the leading field moves the remaining one to index 0 of the target
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Flag"))]
+enum Tagged {
+    Value(#[convert(skip)] String, u8),
+    Trailing(u8, #[convert(skip)] String),
+    Middle(u8, #[convert(skip)] String, u8),
+}
+
+#[derive(Debug, PartialEq)]
+enum Flag {
+    Value(u16),
+    Trailing(u16),
+    Middle(u16, u16),
+}
+
+fn main() {
+    assert_eq!(ApiEvent::from(Event::Id(3)), ApiEvent::Id(3));
+    assert_eq!(
+        ApiEvent::from(Event::Wrapped(Inner { id: 7 })),
+        ApiEvent::Wrapped(ApiInner { id: 7 })
+    );
+    assert_eq!(
+        ApiEvent::from(Event::Named("a".to_string())),
+        ApiEvent::Named("a".to_string())
+    );
+
+    assert_eq!(Event::try_from(ApiEvent::Id(9)).unwrap(), Event::Id(9));
+    assert_eq!(
+        Event::try_from(ApiEvent::Wrapped(ApiInner { id: 4 })).unwrap(),
+        Event::Wrapped(Inner { id: 4 })
+    );
+    assert!(Event::try_from(ApiEvent::Id(-1)).is_err());
+    assert!(Event::try_from(ApiEvent::Wrapped(ApiInner { id: -1 })).is_err());
+
+    assert_eq!(
+        Flag::from(Tagged::Value("ignored".to_string(), 5)),
+        Flag::Value(5)
+    );
+    assert_eq!(
+        Flag::from(Tagged::Trailing(6, "ignored".to_string())),
+        Flag::Trailing(6)
+    );
+    assert_eq!(
+        Flag::from(Tagged::Middle(1, "ignored".to_string(), 2)),
+        Flag::Middle(1, 2)
+    );
+}
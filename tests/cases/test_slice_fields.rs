@@ -0,0 +1,120 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Score(u8);
+
+impl From<u8> for Score {
+    fn from(n: u8) -> Self {
+        Score(n)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Percent(u8);
+
+impl TryFrom<u8> for Percent {
+    type Error = String;
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        if n <= 100 {
+            Ok(Percent(n))
+        } else {
+            Err(format!("{} is over 100", n))
+        }
+    }
+}
+
+// A borrowing view whose slices are collected into owned `Vec`s
+#[derive(Convert)]
+#[convert(into(path = "Scores"))]
+#[convert(try_into(path = "Percentages"))]
+#[convert(into(path = "ScoresRef<'a>"))]
+struct ScoresView<'a> {
+    #[convert(into(path = "ScoresRef<'a>", manual))]
+    values: &'a [u8],
+    #[convert(into(path = "ScoresRef<'a>", skip))]
+    #[convert(try_into(skip))]
+    labels: Option<&'a [&'a str]>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Scores {
+    values: Vec<Score>,
+    labels: Option<Vec<String>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Percentages {
+    values: Vec<Percent>,
+}
+
+// `manual` passes the slice through instead of collecting it
+#[derive(Debug, PartialEq)]
+struct ScoresRef<'a> {
+    values: &'a [u8],
+}
+
+// Slices are passed through when converting into one
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "ScoresView<'a>"))]
+struct Snapshot<'a> {
+    values: &'a [u8],
+    labels: Option<&'a [&'a str]>,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Token"))]
+enum TokenView<'a> {
+    Word(&'a str),
+    Bytes { data: &'a [u8] },
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Bytes { data: Vec<u8> },
+}
+
+fn main() {
+    let values = [10, 20, 150];
+    let labels = ["a", "b"];
+    let view = || ScoresView {
+        values: &values,
+        labels: Some(&labels),
+    };
+
+    let scores: Scores = view().into();
+    assert_eq!(
+        scores,
+        Scores {
+            values: vec![Score(10), Score(20), Score(150)],
+            labels: Some(vec!["a".to_string(), "b".to_string()]),
+        }
+    );
+
+    let error = Percentages::try_from(view()).unwrap_err();
+    assert!(error.contains("150 is over 100"));
+
+    let percentages = Percentages::try_from(ScoresView {
+        values: &values[..2],
+        labels: None,
+    })
+    .unwrap();
+    assert_eq!(percentages.values, vec![Percent(10), Percent(20)]);
+
+    let borrowed: ScoresRef = view().into();
+    assert_eq!(borrowed.values, &values);
+
+    let snapshot: Snapshot = view().into();
+    assert_eq!(snapshot.values, &values);
+    assert_eq!(snapshot.labels, Some(&labels[..]));
+
+    let token: Token = TokenView::Bytes { data: &values }.into();
+    assert_eq!(
+        token,
+        Token::Bytes {
+            data: vec![10, 20, 150]
+        }
+    );
+    let token: Token = TokenView::Word("hi").into();
+    assert_eq!(token, Token::Word("hi".to_string()));
+}
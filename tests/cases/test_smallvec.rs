@@ -0,0 +1,57 @@
+use smallvec::{SmallVec, smallvec};
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Reading(i64);
+
+impl From<i32> for Reading {
+    fn from(value: i32) -> Self {
+        Reading(value.into())
+    }
+}
+
+impl TryFrom<Reading> for i32 {
+    type Error = String;
+    fn try_from(reading: Reading) -> Result<Self, Self::Error> {
+        reading.0.try_into().map_err(|_| "reading out of range".to_string())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiSample {
+    readings: Vec<Reading>,
+    peaks: SmallVec<[Reading; 2]>,
+}
+
+// `SmallVec`s are converted element by element, to and from any other collection
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiSample"))]
+#[convert(try_from(path = "ApiSample"))]
+struct Sample {
+    readings: SmallVec<[i32; 4]>,
+    peaks: SmallVec<[i32; 2]>,
+}
+
+fn main() {
+    let api: ApiSample = Sample {
+        readings: smallvec![1, 2, 3],
+        peaks: smallvec![4, 5, 6],
+    }
+    .into();
+    assert_eq!(api.readings, vec![Reading(1), Reading(2), Reading(3)]);
+    assert_eq!(api.peaks.len(), 3);
+    assert!(api.peaks.spilled());
+
+    let sample = Sample::try_from(api).unwrap();
+    assert_eq!(sample.readings.as_slice(), &[1, 2, 3]);
+    assert_eq!(sample.peaks.as_slice(), &[4, 5, 6]);
+
+    assert!(
+        Sample::try_from(ApiSample {
+            readings: vec![Reading(i64::MAX)],
+            peaks: SmallVec::new(),
+        })
+        .is_err()
+    );
+}
@@ -0,0 +1,84 @@
+use derive_into::Convert;
+use std::borrow::Cow;
+
+#[derive(Convert, Debug, PartialEq, Clone)]
+#[convert(into(path = "Boxed"))]
+#[convert(from(path = "Boxed"))]
+struct Owned {
+    name: String,
+    nickname: Option<String>,
+    aliases: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Boxed {
+    name: Box<str>,
+    nickname: Option<Box<str>>,
+    aliases: Vec<Box<str>>,
+}
+
+#[derive(Convert, Debug, PartialEq, Clone)]
+#[convert(into(path = "Borrowed"))]
+#[convert(from(path = "Borrowed"))]
+struct OwnedCow {
+    name: String,
+    nickname: Option<String>,
+    aliases: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Borrowed {
+    name: Cow<'static, str>,
+    nickname: Option<Cow<'static, str>>,
+    aliases: Vec<Cow<'static, str>>,
+}
+
+// Both sides already use `Box<str>`, so it's passed through as is
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "SameBoxed"))]
+#[convert(try_from(path = "SameBoxed"))]
+struct AlreadyBoxed {
+    name: Box<str>,
+    nickname: Option<Box<str>>,
+}
+
+#[derive(Debug, PartialEq)]
+struct SameBoxed {
+    name: Box<str>,
+    nickname: Option<Box<str>>,
+}
+
+fn main() {
+    let owned = Owned {
+        name: "Alice".to_string(),
+        nickname: Some("Al".to_string()),
+        aliases: vec!["A".to_string(), "Ally".to_string()],
+    };
+
+    let boxed: Boxed = owned.clone().into();
+    assert_eq!(&*boxed.name, "Alice");
+    assert_eq!(boxed.nickname.as_deref(), Some("Al"));
+    assert_eq!(boxed.aliases, vec![Box::from("A"), Box::from("Ally")]);
+    assert_eq!(Owned::from(boxed), owned);
+
+    let owned = OwnedCow {
+        name: "Bob".to_string(),
+        nickname: None,
+        aliases: vec!["B".to_string()],
+    };
+
+    let borrowed: Borrowed = owned.clone().into();
+    assert_eq!(borrowed.name, "Bob");
+    assert_eq!(borrowed.nickname, None);
+    assert_eq!(borrowed.aliases, vec![Cow::Borrowed("B")]);
+    assert_eq!(OwnedCow::from(borrowed), owned);
+
+    let same: SameBoxed = AlreadyBoxed {
+        name: "Carol".into(),
+        nickname: Some("C".into()),
+    }
+    .into();
+    assert_eq!(&*same.name, "Carol");
+    let back = AlreadyBoxed::try_from(same).unwrap();
+    assert_eq!(back.nickname.as_deref(), Some("C"));
+}
@@ -0,0 +1,47 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq, Clone)]
+struct Name(String);
+
+impl From<String> for Name {
+    fn from(s: String) -> Self {
+        Name(s)
+    }
+}
+
+impl From<Name> for String {
+    fn from(name: Name) -> Self {
+        name.0
+    }
+}
+
+type Pair = (u32, String);
+type Single = (u64,);
+
+// The macro only sees the alias path, so the tuple has to be declared
+#[derive(Convert, Debug, PartialEq, Clone)]
+#[convert(into(path = "Pair", target_tuple))]
+#[convert(from(path = "Pair", target_tuple))]
+struct Entry(u32, Name);
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_into(path = "Single", target_tuple))]
+#[convert(try_from(path = "Single", target_tuple))]
+struct Id(u32);
+
+fn main() {
+    let entry = Entry(1, Name("one".to_string()));
+
+    let pair: Pair = entry.clone().into();
+    assert_eq!(pair, (1, "one".to_string()));
+
+    let back: Entry = pair.into();
+    assert_eq!(back, entry);
+
+    let single: Single = Id(7).try_into().unwrap();
+    assert_eq!(single, (7,));
+
+    let id = Id::try_from((7u64,)).unwrap();
+    assert_eq!(id, Id(7));
+    assert!(Id::try_from((u64::MAX,)).is_err());
+}
@@ -0,0 +1,56 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Byte(u8);
+
+impl From<u8> for Byte {
+    fn from(b: u8) -> Self {
+        Byte(b)
+    }
+}
+
+impl From<Byte> for u8 {
+    fn from(b: Byte) -> Self {
+        b.0
+    }
+}
+
+struct RawKey {
+    bytes: Vec<u8>,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "RawKey"))]
+struct Key {
+    #[convert(to_array = 4)]
+    bytes: [Byte; 4],
+}
+
+#[derive(Convert)]
+#[convert(try_into(path = "Digest"))]
+struct RawDigest(#[convert(to_array = 2)] Vec<i32>);
+
+#[derive(Debug, PartialEq)]
+struct Digest([u8; 2]);
+
+fn main() {
+    let key = Key::try_from(RawKey {
+        bytes: vec![1, 2, 3, 4],
+    })
+    .unwrap();
+    assert_eq!(key.bytes, [Byte(1), Byte(2), Byte(3), Byte(4)]);
+
+    let error = Key::try_from(RawKey {
+        bytes: vec![1, 2, 3],
+    })
+    .unwrap_err();
+    assert_eq!(
+        error,
+        "Failed trying to convert source.bytes to Key: expected 4 elements, got 3"
+    );
+
+    assert_eq!(Digest::try_from(RawDigest(vec![7, 8])).unwrap(), Digest([7, 8]));
+    // Both the length and every element are checked
+    assert!(Digest::try_from(RawDigest(vec![7, 8, 9])).is_err());
+    assert!(Digest::try_from(RawDigest(vec![7, -8])).is_err());
+}
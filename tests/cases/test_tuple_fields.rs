@@ -0,0 +1,119 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Score(u64);
+
+impl From<u32> for Score {
+    fn from(score: u32) -> Self {
+        Score(score.into())
+    }
+}
+
+impl TryFrom<Score> for u32 {
+    type Error = String;
+    fn try_from(score: Score) -> Result<Self, Self::Error> {
+        score.0.try_into().map_err(|_| "score too large".to_string())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Tag(String);
+
+impl From<String> for Tag {
+    fn from(tag: String) -> Self {
+        Tag(tag)
+    }
+}
+
+impl From<Tag> for String {
+    fn from(tag: Tag) -> Self {
+        tag.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ApiEntry {
+    best: (Score, Tag),
+    history: Vec<(Score, Tag)>,
+    bounds: Option<(i64, (u64, u64))>,
+}
+
+// With `tuple_elements`, each element of a tuple is converted on its own, through
+// nested tuples and containers
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "ApiEntry"))]
+#[convert(try_from(path = "ApiEntry"))]
+struct Entry {
+    #[convert(tuple_elements)]
+    best: (u32, String),
+    #[convert(tuple_elements)]
+    history: Vec<(u32, String)>,
+    #[convert(tuple_elements)]
+    bounds: Option<(i32, (u32, u8))>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+impl From<(u8, u8)> for Point {
+    fn from((x, y): (u8, u8)) -> Self {
+        Point { x, y }
+    }
+}
+
+struct ApiShape {
+    origin: Point,
+}
+
+// Otherwise a tuple converts as a whole, e.g. into a type implementing `From<(A, B)>`
+#[derive(Convert)]
+#[convert(into(path = "ApiShape"))]
+struct Shape {
+    origin: (u8, u8),
+}
+
+fn main() {
+    let api: ApiEntry = Entry {
+        best: (10, "gold".to_string()),
+        history: vec![(3, "bronze".to_string())],
+        bounds: Some((-1, (2, 3))),
+    }
+    .into();
+    assert_eq!(
+        api,
+        ApiEntry {
+            best: (Score(10), Tag("gold".to_string())),
+            history: vec![(Score(3), Tag("bronze".to_string()))],
+            bounds: Some((-1, (2, 3))),
+        }
+    );
+
+    let entry = Entry::try_from(api).unwrap();
+    assert_eq!(entry.best, (10, "gold".to_string()));
+    assert_eq!(entry.history, vec![(3, "bronze".to_string())]);
+    assert_eq!(entry.bounds, Some((-1, (2, 3))));
+
+    // Any element failing to convert fails the conversion
+    assert!(
+        Entry::try_from(ApiEntry {
+            best: (Score(u64::MAX), Tag("gold".to_string())),
+            history: vec![],
+            bounds: None,
+        })
+        .is_err()
+    );
+    assert!(
+        Entry::try_from(ApiEntry {
+            best: (Score(1), Tag("gold".to_string())),
+            history: vec![],
+            bounds: Some((0, (1, 256))),
+        })
+        .is_err()
+    );
+
+    let shape: ApiShape = Shape { origin: (1, 2) }.into();
+    assert_eq!(shape.origin, Point { x: 1, y: 2 });
+}
@@ -0,0 +1,45 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Pair(u64, String);
+
+// A trailing field is dropped from the target
+#[derive(Convert)]
+#[convert(into(path = "Pair"))]
+struct Triple(u32, String, #[convert(skip)] bool);
+
+// Later fields move up to take the skipped field's place
+#[derive(Convert)]
+#[convert(into(path = "Pair"))]
+struct Middle(u32, #[convert(skip)] bool, String);
+
+// Converting from the smaller tuple, skipped fields are filled with their default
+// and take no index on the source
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "Pair"))]
+struct Wide(u64, #[convert(skip, default)] bool, String);
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Line(u64, String),
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "Shape"))]
+enum FlaggedShape {
+    Line(u64, #[convert(skip, default)] bool, String),
+}
+
+fn main() {
+    let pair: Pair = Triple(1, "a".to_string(), true).into();
+    assert_eq!(pair, Pair(1, "a".to_string()));
+
+    let pair: Pair = Middle(2, true, "b".to_string()).into();
+    assert_eq!(pair, Pair(2, "b".to_string()));
+
+    let wide = Wide::from(Pair(3, "c".to_string()));
+    assert_eq!(wide, Wide(3, false, "c".to_string()));
+
+    let shape = FlaggedShape::from(Shape::Line(4, "d".to_string()));
+    assert_eq!(shape, FlaggedShape::Line(4, false, "d".to_string()));
+}
@@ -0,0 +1,37 @@
+use derive_into::Convert;
+
+fn name_len(source: &Named) -> u64 {
+    source.0.len() as u64
+}
+
+fn checked_name_len(source: &Named) -> Result<u8, String> {
+    u8::try_from(source.0.len()).map_err(|e| e.to_string())
+}
+
+// `with_func` on the second field must not move it to the first position
+#[derive(Convert, Clone)]
+#[convert(into(path = "Target"))]
+#[convert(try_into(path = "TryTarget"))]
+struct Named(
+    String,
+    #[convert(into(with_func = "name_len"), try_into(with_func = "checked_name_len"))] u32,
+    bool,
+);
+
+#[derive(Debug, PartialEq)]
+struct Target(String, u64, bool);
+
+#[derive(Debug, PartialEq)]
+struct TryTarget(String, u8, bool);
+
+fn main() {
+    let named = Named("abc".to_string(), 0, true);
+    assert_eq!(
+        Target::from(named.clone()),
+        Target("abc".to_string(), 3, true)
+    );
+    assert_eq!(
+        TryTarget::try_from(named).unwrap(),
+        TryTarget("abc".to_string(), 3, true)
+    );
+}
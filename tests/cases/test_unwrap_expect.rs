@@ -0,0 +1,87 @@
+use std::panic;
+
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Profile {
+    nickname: String,
+    age: u32,
+    city: String,
+}
+
+fn shout(name: &String) -> String {
+    name.to_uppercase()
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Profile"))]
+struct Signup {
+    #[convert(unwrap(expect = "nickname is set during signup"))]
+    nickname: Option<String>,
+    #[convert(unwrap)]
+    age: Option<u32>,
+    // The message also applies when the unwrapped value is passed to a function
+    #[convert(unwrap(expect = "city is required"), with_func = shout)]
+    city: Option<String>,
+}
+
+struct Form {
+    nickname: Option<String>,
+}
+
+// In fallible conversions the message becomes the error instead of a panic
+#[derive(Convert, Debug)]
+#[convert(try_from(path = "Form"))]
+struct Checked {
+    #[convert(unwrap(expect = "missing nickname"))]
+    nickname: String,
+}
+
+fn panic_message(signup: Signup) -> String {
+    let payload = panic::catch_unwind(|| Profile::from(signup)).unwrap_err();
+    payload.downcast::<String>().map(|message| *message).unwrap()
+}
+
+fn main() {
+    // The message is only formatted on the `None` path
+    let profile: Profile = Signup {
+        nickname: Some("ann".to_string()),
+        age: Some(30),
+        city: Some("oslo".to_string()),
+    }
+    .into();
+    assert_eq!(
+        profile,
+        Profile {
+            nickname: "ann".to_string(),
+            age: 30,
+            city: "OSLO".to_string(),
+        }
+    );
+
+    panic::set_hook(Box::new(|_| {}));
+    let message = panic_message(Signup {
+        nickname: None,
+        age: Some(30),
+        city: Some("oslo".to_string()),
+    });
+    assert_eq!(message, "nickname is set during signup");
+
+    let message = panic_message(Signup {
+        nickname: Some("ann".to_string()),
+        age: None,
+        city: Some("oslo".to_string()),
+    });
+    assert_eq!(message, "Expected value to exist when converting");
+
+    let message = panic_message(Signup {
+        nickname: Some("ann".to_string()),
+        age: Some(30),
+        city: None,
+    });
+    assert_eq!(message, "city is required");
+    let _ = panic::take_hook();
+
+    let error = Checked::try_from(Form { nickname: None }).unwrap_err();
+    assert!(error.ends_with("missing nickname"), "{}", error);
+}
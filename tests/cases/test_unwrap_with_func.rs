@@ -0,0 +1,118 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Token(String);
+
+fn parse_token(raw: &str) -> Result<Token, String> {
+    match raw.strip_prefix("tok_") {
+        Some(rest) if !rest.is_empty() => Ok(Token(rest.to_string())),
+        _ => Err(format!("invalid token {:?}", raw)),
+    }
+}
+
+fn token_len(raw: &str) -> usize {
+    raw.len()
+}
+
+fn parse_port(raw: &str) -> Option<u16> {
+    raw.parse().ok()
+}
+
+// `unwrap` runs first, then the function converts the unwrapped value
+#[derive(Convert)]
+#[convert(try_into(path = "Session"))]
+#[convert(into(path = "Summary"))]
+struct RawSession {
+    #[convert(try_into(unwrap, with_func = "parse_token"))]
+    #[convert(into(unwrap, with_func = "token_len", rename = "token_len"))]
+    token: Option<String>,
+    #[convert(try_into(unwrap, with_func_opt = "parse_port"))]
+    #[convert(into(skip))]
+    port: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Session {
+    token: Token,
+    port: u16,
+}
+
+#[derive(Debug, PartialEq)]
+struct Summary {
+    token_len: usize,
+}
+
+struct Request {
+    token: Option<String>,
+}
+
+// Same in the `from` direction, where the `Option` is on the other type
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "Request"))]
+struct Authorized {
+    #[convert(unwrap, with_func = "parse_token")]
+    token: Token,
+}
+
+fn main() {
+    let session = Session::try_from(RawSession {
+        token: Some("tok_abc".to_string()),
+        port: Some("8080".to_string()),
+    })
+    .unwrap();
+    assert_eq!(
+        session,
+        Session {
+            token: Token("abc".to_string()),
+            port: 8080,
+        }
+    );
+
+    let error = Session::try_from(RawSession {
+        token: None,
+        port: Some("8080".to_string()),
+    })
+    .unwrap_err();
+    assert_eq!(
+        error,
+        "Failed trying to convert source.token to Session: Expected value to exist"
+    );
+
+    let error = Session::try_from(RawSession {
+        token: Some("abc".to_string()),
+        port: Some("8080".to_string()),
+    })
+    .unwrap_err();
+    assert_eq!(
+        error,
+        "Failed trying to convert source.token to Session: \"invalid token \\\"abc\\\"\""
+    );
+
+    let error = Session::try_from(RawSession {
+        token: Some("tok_abc".to_string()),
+        port: Some("http".to_string()),
+    })
+    .unwrap_err();
+    assert_eq!(
+        error,
+        "Failed trying to convert source.port to Session: parse_port returned None"
+    );
+
+    let summary: Summary = RawSession {
+        token: Some("tok_abc".to_string()),
+        port: None,
+    }
+    .into();
+    assert_eq!(summary, Summary { token_len: 7 });
+
+    assert_eq!(
+        Authorized::try_from(Request {
+            token: Some("tok_xyz".to_string()),
+        })
+        .unwrap(),
+        Authorized {
+            token: Token("xyz".to_string()),
+        }
+    );
+    assert!(Authorized::try_from(Request { token: None }).is_err());
+}
@@ -0,0 +1,57 @@
+use derive_into::Convert;
+
+struct Order {
+    quantity: i32,
+}
+
+// Small checks can be written inline rather than as a named function
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(
+    path = "Order",
+    validate = "|order: &Order| if order.quantity > 0 { Ok(()) } else { Err(\"quantity must be positive\".into()) }"
+))]
+struct CheckedOrder {
+    quantity: u32,
+}
+
+enum Command {
+    Move(i32),
+    Stop,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(
+    path = "Command",
+    validate = |command: &Command| match command {
+        Command::Move(0) => Err("a move needs a distance".into()),
+        _ => Ok(()),
+    }
+))]
+enum CheckedCommand {
+    Move(i64),
+    Stop,
+}
+
+fn main() {
+    assert_eq!(
+        CheckedOrder::try_from(Order { quantity: 3 }).unwrap(),
+        CheckedOrder { quantity: 3 }
+    );
+    assert_eq!(
+        CheckedOrder::try_from(Order { quantity: 0 }).unwrap_err(),
+        "Failed trying to convert Order to CheckedOrder: quantity must be positive"
+    );
+
+    assert_eq!(
+        CheckedCommand::try_from(Command::Move(2)).unwrap(),
+        CheckedCommand::Move(2)
+    );
+    assert_eq!(
+        CheckedCommand::try_from(Command::Stop).unwrap(),
+        CheckedCommand::Stop
+    );
+    assert_eq!(
+        CheckedCommand::try_from(Command::Move(0)).unwrap_err(),
+        "Failed trying to convert Command to CheckedCommand: a move needs a distance"
+    );
+}
@@ -0,0 +1,96 @@
+use std::cell::RefCell;
+
+use derive_into::Convert;
+
+thread_local! {
+    static CALLS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(name: &'static str) {
+    CALLS.with(|calls| calls.borrow_mut().push(name));
+}
+
+fn take_calls() -> Vec<&'static str> {
+    CALLS.with(|calls| std::mem::take(&mut *calls.borrow_mut()))
+}
+
+struct RawAccount {
+    name: String,
+    balance: i64,
+}
+
+fn name_present(account: &RawAccount) -> Result<(), String> {
+    record("name_present");
+    if account.name.is_empty() {
+        Err("name is empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+fn balance_positive(account: &RawAccount) -> Result<(), String> {
+    record("balance_positive");
+    if account.balance < 0 {
+        Err("balance is negative".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+// Independent invariants are listed and checked in order, stopping at the first failure
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(
+    path = "RawAccount",
+    validate(
+        "name_present",
+        balance_positive,
+        "|account: &RawAccount| if account.name.len() < 10 { Ok(()) } else { Err(\"name is too long\".into()) }"
+    )
+))]
+struct Account {
+    name: String,
+    balance: u64,
+}
+
+fn main() {
+    let account = Account::try_from(RawAccount {
+        name: "ada".to_string(),
+        balance: 10,
+    })
+    .unwrap();
+    assert_eq!(account.balance, 10);
+    assert_eq!(take_calls(), ["name_present", "balance_positive"]);
+
+    // The first check passes and the second fails, so the rest are skipped
+    let overdrawn = Account::try_from(RawAccount {
+        name: "a very long name".to_string(),
+        balance: -5,
+    })
+    .unwrap_err();
+    assert_eq!(
+        overdrawn,
+        "Failed trying to convert RawAccount to Account: balance is negative"
+    );
+    assert_eq!(take_calls(), ["name_present", "balance_positive"]);
+
+    let unnamed = Account::try_from(RawAccount {
+        name: String::new(),
+        balance: -5,
+    })
+    .unwrap_err();
+    assert_eq!(
+        unnamed,
+        "Failed trying to convert RawAccount to Account: name is empty"
+    );
+    assert_eq!(take_calls(), ["name_present"]);
+
+    let long = Account::try_from(RawAccount {
+        name: "a very long name".to_string(),
+        balance: 5,
+    })
+    .unwrap_err();
+    assert_eq!(
+        long,
+        "Failed trying to convert RawAccount to Account: name is too long"
+    );
+}
@@ -0,0 +1,41 @@
+use derive_into::Convert;
+
+#[derive(Convert, Clone)]
+#[convert(into(path = "String", variant_names))]
+enum Request {
+    Ping,
+    Fetch(String, u32),
+    Upload { name: String, bytes: Vec<u8> },
+    #[convert(rename = "Remove")]
+    Delete(u64),
+}
+
+// The names follow `rename_all` like any other variant rename
+#[derive(Convert)]
+#[convert(into(path = "String", variant_names, rename_all = "snake_case"))]
+enum Status {
+    NotStarted,
+    InProgress { percent: u8 },
+    Done(bool),
+}
+
+fn main() {
+    let name: String = Request::Ping.into();
+    assert_eq!(name, "Ping");
+    assert_eq!(String::from(Request::Fetch("a".to_string(), 1)), "Fetch");
+    assert_eq!(
+        String::from(Request::Upload {
+            name: "b".to_string(),
+            bytes: vec![1],
+        }),
+        "Upload"
+    );
+    assert_eq!(String::from(Request::Delete(3)), "Remove");
+
+    assert_eq!(String::from(Status::NotStarted), "not_started");
+    assert_eq!(
+        String::from(Status::InProgress { percent: 50 }),
+        "in_progress"
+    );
+    assert_eq!(String::from(Status::Done(true)), "done");
+}
@@ -0,0 +1,94 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+enum Shape {
+    Circle(u32, u32, u32),
+    Rect(u32, u32),
+    Line { from: (i32, i32), to: (i32, i32) },
+}
+
+// The two enums disagree on which variants have named fields
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "Shape"))]
+enum Drawing {
+    #[convert(target_tuple)]
+    Circle { x: u16, y: u16, radius: u16 },
+    // `position` places the fields explicitly
+    Rect {
+        #[convert(position = 1)]
+        height: u16,
+        #[convert(position = 0)]
+        width: u16,
+    },
+    #[convert(target_named)]
+    Line(
+        #[convert(rename = "from")] (i32, i32),
+        #[convert(rename = "to")] (i32, i32),
+    ),
+}
+
+#[derive(Debug)]
+enum RawCommand {
+    Move(i64, i64),
+    Resize { width: i64, height: i64 },
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "RawCommand"))]
+enum Command {
+    #[convert(target_tuple)]
+    Move {
+        dx: i8,
+        dy: i8,
+        #[convert(default)]
+        animated: bool,
+    },
+    #[convert(target_named)]
+    Resize(
+        #[convert(rename = "width")] u8,
+        #[convert(rename = "height")] u8,
+    ),
+}
+
+fn main() {
+    assert_eq!(
+        Shape::from(Drawing::Circle {
+            x: 1,
+            y: 2,
+            radius: 3,
+        }),
+        Shape::Circle(1, 2, 3)
+    );
+    assert_eq!(
+        Shape::from(Drawing::Rect {
+            height: 4,
+            width: 5,
+        }),
+        Shape::Rect(5, 4)
+    );
+    assert_eq!(
+        Shape::from(Drawing::Line((0, 0), (1, 1))),
+        Shape::Line {
+            from: (0, 0),
+            to: (1, 1),
+        }
+    );
+
+    assert_eq!(
+        Command::try_from(RawCommand::Move(-1, 2)).unwrap(),
+        Command::Move {
+            dx: -1,
+            dy: 2,
+            animated: false,
+        }
+    );
+    assert_eq!(
+        Command::try_from(RawCommand::Resize {
+            width: 10,
+            height: 20,
+        })
+        .unwrap(),
+        Command::Resize(10, 20)
+    );
+    assert!(Command::try_from(RawCommand::Move(1000, 0)).is_err());
+}
@@ -0,0 +1,55 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Parsed {
+    major: u32,
+    minor: u32,
+}
+
+fn parse_raw(raw: String) -> Result<Parsed, String> {
+    let (major, minor) = raw
+        .split_once('.')
+        .ok_or_else(|| format!("missing '.' in {}", raw))?;
+    Ok(Parsed {
+        major: major.parse().map_err(|_| "bad major".to_string())?,
+        minor: minor.parse().map_err(|_| "bad minor".to_string())?,
+    })
+}
+
+fn format_parsed(parsed: Parsed) -> String {
+    format!("{}.{}", parsed.major, parsed.minor)
+}
+
+enum RawVersion {
+    Raw(String),
+    Latest,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "RawVersion"))]
+#[convert(into(path = "RawVersion"))]
+enum Version {
+    #[convert(rename = "Raw", try_from(with_func = "parse_raw"), into(with_func = "format_parsed"))]
+    Parsed(Parsed),
+    Latest,
+}
+
+fn main() {
+    assert_eq!(
+        Version::try_from(RawVersion::Raw("1.2".to_string())).unwrap(),
+        Version::Parsed(Parsed { major: 1, minor: 2 })
+    );
+    assert_eq!(
+        Version::try_from(RawVersion::Latest).unwrap(),
+        Version::Latest
+    );
+
+    let error = Version::try_from(RawVersion::Raw("12".to_string())).unwrap_err();
+    assert_eq!(
+        error,
+        "Failed trying to convert RawVersion::Raw to Version: \"missing '.' in 12\""
+    );
+
+    let raw: RawVersion = Version::Parsed(Parsed { major: 3, minor: 4 }).into();
+    assert!(matches!(raw, RawVersion::Raw(s) if s == "3.4"));
+}
@@ -0,0 +1,91 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+enum TargetEvent {
+    Started,
+    Moved(u16, u16),
+    Renamed { from: String, to: String },
+}
+
+#[derive(Debug, PartialEq)]
+enum RawEvent {
+    Started,
+    Moved(i64, i64),
+    Renamed { from: String, to: String },
+}
+
+// Unit, tuple and named variants, converted one element at a time through
+// the enum's own generated impls
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "TargetEvent"))]
+#[convert(try_from(path = "RawEvent"))]
+enum SourceEvent {
+    Started,
+    Moved(u16, u16),
+    Renamed { from: String, to: String },
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(into(path = "TargetLog"))]
+#[convert(try_from(path = "RawLog"))]
+struct SourceLog {
+    name: String,
+    events: Vec<SourceEvent>,
+}
+
+#[derive(Debug, PartialEq)]
+struct TargetLog {
+    name: String,
+    events: Vec<TargetEvent>,
+}
+
+struct RawLog {
+    name: String,
+    events: Vec<RawEvent>,
+}
+
+fn main() {
+    let log: TargetLog = SourceLog {
+        name: "session".to_string(),
+        events: vec![
+            SourceEvent::Started,
+            SourceEvent::Moved(1, 2),
+            SourceEvent::Renamed {
+                from: "a".to_string(),
+                to: "b".to_string(),
+            },
+        ],
+    }
+    .into();
+    assert_eq!(
+        log.events,
+        vec![
+            TargetEvent::Started,
+            TargetEvent::Moved(1, 2),
+            TargetEvent::Renamed {
+                from: "a".to_string(),
+                to: "b".to_string(),
+            },
+        ]
+    );
+
+    let parsed = SourceLog::try_from(RawLog {
+        name: "raw".to_string(),
+        events: vec![RawEvent::Started, RawEvent::Moved(3, 4)],
+    })
+    .unwrap();
+    assert_eq!(
+        parsed.events,
+        vec![SourceEvent::Started, SourceEvent::Moved(3, 4)]
+    );
+
+    // The enum's error surfaces through the `Vec`'s collect, naming the struct field
+    let error = SourceLog::try_from(RawLog {
+        name: "raw".to_string(),
+        events: vec![RawEvent::Started, RawEvent::Moved(-1, 4)],
+    })
+    .unwrap_err()
+    .to_string();
+    assert!(error.contains("events"), "{}", error);
+    assert!(error.contains("SourceEvent"), "{}", error);
+}
@@ -0,0 +1,74 @@
+use derive_into::Convert;
+
+mod foreign {
+    // Fields are private, so they can only be read through the getters
+    pub struct Account {
+        id: u32,
+        name: String,
+        tags: Vec<String>,
+        balance: i64,
+    }
+
+    impl Account {
+        pub fn new(id: u32, name: &str, tags: &[&str], balance: i64) -> Self {
+            Account {
+                id,
+                name: name.to_string(),
+                tags: tags.iter().map(|tag| tag.to_string()).collect(),
+                balance,
+            }
+        }
+
+        pub fn id(&self) -> u32 {
+            self.id
+        }
+
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+
+        pub fn tags(&self) -> &[String] {
+            &self.tags
+        }
+
+        pub fn balance(&self) -> i64 {
+            self.balance
+        }
+    }
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "foreign::Account", via_getters))]
+struct Account {
+    id: u64,
+    // Read through the renamed getter
+    #[convert(rename = "name")]
+    display_name: String,
+    tags: Vec<String>,
+    #[convert(skip, default)]
+    note: Option<String>,
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "foreign::Account", via_getters))]
+struct Balance {
+    id: u32,
+    balance: u32,
+}
+
+fn main() {
+    let account = Account::from(foreign::Account::new(1, "ann", &["admin"], 5));
+    assert_eq!(
+        account,
+        Account {
+            id: 1,
+            display_name: "ann".to_string(),
+            tags: vec!["admin".to_string()],
+            note: None,
+        }
+    );
+
+    let balance = Balance::try_from(foreign::Account::new(2, "bob", &[], 12)).unwrap();
+    assert_eq!(balance, Balance { id: 2, balance: 12 });
+    assert!(Balance::try_from(foreign::Account::new(3, "eve", &[], -1)).is_err());
+}
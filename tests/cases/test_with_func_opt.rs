@@ -0,0 +1,60 @@
+use derive_into::Convert;
+
+struct RawUser {
+    name: String,
+    age: String,
+}
+
+fn parse_age(source: &RawUser) -> Option<u8> {
+    source.age.parse().ok()
+}
+
+#[derive(Convert, Debug, PartialEq)]
+#[convert(try_from(path = "RawUser"))]
+struct User {
+    name: String,
+    #[convert(with_func_opt = "parse_age")]
+    age: u8,
+}
+
+fn first_char(source: &Code) -> Option<char> {
+    source.0.chars().next()
+}
+
+#[derive(Convert)]
+#[convert(try_into(path = "Initial"))]
+struct Code(#[convert(with_func_opt = "first_char")] String);
+
+#[derive(Debug, PartialEq)]
+struct Initial(char);
+
+fn main() {
+    let user = User::try_from(RawUser {
+        name: "ann".to_string(),
+        age: "42".to_string(),
+    })
+    .unwrap();
+    assert_eq!(
+        user,
+        User {
+            name: "ann".to_string(),
+            age: 42,
+        }
+    );
+
+    let error = User::try_from(RawUser {
+        name: "bob".to_string(),
+        age: "old".to_string(),
+    })
+    .unwrap_err();
+    assert_eq!(
+        error,
+        "Failed trying to convert source.age to User: parse_age returned None"
+    );
+
+    assert_eq!(
+        Initial::try_from(Code("xyz".to_string())).unwrap(),
+        Initial('x')
+    );
+    assert!(Initial::try_from(Code(String::new())).is_err());
+}
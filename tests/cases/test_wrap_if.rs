@@ -0,0 +1,135 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Note(String);
+
+impl From<String> for Note {
+    fn from(s: String) -> Self {
+        Note(s)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+impl TryFrom<u32> for Port {
+    type Error = String;
+    fn try_from(n: u32) -> Result<Self, Self::Error> {
+        u16::try_from(n).map(Port).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Convert, Clone)]
+#[convert(into(path = "Target"))]
+#[convert(try_into(path = "TryTarget"))]
+struct Source {
+    #[convert(wrap_if = "!source.note.is_empty()")]
+    note: String,
+    #[convert(try_into(wrap_if = "source.port != 0"))]
+    #[convert(into(skip))]
+    port: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Target {
+    note: Option<Note>,
+}
+
+#[derive(Debug, PartialEq)]
+struct TryTarget {
+    note: Option<Note>,
+    port: Option<Port>,
+}
+
+// The `Option` lives on the deriving side
+#[derive(Convert, Debug, PartialEq)]
+#[convert(from(path = "Form"))]
+struct Profile {
+    #[convert(wrap_if = "source.nickname.len() > 2")]
+    nickname: Option<Note>,
+}
+
+struct Form {
+    nickname: String,
+}
+
+// Enum variant fields are bound by name
+#[derive(Convert)]
+#[convert(into(path = "Event"))]
+enum RawEvent {
+    Comment {
+        #[convert(wrap_if = "!text.is_empty()")]
+        text: String,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Comment { text: Option<Note> },
+}
+
+fn main() {
+    let empty = Source {
+        note: String::new(),
+        port: 0,
+    };
+    let filled = Source {
+        note: "hello".to_string(),
+        port: 80,
+    };
+
+    assert_eq!(Target::from(empty.clone()).note, None);
+    assert_eq!(
+        Target::from(filled.clone()).note,
+        Some(Note("hello".to_string()))
+    );
+
+    assert_eq!(
+        TryTarget::try_from(empty).unwrap(),
+        TryTarget {
+            note: None,
+            port: None,
+        }
+    );
+    assert_eq!(
+        TryTarget::try_from(filled).unwrap(),
+        TryTarget {
+            note: Some(Note("hello".to_string())),
+            port: Some(Port(80)),
+        }
+    );
+    // The condition holds, so the conversion runs and its error is reported
+    assert!(
+        TryTarget::try_from(Source {
+            note: String::new(),
+            port: 70000,
+        })
+        .is_err()
+    );
+
+    let profile: Profile = Form {
+        nickname: "al".to_string(),
+    }
+    .into();
+    assert_eq!(profile.nickname, None);
+    let profile: Profile = Form {
+        nickname: "alice".to_string(),
+    }
+    .into();
+    assert_eq!(profile.nickname, Some(Note("alice".to_string())));
+
+    assert_eq!(
+        Event::from(RawEvent::Comment {
+            text: String::new()
+        }),
+        Event::Comment { text: None }
+    );
+    assert_eq!(
+        Event::from(RawEvent::Comment {
+            text: "hi".to_string()
+        }),
+        Event::Comment {
+            text: Some(Note("hi".to_string()))
+        }
+    );
+}
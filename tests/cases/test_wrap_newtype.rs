@@ -0,0 +1,45 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Meters(f64);
+
+impl From<f32> for Meters {
+    fn from(value: f32) -> Self {
+        Meters(value.into())
+    }
+}
+
+// The target's own representation doesn't matter, it's built as `Distance(..)`
+#[repr(transparent)]
+#[derive(Debug, PartialEq)]
+struct Distance(Meters);
+
+#[derive(Convert)]
+#[convert(into(path = "Distance", wrap_newtype))]
+struct Measurement {
+    value: f32,
+    #[convert(skip)]
+    #[allow(dead_code)]
+    label: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Port(u16);
+
+#[derive(Convert)]
+#[convert(try_into(path = "Port", wrap_newtype))]
+struct RawPort {
+    number: u32,
+}
+
+fn main() {
+    let distance: Distance = Measurement {
+        value: 2.5,
+        label: "track".to_string(),
+    }
+    .into();
+    assert_eq!(distance, Distance(Meters(2.5)));
+
+    assert_eq!(Port::try_from(RawPort { number: 443 }).unwrap(), Port(443));
+    assert!(Port::try_from(RawPort { number: 1 << 20 }).is_err());
+}
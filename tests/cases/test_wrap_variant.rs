@@ -0,0 +1,65 @@
+use derive_into::Convert;
+
+#[derive(Debug, PartialEq)]
+struct Id(u32);
+
+impl From<u32> for Id {
+    fn from(n: u32) -> Self {
+        Id(n)
+    }
+}
+
+impl TryFrom<i64> for Id {
+    type Error = String;
+    fn try_from(n: i64) -> Result<Self, Self::Error> {
+        u32::try_from(n).map(Id).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Message", wrap_variant = "Payload"))]
+struct Payload {
+    a: u32,
+    #[convert(rename = "body")]
+    b: String,
+}
+
+#[derive(Convert)]
+#[convert(into(path = "Message", wrap_variant = "Ping"))]
+struct Ping(u32);
+
+#[derive(Convert)]
+#[convert(try_into(path = "Message", wrap_variant = "Ack"))]
+struct RawAck {
+    a: i64,
+}
+
+#[derive(Debug, PartialEq)]
+enum Message {
+    Payload { a: Id, body: String },
+    Ping(Id),
+    Ack { a: Id },
+}
+
+fn main() {
+    let message: Message = Payload {
+        a: 1,
+        b: "hi".to_string(),
+    }
+    .into();
+    assert_eq!(
+        message,
+        Message::Payload {
+            a: Id(1),
+            body: "hi".to_string(),
+        }
+    );
+
+    assert_eq!(Message::from(Ping(7)), Message::Ping(Id(7)));
+
+    assert_eq!(
+        Message::try_from(RawAck { a: 3 }).unwrap(),
+        Message::Ack { a: Id(3) }
+    );
+    assert!(Message::try_from(RawAck { a: -1 }).is_err());
+}
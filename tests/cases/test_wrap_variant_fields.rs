@@ -0,0 +1,76 @@
+use derive_into::Convert;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum State {
+    Idle,
+    Running,
+}
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Transitioned {
+        from: State,
+        to: State,
+        cause: String,
+    },
+    Reset,
+}
+
+#[derive(Debug, PartialEq)]
+enum Audit {
+    Entered {
+        state: State,
+        note: String,
+        acknowledged: bool,
+    },
+}
+
+// The struct's fields become the variant's fields, renamed and skipped like
+// they would be for a struct target
+#[derive(Convert, Debug)]
+#[convert(into(path = "Event", wrap_variant = "Transitioned"))]
+#[convert(into(path = "Audit", wrap_variant = "Entered", default_field = "acknowledged"))]
+struct Transition {
+    #[convert(into(path = "Audit", skip))]
+    from: State,
+    #[convert(into(path = "Audit", rename = "state"))]
+    to: State,
+    #[convert(rename = "cause", into(path = "Audit", rename = "note"))]
+    reason: String,
+    #[convert(skip)]
+    recorded_at: u64,
+}
+
+fn transition() -> Transition {
+    Transition {
+        from: State::Idle,
+        to: State::Running,
+        reason: "started".to_string(),
+        recorded_at: 100,
+    }
+}
+
+fn main() {
+    assert_eq!(transition().recorded_at, 100);
+
+    let event: Event = transition().into();
+    assert_eq!(
+        event,
+        Event::Transitioned {
+            from: State::Idle,
+            to: State::Running,
+            cause: "started".to_string(),
+        }
+    );
+    assert_ne!(event, Event::Reset);
+
+    let audit: Audit = transition().into();
+    assert_eq!(
+        audit,
+        Audit::Entered {
+            state: State::Running,
+            note: "started".to_string(),
+            acknowledged: false,
+        }
+    );
+}
@@ -0,0 +1,176 @@
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::From<A> for B {
+    fn from(source: A) -> B {
+        Self {
+            normal: {
+                let __unwrapped = ::core::option::Option::unwrap_or_else(
+                    source.normal,
+                    || ::core::panic!("{}", "Expected value to exist when converting"),
+                );
+                __unwrapped.into()
+            },
+            opt: source.opt.map(|v| v.into()),
+            vec: source.vec.into_iter().map(|v| v.into()).collect(),
+            renamed_field: source.old_name.into(),
+            ..::core::default::Default::default()
+        }
+    }
+}
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::TryFrom<B> for A {
+    type Error = ::std::string::String;
+    fn try_from(source: B) -> ::core::result::Result<A, Self::Error> {
+        ::core::result::Result::Ok(Self {
+            normal: <u8 as ::core::convert::TryFrom<_>>::try_from(source.normal)
+                .map_err(|e| {
+                    struct Describe<'a, E>(&'a E);
+                    trait ViaDisplay {
+                        fn describe(&self) -> ::std::string::String;
+                    }
+                    impl<E: ::core::fmt::Display> ViaDisplay for Describe<'_, E> {
+                        fn describe(&self) -> ::std::string::String {
+                            ::std::string::ToString::to_string(self.0)
+                        }
+                    }
+                    trait ViaDebug {
+                        fn describe(&self) -> ::std::string::String;
+                    }
+                    impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                        fn describe(&self) -> ::std::string::String {
+                            ::std::format!("{:?}", self.0)
+                        }
+                    }
+                    (&Describe(&e)).describe()
+                })
+                .map(::core::option::Option::Some)
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.normal), ::core::stringify!(A), e,
+                    )
+                })?,
+            opt: source
+                .opt
+                .map(|v| {
+                    <u8 as ::core::convert::TryFrom<_>>::try_from(v)
+                        .map_err(|e| {
+                            struct Describe<'a, E>(&'a E);
+                            trait ViaDisplay {
+                                fn describe(&self) -> ::std::string::String;
+                            }
+                            impl<E: ::core::fmt::Display> ViaDisplay
+                            for Describe<'_, E> {
+                                fn describe(&self) -> ::std::string::String {
+                                    ::std::string::ToString::to_string(self.0)
+                                }
+                            }
+                            trait ViaDebug {
+                                fn describe(&self) -> ::std::string::String;
+                            }
+                            impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                                fn describe(&self) -> ::std::string::String {
+                                    ::std::format!("{:?}", self.0)
+                                }
+                            }
+                            (&Describe(&e)).describe()
+                        })
+                })
+                .transpose()
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.opt), ::core::stringify!(A), e,
+                    )
+                })?,
+            vec: source
+                .vec
+                .into_iter()
+                .map(|v| {
+                    <u8 as ::core::convert::TryFrom<_>>::try_from(v)
+                        .map_err(|e| {
+                            struct Describe<'a, E>(&'a E);
+                            trait ViaDisplay {
+                                fn describe(&self) -> ::std::string::String;
+                            }
+                            impl<E: ::core::fmt::Display> ViaDisplay
+                            for Describe<'_, E> {
+                                fn describe(&self) -> ::std::string::String {
+                                    ::std::string::ToString::to_string(self.0)
+                                }
+                            }
+                            trait ViaDebug {
+                                fn describe(&self) -> ::std::string::String;
+                            }
+                            impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                                fn describe(&self) -> ::std::string::String {
+                                    ::std::format!("{:?}", self.0)
+                                }
+                            }
+                            (&Describe(&e)).describe()
+                        })
+                })
+                .collect::<::core::result::Result<_, _>>()
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.vec), ::core::stringify!(A), e,
+                    )
+                })?,
+            old_name: <u16 as ::core::convert::TryFrom<
+                _,
+            >>::try_from(source.renamed_field)
+                .map_err(|e| {
+                    struct Describe<'a, E>(&'a E);
+                    trait ViaDisplay {
+                        fn describe(&self) -> ::std::string::String;
+                    }
+                    impl<E: ::core::fmt::Display> ViaDisplay for Describe<'_, E> {
+                        fn describe(&self) -> ::std::string::String {
+                            ::std::string::ToString::to_string(self.0)
+                        }
+                    }
+                    trait ViaDebug {
+                        fn describe(&self) -> ::std::string::String;
+                    }
+                    impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                        fn describe(&self) -> ::std::string::String {
+                            ::std::format!("{:?}", self.0)
+                        }
+                    }
+                    (&Describe(&e)).describe()
+                })
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.renamed_field), ::core::stringify!(A),
+                        e,
+                    )
+                })?,
+        })
+    }
+}
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::From<C> for D {
+    fn from(source: C) -> D {
+        Self(source.0.map(|v| v.into()), source.1.into())
+    }
+}
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::From<E> for F {
+    fn from(source: E) -> F {
+        match source {
+            E::Variant1(field0) => Self::Variant1(field0.into()),
+            E::VariantNamed { field, other } => {
+                Self::VariantRenamed {
+                    field: field.into(),
+                    other2: other.into(),
+                }
+            }
+            E::Unit => Self::Unit,
+        }
+    }
+}
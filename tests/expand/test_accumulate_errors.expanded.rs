@@ -0,0 +1,92 @@
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::TryFrom<Form> for User {
+    type Error = ::std::string::String;
+    fn try_from(source: Form) -> ::core::result::Result<User, Self::Error> {
+        let mut __errors: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+        let __converted_0 = source
+            .name
+            .try_into()
+            .map_err(|e| ::std::format!("{:?}", e))
+            .map_err(|e| {
+                ::std::format!(
+                    "Failed trying to convert {} to {}: {}", ::core::stringify!(source
+                    .name), ::core::stringify!(User), e,
+                )
+            })
+            .map_err(|e| __errors.push(e.to_string()))
+            .ok();
+        let __converted_1 = source
+            .age
+            .try_into()
+            .map_err(|e| ::std::format!("{:?}", e))
+            .map_err(|e| {
+                ::std::format!(
+                    "Failed trying to convert {} to {}: {}", ::core::stringify!(source
+                    .age), ::core::stringify!(User), e,
+                )
+            })
+            .map_err(|e| __errors.push(e.to_string()))
+            .ok();
+        let __converted_2 = source
+            .email
+            .try_into()
+            .map_err(|e| ::std::format!("{:?}", e))
+            .map_err(|e| {
+                ::std::format!(
+                    "Failed trying to convert {} to {}: {}", ::core::stringify!(source
+                    .email), ::core::stringify!(User), e,
+                )
+            })
+            .map_err(|e| __errors.push(e.to_string()))
+            .ok();
+        if !__errors.is_empty() {
+            return ::core::result::Result::Err(
+                ::std::format!("{}", __errors.join("; ")).into(),
+            );
+        }
+        ::core::result::Result::Ok(Self {
+            name: __converted_0.unwrap(),
+            age: __converted_1.unwrap(),
+            email: __converted_2.unwrap(),
+        })
+    }
+}
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::TryFrom<RawPair> for Pair {
+    type Error = ::std::string::String;
+    fn try_from(source: RawPair) -> ::core::result::Result<Pair, Self::Error> {
+        let mut __errors: ::std::vec::Vec<::std::string::String> = ::std::vec::Vec::new();
+        let __converted_0 = source
+            .0
+            .try_into()
+            .map_err(|e| ::std::format!("{:?}", e))
+            .map_err(|e| {
+                ::std::format!(
+                    "Failed trying to convert {} to {}: {}", ::core::stringify!(source
+                    .0), ::core::stringify!(Pair), e,
+                )
+            })
+            .map_err(|e| __errors.push(e.to_string()))
+            .ok();
+        let __converted_1 = source
+            .1
+            .try_into()
+            .map_err(|e| ::std::format!("{:?}", e))
+            .map_err(|e| {
+                ::std::format!(
+                    "Failed trying to convert {} to {}: {}", ::core::stringify!(source
+                    .1), ::core::stringify!(Pair), e,
+                )
+            })
+            .map_err(|e| __errors.push(e.to_string()))
+            .ok();
+        if !__errors.is_empty() {
+            return ::core::result::Result::Err(
+                ::std::format!("{}", __errors.join("; ")).into(),
+            );
+        }
+        ::core::result::Result::Ok(Self(__converted_0.unwrap(), __converted_1.unwrap()))
+    }
+}
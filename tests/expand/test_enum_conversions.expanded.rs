@@ -0,0 +1,364 @@
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::From<SourceEvent> for TargetEvent {
+    fn from(source: SourceEvent) -> TargetEvent {
+        match source {
+            SourceEvent::Heartbeat => Self::Heartbeat,
+            SourceEvent::Click(field0) => Self::Click(field0.into()),
+            SourceEvent::MouseMove(field0, field1) => {
+                Self::MouseMove(field0.into(), field1.into())
+            }
+            SourceEvent::Login { username, token, timestamp } => {
+                Self::Login {
+                    username: username.into(),
+                    auth_token: token.into(),
+                    timestamp: timestamp.into(),
+                }
+            }
+            SourceEvent::Logout { username, timestamp } => {
+                Self::LogoutEvent {
+                    username: username.into(),
+                    timestamp: timestamp.into(),
+                }
+            }
+            SourceEvent::Message { from, to, content } => {
+                Self::Message {
+                    from: from.into(),
+                    to: to.into(),
+                    content: {
+                        let __unwrapped = ::core::option::Option::unwrap_or_else(
+                            content,
+                            || {
+                                ::core::panic!(
+                                    "{}", "Expected value to exist when converting"
+                                )
+                            },
+                        );
+                        __unwrapped.into()
+                    },
+                }
+            }
+            SourceEvent::UserAction { user_id, action_type } => {
+                Self::UserAction {
+                    user_id: user_id.into(),
+                    action_type: action_type.into(),
+                }
+            }
+        }
+    }
+}
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::TryFrom<TargetEvent> for SourceEvent {
+    type Error = ::std::string::String;
+    fn try_from(
+        source: TargetEvent,
+    ) -> ::core::result::Result<SourceEvent, Self::Error> {
+        ::core::result::Result::Ok(
+            match source {
+                TargetEvent::Heartbeat => Self::Heartbeat,
+                TargetEvent::Click(field0) => {
+                    Self::Click(
+                        <u64 as ::core::convert::TryFrom<_>>::try_from(field0)
+                            .map_err(|e| {
+                                struct Describe<'a, E>(&'a E);
+                                trait ViaDisplay {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Display> ViaDisplay
+                                for Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::string::ToString::to_string(self.0)
+                                    }
+                                }
+                                trait ViaDebug {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::format!("{:?}", self.0)
+                                    }
+                                }
+                                (&Describe(&e)).describe()
+                            })
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(field0), ::core::stringify!(SourceEvent),
+                                    e,
+                                )
+                            })?,
+                    )
+                }
+                TargetEvent::MouseMove(field0, field1) => {
+                    Self::MouseMove(
+                        <u64 as ::core::convert::TryFrom<_>>::try_from(field0)
+                            .map_err(|e| {
+                                struct Describe<'a, E>(&'a E);
+                                trait ViaDisplay {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Display> ViaDisplay
+                                for Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::string::ToString::to_string(self.0)
+                                    }
+                                }
+                                trait ViaDebug {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::format!("{:?}", self.0)
+                                    }
+                                }
+                                (&Describe(&e)).describe()
+                            })
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(field0), ::core::stringify!(SourceEvent),
+                                    e,
+                                )
+                            })?,
+                        <u64 as ::core::convert::TryFrom<_>>::try_from(field1)
+                            .map_err(|e| {
+                                struct Describe<'a, E>(&'a E);
+                                trait ViaDisplay {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Display> ViaDisplay
+                                for Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::string::ToString::to_string(self.0)
+                                    }
+                                }
+                                trait ViaDebug {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::format!("{:?}", self.0)
+                                    }
+                                }
+                                (&Describe(&e)).describe()
+                            })
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(field1), ::core::stringify!(SourceEvent),
+                                    e,
+                                )
+                            })?,
+                    )
+                }
+                TargetEvent::Login { username, auth_token, timestamp } => {
+                    Self::Login {
+                        username: username
+                            .try_into()
+                            .map_err(|e| ::std::format!("{:?}", e))
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(username),
+                                    ::core::stringify!(SourceEvent), e,
+                                )
+                            })?,
+                        token: auth_token
+                            .try_into()
+                            .map_err(|e| ::std::format!("{:?}", e))
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(auth_token),
+                                    ::core::stringify!(SourceEvent), e,
+                                )
+                            })?,
+                        timestamp: <u64 as ::core::convert::TryFrom<
+                            _,
+                        >>::try_from(timestamp)
+                            .map_err(|e| {
+                                struct Describe<'a, E>(&'a E);
+                                trait ViaDisplay {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Display> ViaDisplay
+                                for Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::string::ToString::to_string(self.0)
+                                    }
+                                }
+                                trait ViaDebug {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::format!("{:?}", self.0)
+                                    }
+                                }
+                                (&Describe(&e)).describe()
+                            })
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(timestamp),
+                                    ::core::stringify!(SourceEvent), e,
+                                )
+                            })?,
+                    }
+                }
+                TargetEvent::LogoutEvent { username, timestamp } => {
+                    Self::Logout {
+                        username: username
+                            .try_into()
+                            .map_err(|e| ::std::format!("{:?}", e))
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(username),
+                                    ::core::stringify!(SourceEvent), e,
+                                )
+                            })?,
+                        timestamp: <u64 as ::core::convert::TryFrom<
+                            _,
+                        >>::try_from(timestamp)
+                            .map_err(|e| {
+                                struct Describe<'a, E>(&'a E);
+                                trait ViaDisplay {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Display> ViaDisplay
+                                for Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::string::ToString::to_string(self.0)
+                                    }
+                                }
+                                trait ViaDebug {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::format!("{:?}", self.0)
+                                    }
+                                }
+                                (&Describe(&e)).describe()
+                            })
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(timestamp),
+                                    ::core::stringify!(SourceEvent), e,
+                                )
+                            })?,
+                    }
+                }
+                TargetEvent::Message { from, to, content } => {
+                    Self::Message {
+                        from: from
+                            .try_into()
+                            .map_err(|e| ::std::format!("{:?}", e))
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(from), ::core::stringify!(SourceEvent),
+                                    e,
+                                )
+                            })?,
+                        to: to
+                            .try_into()
+                            .map_err(|e| ::std::format!("{:?}", e))
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(to), ::core::stringify!(SourceEvent), e,
+                                )
+                            })?,
+                        content: content
+                            .try_into()
+                            .map_err(|e| ::std::format!("{:?}", e))
+                            .map(::core::option::Option::Some)
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(content),
+                                    ::core::stringify!(SourceEvent), e,
+                                )
+                            })?,
+                    }
+                }
+                TargetEvent::UserAction { user_id, action_type } => {
+                    Self::UserAction {
+                        user_id: <u64 as ::core::convert::TryFrom<_>>::try_from(user_id)
+                            .map_err(|e| {
+                                struct Describe<'a, E>(&'a E);
+                                trait ViaDisplay {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Display> ViaDisplay
+                                for Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::string::ToString::to_string(self.0)
+                                    }
+                                }
+                                trait ViaDebug {
+                                    fn describe(&self) -> ::std::string::String;
+                                }
+                                impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                                    fn describe(&self) -> ::std::string::String {
+                                        ::std::format!("{:?}", self.0)
+                                    }
+                                }
+                                (&Describe(&e)).describe()
+                            })
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(user_id),
+                                    ::core::stringify!(SourceEvent), e,
+                                )
+                            })?,
+                        action_type: action_type
+                            .try_into()
+                            .map_err(|e| ::std::format!("{:?}", e))
+                            .map_err(|e| {
+                                ::std::format!(
+                                    "Failed trying to convert {} to {}: {}",
+                                    ::core::stringify!(action_type),
+                                    ::core::stringify!(SourceEvent), e,
+                                )
+                            })?,
+                    }
+                }
+            },
+        )
+    }
+}
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::From<SourceActionType> for TargetActionType {
+    fn from(source: SourceActionType) -> TargetActionType {
+        match source {
+            SourceActionType::View => Self::View,
+            SourceActionType::Edit => Self::Edit,
+            SourceActionType::Delete => Self::Delete,
+            SourceActionType::Create => Self::CreateNew,
+        }
+    }
+}
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::TryFrom<TargetActionType> for SourceActionType {
+    type Error = ::std::string::String;
+    fn try_from(
+        source: TargetActionType,
+    ) -> ::core::result::Result<SourceActionType, Self::Error> {
+        ::core::result::Result::Ok(
+            match source {
+                TargetActionType::View => Self::View,
+                TargetActionType::Edit => Self::Edit,
+                TargetActionType::Delete => Self::Delete,
+                TargetActionType::CreateNew => Self::Create,
+            },
+        )
+    }
+}
@@ -0,0 +1,71 @@
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::TryFrom<HashMap<String, String>> for Config {
+    type Error = ::std::string::String;
+    fn try_from(
+        source: HashMap<String, String>,
+    ) -> ::core::result::Result<Config, Self::Error> {
+        let mut source = source;
+        ::core::result::Result::Ok(Self {
+            host: source
+                .remove("host")
+                .ok_or_else(|| {
+                    ::std::format!(
+                        "Missing key {} when converting to {}", "host",
+                        ::core::stringify!(Config),
+                    )
+                })?
+                .parse()
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {:?}", "host",
+                        ::core::stringify!(Config), e,
+                    )
+                })?,
+            port: source
+                .remove("port")
+                .ok_or_else(|| {
+                    ::std::format!(
+                        "Missing key {} when converting to {}", "port",
+                        ::core::stringify!(Config),
+                    )
+                })?
+                .parse()
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {:?}", "port",
+                        ::core::stringify!(Config), e,
+                    )
+                })?,
+            max_connections: source
+                .remove("connections")
+                .ok_or_else(|| {
+                    ::std::format!(
+                        "Missing key {} when converting to {}", "connections",
+                        ::core::stringify!(Config),
+                    )
+                })?
+                .parse()
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {:?}", "connections",
+                        ::core::stringify!(Config), e,
+                    )
+                })?,
+            timeout: source
+                .remove("timeout")
+                .map(|v| {
+                    v
+                        .parse()
+                        .map_err(|e| {
+                            ::std::format!(
+                                "Failed trying to convert {} to {}: {:?}", "timeout",
+                                ::core::stringify!(Config), e,
+                            )
+                        })
+                })
+                .transpose()?,
+            retries: ::core::default::Default::default(),
+        })
+    }
+}
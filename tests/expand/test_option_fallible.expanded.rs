@@ -0,0 +1,19 @@
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::TryFrom<ApiOrder> for Order {
+    type Error = ::std::string::String;
+    fn try_from(source: ApiOrder) -> ::core::result::Result<Order, Self::Error> {
+        ::core::result::Result::Ok(Self {
+            thing: source
+                .thing
+                .map(|v| v.try_into().map_err(|e| ::std::format!("{:?}", e)))
+                .transpose()
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.thing), ::core::stringify!(Order), e,
+                    )
+                })?,
+        })
+    }
+}
@@ -0,0 +1,127 @@
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::From<User> for UserRecord {
+    fn from(source: User) -> UserRecord {
+        Self {
+            name: source.name.into(),
+            id: source.id.into(),
+            email: source.email.map(|v| v.into()),
+            creation_date: source.created_at.into(),
+            age: {
+                let __unwrapped = ::core::option::Option::unwrap_or_else(
+                    source.age,
+                    || ::core::panic!("{}", "Expected value to exist when converting"),
+                );
+                __unwrapped.into()
+            },
+            roles: source.roles.into_iter().map(|v| v.into()).collect(),
+            ..::core::default::Default::default()
+        }
+    }
+}
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::TryFrom<UserRecord> for User {
+    type Error = ::std::string::String;
+    fn try_from(source: UserRecord) -> ::core::result::Result<User, Self::Error> {
+        ::core::result::Result::Ok(Self {
+            name: source
+                .name
+                .try_into()
+                .map_err(|e| ::std::format!("{:?}", e))
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.name), ::core::stringify!(User), e,
+                    )
+                })?,
+            id: <u32 as ::core::convert::TryFrom<_>>::try_from(source.id)
+                .map_err(|e| {
+                    struct Describe<'a, E>(&'a E);
+                    trait ViaDisplay {
+                        fn describe(&self) -> ::std::string::String;
+                    }
+                    impl<E: ::core::fmt::Display> ViaDisplay for Describe<'_, E> {
+                        fn describe(&self) -> ::std::string::String {
+                            ::std::string::ToString::to_string(self.0)
+                        }
+                    }
+                    trait ViaDebug {
+                        fn describe(&self) -> ::std::string::String;
+                    }
+                    impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                        fn describe(&self) -> ::std::string::String {
+                            ::std::format!("{:?}", self.0)
+                        }
+                    }
+                    (&Describe(&e)).describe()
+                })
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.id), ::core::stringify!(User), e,
+                    )
+                })?,
+            email: source
+                .email
+                .map(|v| v.try_into().map_err(|e| ::std::format!("{:?}", e)))
+                .transpose()
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.email), ::core::stringify!(User), e,
+                    )
+                })?,
+            created_at: source
+                .creation_date
+                .try_into()
+                .map_err(|e| ::std::format!("{:?}", e))
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.creation_date),
+                        ::core::stringify!(User), e,
+                    )
+                })?,
+            age: <u8 as ::core::convert::TryFrom<_>>::try_from(source.age)
+                .map_err(|e| {
+                    struct Describe<'a, E>(&'a E);
+                    trait ViaDisplay {
+                        fn describe(&self) -> ::std::string::String;
+                    }
+                    impl<E: ::core::fmt::Display> ViaDisplay for Describe<'_, E> {
+                        fn describe(&self) -> ::std::string::String {
+                            ::std::string::ToString::to_string(self.0)
+                        }
+                    }
+                    trait ViaDebug {
+                        fn describe(&self) -> ::std::string::String;
+                    }
+                    impl<E: ::core::fmt::Debug> ViaDebug for &Describe<'_, E> {
+                        fn describe(&self) -> ::std::string::String {
+                            ::std::format!("{:?}", self.0)
+                        }
+                    }
+                    (&Describe(&e)).describe()
+                })
+                .map(::core::option::Option::Some)
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.age), ::core::stringify!(User), e,
+                    )
+                })?,
+            roles: source
+                .roles
+                .into_iter()
+                .map(|v| v.try_into().map_err(|e| ::std::format!("{:?}", e)))
+                .collect::<::core::result::Result<_, _>>()
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.roles), ::core::stringify!(User), e,
+                    )
+                })?,
+        })
+    }
+}
@@ -0,0 +1,49 @@
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::From<Named> for Target {
+    fn from(source: Named) -> Target {
+        let (__field_1, __field_0, __field_2) = (
+            name_len(&source),
+            source.0.into(),
+            source.2.into(),
+        );
+        Self(__field_0, __field_1, __field_2)
+    }
+}
+#[automatically_derived]
+#[allow(clippy::all, unused)]
+impl ::core::convert::TryFrom<Named> for TryTarget {
+    type Error = ::std::string::String;
+    fn try_from(source: Named) -> ::core::result::Result<TryTarget, Self::Error> {
+        let (__field_1, __field_0, __field_2) = (
+            checked_name_len(&source)
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {:?}",
+                        ::core::stringify!(source.1), ::core::stringify!(TryTarget), e,
+                    )
+                })?,
+            source
+                .0
+                .try_into()
+                .map_err(|e| ::std::format!("{:?}", e))
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.0), ::core::stringify!(TryTarget), e,
+                    )
+                })?,
+            source
+                .2
+                .try_into()
+                .map_err(|e| ::std::format!("{:?}", e))
+                .map_err(|e| {
+                    ::std::format!(
+                        "Failed trying to convert {} to {}: {}",
+                        ::core::stringify!(source.2), ::core::stringify!(TryTarget), e,
+                    )
+                })?,
+        );
+        ::core::result::Result::Ok(Self(__field_0, __field_1, __field_2))
+    }
+}